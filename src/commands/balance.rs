@@ -0,0 +1,50 @@
+use anyhow::{bail, Result};
+use chrono::Local;
+use colored::Colorize;
+use crate::commands::report::retainer_history;
+use crate::config::load_config;
+use crate::errors::GttError;
+use crate::i18n::{t, tf};
+use crate::output::print_retainer_balance;
+
+pub struct BalanceArgs {
+    pub client: Option<String>,
+}
+
+pub fn run(args: BalanceArgs) -> Result<()> {
+    let config = load_config()?;
+    let today = Local::now().date_naive();
+
+    let clients: Vec<_> = if let Some(ref name) = args.client {
+        let cfg = config
+            .client
+            .get(name)
+            .ok_or_else(|| GttError::ClientNotFound(name.clone()))?;
+        if cfg.retainer.is_none() {
+            bail!(tf("balance.no_retainer", &[("client", name)]));
+        }
+        vec![(name.clone(), cfg)]
+    } else {
+        config
+            .client
+            .iter()
+            .filter(|(_, cfg)| cfg.retainer.is_some())
+            .map(|(k, v)| (k.clone(), v))
+            .collect()
+    };
+
+    if clients.is_empty() {
+        println!("{}", t("balance.no_retainers").yellow());
+        return Ok(());
+    }
+
+    for (client_name, client_cfg) in clients {
+        let Some(ref retainer) = client_cfg.retainer else {
+            continue;
+        };
+        let (_, periods) = retainer_history(&client_name, client_cfg, retainer, retainer.start, today, &config.settings)?;
+        print_retainer_balance(&client_name, &periods);
+    }
+
+    Ok(())
+}
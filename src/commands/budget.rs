@@ -0,0 +1,77 @@
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use crate::commands::report::collect_days;
+use crate::config::{load_config, ClientConfig, Settings};
+use crate::errors::GttError;
+use crate::i18n::{t, tf};
+use crate::output::print_budget_status;
+use crate::session::budget::{budget_status, BudgetStatus};
+
+pub struct BudgetArgs {
+    pub client: Option<String>,
+}
+
+pub fn run(args: BudgetArgs) -> Result<()> {
+    let config = load_config()?;
+    let today = Local::now().date_naive();
+
+    let clients: Vec<_> = if let Some(ref name) = args.client {
+        let cfg = config
+            .client
+            .get(name)
+            .ok_or_else(|| GttError::ClientNotFound(name.clone()))?;
+        if cfg.budgets.is_empty() {
+            bail!(tf("budget.no_budget", &[("client", name)]));
+        }
+        vec![(name.clone(), cfg)]
+    } else {
+        config
+            .client
+            .iter()
+            .filter(|(_, cfg)| !cfg.budgets.is_empty())
+            .map(|(k, v)| (k.clone(), v))
+            .collect()
+    };
+
+    if clients.is_empty() {
+        println!("{}", t("budget.no_budgets").yellow());
+        return Ok(());
+    }
+
+    for (client_name, client_cfg) in clients {
+        let statuses = client_budget_statuses(&client_name, client_cfg, &config.settings, today)?;
+        print_budget_status(&client_name, &client_cfg.currency, &statuses);
+    }
+
+    Ok(())
+}
+
+/// Status of every budget of a client as of `today`. Manual time entries only
+/// count toward budgets that cover all of the client's repos.
+pub fn client_budget_statuses(
+    client_name: &str,
+    client_cfg: &ClientConfig,
+    settings: &Settings,
+    today: NaiveDate,
+) -> Result<Vec<BudgetStatus>> {
+    let mut statuses = Vec::new();
+    for budget in &client_cfg.budgets {
+        if budget.start > today {
+            continue;
+        }
+        // Restrict to the budget's repos; the report-period cap does not apply to budgets
+        let mut scoped = client_cfg.clone();
+        scoped.max_hours_per_period = None;
+        if !budget.repos.is_empty() {
+            scoped.repos.retain(|path| {
+                path.file_name()
+                    .is_some_and(|n| budget.repos.iter().any(|r| *r == n.to_string_lossy()))
+            });
+        }
+        let manual_client = budget.repos.is_empty().then_some(client_name);
+        let days = collect_days(&scoped, manual_client, budget.start, today, settings)?;
+        statuses.push(budget_status(budget, &days, today));
+    }
+    Ok(statuses)
+}
@@ -1,12 +1,13 @@
 use anyhow::{bail, Result};
 use colored::Colorize;
 use crate::config::config_path;
+use crate::i18n::{t, tf};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ConfigAction {
-    /// Show current configuration
+    /// Muestra la configuración actual
     Show,
-    /// Open config in $EDITOR
+    /// Abre la configuración en $EDITOR
     Edit,
 }
 
@@ -20,7 +21,7 @@ pub fn run(action: ConfigAction) -> Result<()> {
 fn show() -> Result<()> {
     let path = config_path()?;
     if !path.exists() {
-        println!("{}", "No hay configuración. Ejecuta `gtt init`.".yellow());
+        println!("{}", t("config.missing").yellow());
         return Ok(());
     }
     let content = std::fs::read_to_string(&path)?;
@@ -32,7 +33,7 @@ fn show() -> Result<()> {
 fn edit() -> Result<()> {
     let path = config_path()?;
     if !path.exists() {
-        bail!("No hay configuración. Ejecuta `gtt init` primero.");
+        bail!(t("config.missing_edit"));
     }
 
     let editor = std::env::var("EDITOR")
@@ -44,7 +45,7 @@ fn edit() -> Result<()> {
         .status()?;
 
     if !status.success() {
-        bail!("El editor '{}' terminó con error.", editor);
+        bail!(tf("config.editor_failed", &[("editor", &editor)]));
     }
 
     Ok(())
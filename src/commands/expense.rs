@@ -0,0 +1,154 @@
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Table};
+use rust_decimal::Decimal;
+use crate::config::load_config;
+use crate::errors::GttError;
+use crate::i18n::{format_date, format_money, t, tf};
+use crate::store::ExpenseStore;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ExpenseAction {
+    /// Registra un gasto reembolsable (hosting, dominios, APIs pagas)
+    Add {
+        /// Nombre del cliente
+        #[arg(long)]
+        client: String,
+
+        /// Fecha del gasto (YYYY-MM-DD, default: hoy)
+        #[arg(long)]
+        date: Option<String>,
+
+        /// Monto en la moneda del cliente
+        #[arg(long)]
+        amount: Decimal,
+
+        /// Descripción que aparece en el reporte
+        #[arg(long)]
+        description: String,
+
+        /// Repositorio al que corresponde el gasto (nombre del directorio)
+        #[arg(long)]
+        repo: Option<String>,
+    },
+    /// Lista los gastos registrados
+    List {
+        /// Nombre del cliente (todos si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
+    },
+    /// Elimina un gasto por su número
+    Remove {
+        /// Número del gasto (ver `gtt expense list`)
+        id: u32,
+    },
+}
+
+pub fn run(action: ExpenseAction) -> Result<()> {
+    match action {
+        ExpenseAction::Add {
+            client,
+            date,
+            amount,
+            description,
+            repo,
+        } => add(&client, date, amount, &description, repo),
+        ExpenseAction::List { client } => list(client),
+        ExpenseAction::Remove { id } => remove(id),
+    }
+}
+
+fn add(client: &str, date: Option<String>, amount: Decimal, description: &str, repo: Option<String>) -> Result<()> {
+    let config = load_config()?;
+    let client_cfg = config
+        .client
+        .get(client)
+        .ok_or_else(|| GttError::ClientNotFound(client.to_string()))?;
+
+    if amount <= Decimal::ZERO {
+        bail!(t("common.amount_not_positive"));
+    }
+    if let Some(ref name) = repo {
+        let known = client_cfg
+            .repos
+            .iter()
+            .any(|p| p.file_name().is_some_and(|f| f.to_string_lossy() == name.as_str()));
+        if !known {
+            bail!(tf("expense.unknown_repo", &[("client", &client), ("repo", name)]));
+        }
+    }
+    let date = match date {
+        Some(ref d) => NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|_| GttError::DateParse(tf("date.invalid", &[("value", d)])))?,
+        None => Local::now().date_naive(),
+    };
+
+    let mut store = ExpenseStore::load()?;
+    let expense = store.add(client, date, amount, description, repo).clone();
+    store.save()?;
+
+    println!(
+        "{}",
+        tf(
+            "expense.added",
+            &[
+                ("id", &expense.id),
+                ("date", &format_date(expense.date)),
+                ("amount", &format!("{} {}", format_money(expense.amount), client_cfg.currency)),
+                ("description", &expense.description),
+            ]
+        )
+        .green()
+    );
+    Ok(())
+}
+
+fn list(client: Option<String>) -> Result<()> {
+    let config = load_config()?;
+    let store = ExpenseStore::load()?;
+    let mut expenses: Vec<_> = store
+        .expenses
+        .iter()
+        .filter(|e| client.as_ref().is_none_or(|c| *c == e.client))
+        .collect();
+    expenses.sort_by_key(|e| (e.date, e.id));
+
+    if expenses.is_empty() {
+        println!("{}", t("expense.none").yellow());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic).set_header(vec![
+        Cell::new("#").fg(Color::Cyan),
+        Cell::new(t("col.date")).fg(Color::Cyan),
+        Cell::new(t("col.client")).fg(Color::Cyan),
+        Cell::new(t("col.description")).fg(Color::Cyan),
+        Cell::new(t("col.repo")).fg(Color::Cyan),
+        Cell::new(t("col.amount")).fg(Color::Cyan),
+    ]);
+    for e in expenses {
+        let currency = config.client.get(&e.client).map_or("", |c| c.currency.as_str());
+        table.add_row(vec![
+            Cell::new(e.id.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_date(e.date)),
+            Cell::new(&e.client),
+            Cell::new(&e.description),
+            Cell::new(e.repo.as_deref().unwrap_or("")),
+            Cell::new(format!("{} {}", format_money(e.amount), currency)).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+fn remove(id: u32) -> Result<()> {
+    let mut store = ExpenseStore::load()?;
+    if !store.remove(id) {
+        bail!(tf("expense.not_found", &[("id", &id)]));
+    }
+    store.save()?;
+    println!("{}", tf("expense.removed", &[("id", &id)]).green());
+    Ok(())
+}
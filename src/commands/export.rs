@@ -10,6 +10,12 @@ pub struct ExportArgs {
     pub until: Option<String>,
     pub format: String,
     pub output: Option<String>,
+    pub in_currency: Option<String>,
+    pub rate_date: String,
+    pub sessions: bool,
+    pub postings: String,
+    pub detail: Option<String>,
+    pub group_by: String,
 }
 
 pub fn run(args: ExportArgs) -> Result<()> {
@@ -28,7 +34,7 @@ pub fn run(args: ExportArgs) -> Result<()> {
             "gtt-{}-{}.{}",
             safe_client,
             Local::now().format("%Y-%m"),
-            args.format
+            extension(&args.format)
         ))
     } else {
         args.output
@@ -42,6 +48,23 @@ pub fn run(args: ExportArgs) -> Result<()> {
         until: args.until,
         format: args.format,
         output,
+        in_currency: args.in_currency,
+        rate_date: args.rate_date,
+        sessions: args.sessions,
+        postings: args.postings,
+        detail: args.detail,
+        group_by: args.group_by,
         verify_mode: false,
     })
 }
+
+/// File extension for an export format
+fn extension(format: &str) -> &str {
+    match format {
+        "markdown" => "md",
+        "toggl" | "clockify" | "harvest" => "csv",
+        "ledger" => "journal",
+        "timewarrior" => "json",
+        other => other,
+    }
+}
@@ -1,14 +1,16 @@
 use anyhow::Result;
 use colored::Colorize;
 use inquire::{Confirm, CustomType, Select, Text};
+use rust_decimal::Decimal;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use crate::config::{ClientConfig, Config, Settings, save_config};
+use crate::i18n::t;
 
 pub fn run() -> Result<()> {
     println!();
     println!("{}", "gtt — Git Time Tracker".bold().cyan());
-    println!("{}", "Setup interactivo de configuración".dimmed());
+    println!("{}", t("init.subtitle").dimmed());
     println!();
 
     let mut config = Config {
@@ -17,23 +19,23 @@ pub fn run() -> Result<()> {
     };
 
     // Session gap
-    let gap: u32 = CustomType::new("¿Minutos de inactividad para iniciar nueva sesión?")
+    let gap: u32 = CustomType::new(t("init.gap"))
         .with_default(120u32)
-        .with_help_message("Default 120 min (2 horas). Ajusta según tu estilo de trabajo.")
+        .with_help_message(t("init.gap_help"))
         .prompt()?;
     config.settings.session_gap_minutes = gap;
 
     // First commit time
-    let first: u32 = CustomType::new("¿Minutos base por primer commit de sesión?")
+    let first: u32 = CustomType::new(t("init.first_commit"))
         .with_default(30u32)
-        .with_help_message("Tiempo que se asume trabajado antes del primer commit. Default 30 min.")
+        .with_help_message(t("init.first_commit_help"))
         .prompt()?;
     config.settings.first_commit_minutes = first;
 
     // Add clients
     loop {
         println!();
-        let client_name = Text::new("Nombre del cliente (vacío para terminar):")
+        let client_name = Text::new(t("init.client_name"))
             .prompt()?;
         if client_name.trim().is_empty() {
             break;
@@ -41,28 +43,29 @@ pub fn run() -> Result<()> {
 
         let mut repos: Vec<PathBuf> = Vec::new();
         loop {
-            let repo_path = Text::new("  Ruta al repositorio (vacío para terminar):")
-                .with_help_message("Ruta absoluta, ej: /home/user/mi-proyecto")
+            let repo_path = Text::new(t("init.repo_path"))
+                .with_help_message(t("init.repo_path_help"))
                 .prompt()?;
             if repo_path.trim().is_empty() {
                 break;
             }
             let path = PathBuf::from(repo_path.trim());
             if !path.exists() {
-                println!("{}", "  Advertencia: la ruta no existe (se agregará de todas formas)".yellow());
+                println!("{}", t("init.repo_missing").yellow());
             }
             repos.push(path);
         }
 
-        let rate: f64 = CustomType::new("  Tasa horaria (0 para no configurar):")
-            .with_default(0.0f64)
+        let rate: Decimal = CustomType::new(t("init.rate"))
+            .with_default(Decimal::ZERO)
             .prompt()?;
 
-        let currency = if rate > 0.0 {
-            let options = vec!["USD", "EUR", "GBP", "ARS", "MXN", "Otra"];
-            let sel = Select::new("  Moneda:", options).prompt()?;
-            if sel == "Otra" {
-                Text::new("  Código de moneda (ej: CLP):").prompt()?
+        let currency = if rate > Decimal::ZERO {
+            let other = t("init.currency_other");
+            let options = vec!["USD", "EUR", "GBP", "ARS", "MXN", other];
+            let sel = Select::new(t("init.currency"), options).prompt()?;
+            if sel == other {
+                Text::new(t("init.currency_code")).prompt()?
             } else {
                 sel.to_string()
             }
@@ -76,11 +79,11 @@ pub fn run() -> Result<()> {
                 repos,
                 hourly_rate: rate,
                 currency,
-                llm_cost_per_kloc: 0.0,
+                ..Default::default()
             },
         );
 
-        let add_more = Confirm::new("¿Agregar otro cliente?")
+        let add_more = Confirm::new(t("init.add_another"))
             .with_default(false)
             .prompt()?;
         if !add_more {
@@ -89,19 +92,19 @@ pub fn run() -> Result<()> {
     }
 
     if config.client.is_empty() {
-        println!("{}", "\nNo se configuró ningún cliente. Puedes editar el config manualmente.".yellow());
+        println!("\n{}", t("init.no_clients").yellow());
     }
 
     save_config(&config)?;
 
     let config_path = crate::config::config_path()?;
     println!();
-    println!("{}", "Configuración guardada exitosamente.".green().bold());
+    println!("{}", t("init.saved").green().bold());
     println!("  {}", config_path.display().to_string().dimmed());
     println!();
-    println!("Próximos pasos:");
-    println!("  {}  Ver horas de hoy y esta semana", "gtt status".cyan());
-    println!("  {}  Ver reporte del mes anterior", "gtt report --last-month".cyan());
+    println!("{}", t("init.next_steps"));
+    println!("  {}  {}", "gtt status".cyan(), t("init.next_status"));
+    println!("  {}  {}", "gtt report --last-month".cyan(), t("init.next_report"));
     println!();
 
     Ok(())
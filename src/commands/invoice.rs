@@ -0,0 +1,194 @@
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Table};
+use crate::commands::report::{build_client_report, resolve_date_range, ReportArgs};
+use crate::config::load_config;
+use crate::errors::GttError;
+use crate::i18n::{format_date, format_money, t, tf};
+use crate::store::{InvoiceRecord, InvoiceStore};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum InvoiceAction {
+    /// Registra una factura con el total del reporte del periodo
+    Create {
+        /// Nombre del cliente
+        #[arg(long)]
+        client: String,
+
+        /// Semana pasada
+        #[arg(long)]
+        last_week: bool,
+
+        /// Mes pasado
+        #[arg(long)]
+        last_month: bool,
+
+        /// Fecha de inicio (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Fecha de fin (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Número de factura (default: AAAA-NNN correlativo)
+        #[arg(long)]
+        number: Option<String>,
+
+        /// Fecha de emisión (YYYY-MM-DD, default: hoy)
+        #[arg(long)]
+        date: Option<String>,
+    },
+    /// Lista las facturas registradas con lo cobrado y lo pendiente
+    List {
+        /// Nombre del cliente (todos si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
+    },
+}
+
+pub fn run(action: InvoiceAction) -> Result<()> {
+    match action {
+        InvoiceAction::Create {
+            client,
+            last_week,
+            last_month,
+            since,
+            until,
+            number,
+            date,
+        } => create(
+            ReportArgs {
+                client: Some(client),
+                last_week,
+                last_month,
+                since,
+                until,
+                format: "table".to_string(),
+                output: None,
+                in_currency: None,
+                rate_date: "invoice".to_string(),
+                sessions: false,
+                postings: "period".to_string(),
+                detail: None,
+                group_by: "day".to_string(),
+                verify_mode: false,
+            },
+            number,
+            date,
+        ),
+        InvoiceAction::List { client } => list(client),
+    }
+}
+
+fn create(args: ReportArgs, number: Option<String>, date: Option<String>) -> Result<()> {
+    let config = load_config()?;
+    let client_name = args.client.clone().unwrap_or_default();
+    let client_cfg = config
+        .client
+        .get(&client_name)
+        .ok_or_else(|| GttError::ClientNotFound(client_name.clone()))?;
+    let (since, until) = resolve_date_range(&args)?;
+    let issued = parse_date_or_today(date)?;
+
+    let report = build_client_report(&client_name, client_cfg, since, until, &config.settings)?;
+    let amount = report.total_amount();
+    if amount.is_zero() {
+        bail!(tf(
+            "invoice.nothing_to_bill",
+            &[("client", &client_name), ("since", &format_date(since)), ("until", &format_date(until))]
+        ));
+    }
+
+    let mut store = InvoiceStore::load()?;
+    if let Some(existing) = store
+        .invoices
+        .iter()
+        .find(|i| i.client == client_name && i.period_start <= until && i.period_end >= since)
+    {
+        bail!(tf(
+            "invoice.overlaps",
+            &[
+                ("number", &existing.number),
+                ("since", &format_date(existing.period_start)),
+                ("until", &format_date(existing.period_end)),
+            ]
+        ));
+    }
+    let number = number.unwrap_or_else(|| store.next_number(issued));
+    store.add(InvoiceRecord {
+        number: number.clone(),
+        client: client_name.clone(),
+        issued,
+        period_start: since,
+        period_end: until,
+        amount,
+        currency: report.currency.clone(),
+        payments: Vec::new(),
+    })?;
+    store.save()?;
+
+    println!(
+        "{}",
+        tf(
+            "invoice.added",
+            &[
+                ("number", &number),
+                ("client", &client_name),
+                ("amount", &format!("{} {}", format_money(amount), report.currency)),
+                ("since", &format_date(since)),
+                ("until", &format_date(until)),
+            ]
+        )
+        .green()
+    );
+    Ok(())
+}
+
+fn list(client: Option<String>) -> Result<()> {
+    let store = InvoiceStore::load()?;
+    let mut invoices: Vec<_> = store
+        .invoices
+        .iter()
+        .filter(|i| client.as_ref().is_none_or(|c| *c == i.client))
+        .collect();
+    invoices.sort_by_key(|i| (i.issued, i.number.clone()));
+
+    if invoices.is_empty() {
+        println!("{}", t("invoice.none").yellow());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic).set_header(vec![
+        Cell::new(t("col.invoice")).fg(Color::Cyan),
+        Cell::new(t("col.issued")).fg(Color::Cyan),
+        Cell::new(t("col.client")).fg(Color::Cyan),
+        Cell::new(t("col.amount")).fg(Color::Cyan),
+        Cell::new(t("col.paid")).fg(Color::Cyan),
+        Cell::new(t("col.outstanding")).fg(Color::Cyan),
+    ]);
+    for i in invoices {
+        let pending = Cell::new(format!("{} {}", format_money(i.outstanding()), i.currency))
+            .set_alignment(CellAlignment::Right);
+        table.add_row(vec![
+            Cell::new(&i.number),
+            Cell::new(format_date(i.issued)),
+            Cell::new(&i.client),
+            Cell::new(format!("{} {}", format_money(i.amount), i.currency)).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{} {}", format_money(i.paid()), i.currency)).set_alignment(CellAlignment::Right),
+            if i.is_paid() { pending.fg(Color::Green) } else { pending.fg(Color::Yellow) },
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+pub(crate) fn parse_date_or_today(date: Option<String>) -> Result<NaiveDate> {
+    match date {
+        Some(ref d) => Ok(NaiveDate::parse_from_str(d, "%Y-%m-%d")
+            .map_err(|_| GttError::DateParse(tf("date.invalid", &[("value", d)])))?),
+        None => Ok(Local::now().date_naive()),
+    }
+}
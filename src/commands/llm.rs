@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use crate::i18n::{format_money, tf};
+use crate::import::llm::parse_usage;
+use crate::store::LlmUsageStore;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum LlmAction {
+    /// Importa el uso real de LLM desde un export del proveedor (CSV o JSON)
+    Import {
+        /// Archivo exportado por el proveedor
+        file: PathBuf,
+
+        /// Formato del archivo: csv, json, openai (default: autodetectar)
+        #[arg(long)]
+        format: Option<String>,
+
+        /// Nombre del proveedor guardado con cada registro (default: el formato)
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Etiqueta (cliente o uno de sus llm_tags) para los registros sin etiqueta
+        #[arg(long)]
+        tag: Option<String>,
+    },
+}
+
+pub fn run(action: LlmAction) -> Result<()> {
+    match action {
+        LlmAction::Import {
+            file,
+            format,
+            provider,
+            tag,
+        } => import(&file, format.as_deref(), provider, tag),
+    }
+}
+
+fn import(file: &PathBuf, format: Option<&str>, provider: Option<String>, tag: Option<String>) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| tf("common.read_failed", &[("path", &file.display())]))?;
+    let mut entries = parse_usage(&content, format)?;
+    for entry in entries.iter_mut() {
+        if let Some(ref provider) = provider {
+            entry.provider = provider.clone();
+        }
+        if entry.tag.is_none() {
+            entry.tag = tag.clone();
+        }
+    }
+
+    let mut totals: BTreeMap<String, Decimal> = BTreeMap::new();
+    for entry in &entries {
+        *totals.entry(entry.currency.clone()).or_default() += entry.cost;
+    }
+    let read = entries.len();
+
+    let mut store = LlmUsageStore::load()?;
+    let added = store.import(entries);
+    store.save()?;
+
+    let totals: Vec<String> = totals
+        .iter()
+        .map(|(currency, total)| format!("{} {}", format_money(*total), currency))
+        .collect();
+    println!(
+        "{}",
+        tf(
+            "llm.imported",
+            &[
+                ("added", &added),
+                ("read", &read),
+                ("existing", &(read - added)),
+                ("cost", &if totals.is_empty() { "0.00".to_string() } else { totals.join(", ") }),
+            ]
+        )
+        .green()
+    );
+    Ok(())
+}
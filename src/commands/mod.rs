@@ -1,6 +1,15 @@
+pub mod balance;
+pub mod budget;
 pub mod config_cmd;
+pub mod expense;
 pub mod export;
 pub mod init;
+pub mod invoice;
+pub mod llm;
+pub mod payments;
+pub mod receivables;
 pub mod report;
+pub mod schema;
 pub mod status;
+pub mod time;
 pub mod verify;
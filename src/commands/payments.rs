@@ -0,0 +1,51 @@
+use anyhow::Result;
+use colored::Colorize;
+use rust_decimal::Decimal;
+use crate::commands::invoice::parse_date_or_today;
+use crate::i18n::{format_money, tf};
+use crate::store::InvoiceStore;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum PaymentsAction {
+    /// Registra un pago (total o parcial) de una factura
+    Record {
+        /// Número de factura (ver `gtt invoice list`)
+        invoice: String,
+
+        /// Monto cobrado, en la moneda de la factura
+        #[arg(long)]
+        amount: Decimal,
+
+        /// Fecha del cobro (YYYY-MM-DD, default: hoy)
+        #[arg(long)]
+        date: Option<String>,
+    },
+}
+
+pub fn run(action: PaymentsAction) -> Result<()> {
+    match action {
+        PaymentsAction::Record { invoice, amount, date } => record(&invoice, amount, date),
+    }
+}
+
+fn record(number: &str, amount: Decimal, date: Option<String>) -> Result<()> {
+    let date = parse_date_or_today(date)?;
+    let mut store = InvoiceStore::load()?;
+    let invoice = store.record_payment(number, amount, date)?.clone();
+    store.save()?;
+
+    let line = if invoice.is_paid() {
+        tf("payments.paid", &[("number", &invoice.number)])
+    } else {
+        tf(
+            "payments.recorded",
+            &[
+                ("number", &invoice.number),
+                ("outstanding", &format!("{} {}", format_money(invoice.outstanding()), invoice.currency)),
+                ("amount", &format!("{} {}", format_money(invoice.amount), invoice.currency)),
+            ]
+        )
+    };
+    println!("{}", line.green());
+    Ok(())
+}
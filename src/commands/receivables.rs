@@ -0,0 +1,28 @@
+use anyhow::Result;
+use chrono::Local;
+use colored::Colorize;
+use crate::i18n::t;
+use crate::output::print_receivables;
+use crate::session::receivables::receivables;
+use crate::store::InvoiceStore;
+
+pub struct ReceivablesArgs {
+    pub client: Option<String>,
+}
+
+pub fn run(args: ReceivablesArgs) -> Result<()> {
+    let store = InvoiceStore::load()?;
+    let today = Local::now().date_naive();
+
+    let groups: Vec<_> = receivables(&store.invoices, today)
+        .into_iter()
+        .filter(|g| args.client.as_ref().is_none_or(|c| *c == g.client))
+        .collect();
+
+    if groups.is_empty() {
+        println!("{}", t("receivables.none").green());
+        return Ok(());
+    }
+    print_receivables(&groups);
+    Ok(())
+}
@@ -1,15 +1,42 @@
 use anyhow::{bail, Result};
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Utc};
 use colored::Colorize;
+use rust_decimal::Decimal;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::Path;
-use crate::config::{load_config, ClientConfig};
+use crate::config::{exchange_rates_path, load_config, ClientConfig, RetainerConfig, Settings};
 use crate::errors::GttError;
-use crate::git::{get_repo_user_email, merge_numstat, parse_git_log, parse_numstat, run_git_log, run_git_log_numstat};
-use crate::output::{print_client_report, print_verify_report};
+use crate::i18n::{format_date, t, tf};
+use crate::git::{
+    get_repo_user_email, merge_branches, merge_files, merge_numstat, parse_branch_map, parse_git_log,
+    parse_numstat, parse_numstat_files, run_git_branch_map, run_git_log, run_git_log_numstat,
+};
+use crate::output::{format_duration, print_client_report, print_grand_total, print_verify_report, Detail};
 use crate::output::csv::serialize_csv;
+use crate::output::html::serialize_html;
+use crate::output::ics::serialize_ics;
 use crate::output::json_fmt::serialize_json;
-use crate::session::{analyze, group_by_day};
-use crate::session::types::ClientReport;
+use crate::output::ledger::{serialize_ledger, serialize_timeclock, LedgerFlavor, Postings};
+use crate::output::markdown::serialize_markdown;
+use crate::output::ndjson::write_ndjson;
+use crate::output::ods::write_ods;
+use crate::output::org::serialize_org;
+use crate::output::spreadsheet::build_workbook;
+use crate::output::time_entries::{import_target, serialize_time_entries};
+use crate::output::timewarrior::serialize_timewarrior;
+use crate::output::xlsx::write_xlsx;
+use crate::session::{
+    analyze, apply_retainer, attribute_usage, bill_by_month, bill_sessions, convert_report, trim_manual_overlaps,
+    ExchangeRates, GroupBy, RateDate, RetainerPeriod,
+};
+use crate::session::llm::usage_date;
+use crate::session::types::{llm_cost, ClientReport, DayReport, Session};
+use crate::store::{ExpenseStore, LlmUsageEntry, LlmUsageStore, ManualTimeStore};
+
+/// Every value accepted by `--format`
+const FORMATS: &str =
+    "table, csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods";
 
 pub struct ReportArgs {
     pub client: Option<String>,
@@ -19,6 +46,18 @@ pub struct ReportArgs {
     pub until: Option<String>,
     pub format: String,
     pub output: Option<String>,
+    /// Convert amounts into this currency
+    pub in_currency: Option<String>,
+    /// "invoice" or "day": which date's exchange rate to use
+    pub rate_date: String,
+    /// Include the per-session breakdown (markdown)
+    pub sessions: bool,
+    /// "day" or "period": how ledger and beancount postings are grouped
+    pub postings: String,
+    /// "sessions" or "commits": rows below the per-day aggregates (JSON and CSV)
+    pub detail: Option<String>,
+    /// "day", "week", "month", "repo" or "weekday": rows of the table, CSV and JSON
+    pub group_by: String,
     pub verify_mode: bool,
 }
 
@@ -38,23 +77,56 @@ pub fn run(args: ReportArgs) -> Result<()> {
     };
 
     if clients.is_empty() {
-        bail!("No hay clientes configurados. Ejecuta `gtt init` para comenzar.");
+        bail!(t("report.no_clients"));
+    }
+
+    let conversion = match args.in_currency {
+        Some(ref currency) => Some((currency.to_uppercase(), load_exchange_rates(&config.settings)?, parse_rate_date(&args.rate_date)?)),
+        None => None,
+    };
+    let postings = parse_postings(&args.postings)?;
+    let detail = parse_detail(args.detail.as_deref())?;
+    let group_by = parse_group_by(&args.group_by)?;
+    // NDJSON writes each client's records into one writer once that client's
+    // report is built, so memory holds one client at a time
+    let mut ndjson: Option<Box<dyn Write>> = match (args.format.as_str(), &args.output) {
+        ("ndjson", Some(path)) => Some(Box::new(BufWriter::new(File::create(path)?))),
+        ("ndjson", None) => Some(Box::new(BufWriter::new(std::io::stdout().lock()))),
+        _ => None,
+    };
+    // Spreadsheets hold every client's sheets in one workbook
+    let mut workbook_reports: Vec<ClientReport> = Vec::new();
+    if matches!(args.format.as_str(), "xlsx" | "ods") && args.output.is_none() {
+        bail!(tf("report.binary_format", &[("format", &args.format)]));
     }
+    let mut grand_total = Decimal::ZERO;
+    let mut converted_clients = 0;
 
     for (client_name, client_cfg) in clients {
-        let report = build_client_report(&client_name, client_cfg, since, until, &config.settings.bot_authors)?;
-
-        if report.days.is_empty() {
-            println!(
-                "{}",
-                format!(
-                    "Sin commits para '{}' entre {} y {}.",
-                    client_name,
-                    since.format("%d/%m/%Y"),
-                    until.format("%d/%m/%Y"),
-                )
-                .yellow()
-            );
+        let mut report = build_client_report(&client_name, client_cfg, since, until, &config.settings)?;
+        if let Some((ref currency, ref rates, rate_date)) = conversion {
+            let converted = convert_report(&report, rates, currency, rate_date)?;
+            grand_total += converted.total_amount;
+            converted_clients += 1;
+            report.converted = Some(converted);
+        }
+
+        if report.days.is_empty() && report.expenses.is_empty() {
+            let notice = tf(
+                "report.no_commits",
+                &[
+                    ("client", &client_name),
+                    ("since", &format_date(since)),
+                    ("until", &format_date(until)),
+                ],
+            )
+            .yellow();
+            // Keep machine-readable output on stdout parseable
+            if args.format == "table" {
+                println!("{}", notice);
+            } else {
+                eprintln!("{}", notice);
+            }
             continue;
         }
 
@@ -63,44 +135,259 @@ pub fn run(args: ReportArgs) -> Result<()> {
                 if args.verify_mode {
                     print_verify_report(&report);
                 } else {
-                    print_client_report(&report);
+                    print_client_report(&report, group_by);
                 }
             }
             "csv" => {
-                let data = serialize_csv(&report)?;
+                let data = serialize_csv(&report, detail, group_by)?;
                 output_data(&data, &args.output, &client_name, "csv")?;
             }
             "json" => {
-                let data = serialize_json(&report)?;
+                let data = serialize_json(&report, detail, group_by)?;
                 output_data(&data, &args.output, &client_name, "json")?;
             }
-            other => bail!("Formato no soportado: '{}'. Usa: table, csv, json", other),
+            "xlsx" | "ods" => workbook_reports.push(report),
+            "ndjson" => {
+                if let Some(ref mut out) = ndjson {
+                    write_ndjson(out, &report, detail)?;
+                    out.flush()?;
+                }
+            }
+            "html" => {
+                let data = serialize_html(&report);
+                output_data(&data, &args.output, &client_name, "html")?;
+            }
+            "ics" => {
+                let data = serialize_ics(&report, Utc::now());
+                output_data(&data, &args.output, &client_name, "ics")?;
+            }
+            "timeclock" => {
+                let data = serialize_timeclock(&report, &client_cfg.accounts);
+                output_data(&data, &args.output, &client_name, "timeclock")?;
+            }
+            "ledger" | "beancount" => {
+                let flavor = if args.format == "ledger" { LedgerFlavor::Ledger } else { LedgerFlavor::Beancount };
+                let data = serialize_ledger(&report, &client_cfg.accounts, flavor, postings)?;
+                output_data(&data, &args.output, &client_name, &args.format)?;
+            }
+            "markdown" => {
+                let data = serialize_markdown(&report, args.sessions);
+                output_data(&data, &args.output, &client_name, "md")?;
+            }
+            "timewarrior" => {
+                let data = serialize_timewarrior(&report)?;
+                output_data(&data, &args.output, &client_name, "json")?;
+            }
+            "org" => {
+                let data = serialize_org(&report);
+                output_data(&data, &args.output, &client_name, "org")?;
+            }
+            other => match import_target(other) {
+                Some(target) => {
+                    let data = serialize_time_entries(&report, target)?;
+                    output_data(&data, &args.output, &client_name, "csv")?;
+                }
+                None => bail!(tf("report.unsupported_format", &[("format", &other), ("formats", &FORMATS)])),
+            },
+        }
+    }
+
+    if let (Some(_), Some(path)) = (ndjson, &args.output) {
+        println!("{}", tf("common.saved_to", &[("path", path)]).green());
+    }
+
+    if let (false, Some(path)) = (workbook_reports.is_empty(), &args.output) {
+        let book = build_workbook(&workbook_reports);
+        let data = if args.format == "xlsx" { write_xlsx(&book)? } else { write_ods(&book)? };
+        std::fs::write(path, data)?;
+        println!("{}", tf("common.saved_to", &[("path", path)]).green());
+    }
+
+    if let Some((ref currency, _, _)) = conversion {
+        if args.format == "table" && !args.verify_mode && converted_clients > 1 {
+            print_grand_total(grand_total, currency, converted_clients);
         }
     }
 
     Ok(())
 }
 
-fn build_client_report(
+/// Read the offline exchange-rate table configured in `settings`.
+pub fn load_exchange_rates(settings: &Settings) -> Result<ExchangeRates> {
+    let path = exchange_rates_path(settings)?;
+    let data = std::fs::read_to_string(&path).map_err(|_| {
+        GttError::ExchangeRateParse(tf("report.rates_unreadable", &[("path", &path.display())]))
+    })?;
+    Ok(ExchangeRates::parse(&data)?)
+}
+
+fn parse_rate_date(value: &str) -> Result<RateDate> {
+    match value {
+        "invoice" => Ok(RateDate::Invoice),
+        "day" => Ok(RateDate::Day),
+        other => bail!(tf("report.unsupported_rate_date", &[("value", &other)])),
+    }
+}
+
+fn parse_postings(value: &str) -> Result<Postings> {
+    match value {
+        "period" => Ok(Postings::Period),
+        "day" => Ok(Postings::Day),
+        other => bail!(tf("report.unsupported_postings", &[("value", &other)])),
+    }
+}
+
+fn parse_detail(value: Option<&str>) -> Result<Option<Detail>> {
+    match value {
+        None => Ok(None),
+        Some("sessions") => Ok(Some(Detail::Sessions)),
+        Some("commits") => Ok(Some(Detail::Commits)),
+        Some(other) => bail!(tf("report.unsupported_detail", &[("value", &other)])),
+    }
+}
+
+fn parse_group_by(value: &str) -> Result<GroupBy> {
+    match GroupBy::parse(value) {
+        Some(group_by) => Ok(group_by),
+        None => bail!(tf("report.unsupported_group_by", &[("value", &value), ("values", &GroupBy::NAMES)])),
+    }
+}
+
+pub fn build_client_report(
     client_name: &str,
     client_cfg: &ClientConfig,
     since: NaiveDate,
     until: NaiveDate,
-    bot_authors: &[String],
+    settings: &Settings,
 ) -> Result<ClientReport> {
+    let days = match client_cfg.retainer {
+        // Retainer coverage depends on everything billed since the retainer started
+        Some(ref retainer) if retainer.start <= until => {
+            let (history, _) = retainer_history(client_name, client_cfg, retainer, since, until, settings)?;
+            history.into_iter().filter(|d| d.date >= since).collect()
+        }
+        _ => collect_days(client_cfg, Some(client_name), since, until, settings)?,
+    };
+
+    let total_minutes = days.iter().map(|d| d.total_minutes).sum();
+    let billed_minutes = days.iter().map(|d| d.billed_minutes).sum();
+    let overflow_minutes = days.iter().map(|d| d.overflow_minutes).sum();
+    let covered_minutes = days.iter().map(|d| d.covered_minutes).sum();
+    let total_commits = days.iter().map(|d| d.total_commits).sum();
+    let total_lines_added = days.iter().map(|d| d.total_lines_added).sum();
+    let total_lines_deleted = days.iter().map(|d| d.total_lines_deleted).sum();
+
+    let llm_usage = client_llm_usage(client_name, client_cfg, &days, since, until, settings)?;
+    let llm_cost = if llm_usage.is_empty() {
+        llm_cost(total_lines_added + total_lines_deleted, client_cfg.llm_cost_per_kloc)
+    } else {
+        llm_usage.iter().map(|u| u.cost).sum()
+    };
+
+    Ok(ClientReport {
+        client_name: client_name.to_string(),
+        period_start: since,
+        period_end: until,
+        days,
+        total_minutes,
+        billed_minutes,
+        overflow_minutes,
+        covered_minutes,
+        total_commits,
+        hourly_rate: client_cfg.rate_on(until),
+        currency: client_cfg.currency.clone(),
+        total_lines_added,
+        total_lines_deleted,
+        llm_cost_per_kloc: client_cfg.llm_cost_per_kloc,
+        llm_usage,
+        llm_cost,
+        expenses: ExpenseStore::load()?.for_client(client_name, since, until),
+        discounts: client_cfg.discounts.clone(),
+        tax_rules: client_cfg.tax_rules.clone(),
+        converted: None,
+        commit_urls: client_cfg.commit_urls.clone(),
+    })
+}
+
+/// Imported LLM usage attributed to the client, converted into its currency.
+fn client_llm_usage(
+    client_name: &str,
+    client_cfg: &ClientConfig,
+    days: &[DayReport],
+    since: NaiveDate,
+    until: NaiveDate,
+    settings: &Settings,
+) -> Result<Vec<LlmUsageEntry>> {
+    let store = LlmUsageStore::load()?;
+    let mut usage = attribute_usage(&store.entries, client_name, &client_cfg.llm_tags, days, since, until);
+    if usage.iter().any(|u| !u.currency.eq_ignore_ascii_case(&client_cfg.currency)) {
+        let rates = load_exchange_rates(settings)?;
+        for entry in usage.iter_mut() {
+            entry.cost *= rates.require(&entry.currency, &client_cfg.currency, usage_date(entry))?;
+            entry.currency = client_cfg.currency.clone();
+        }
+    }
+    usage.sort_by_key(|u| u.timestamp);
+    Ok(usage)
+}
+
+/// Billed days from the retainer start (or `since`, if earlier) up to `until`,
+/// with retainer coverage applied, plus the month-by-month balance.
+///
+/// The repos are read once. Days from `since` on are billed as their own
+/// period, exactly as a report of `since..until` bills them, so rounding and
+/// caps match the report; the days before are billed a calendar month at a
+/// time, as monthly reports would bill them.
+pub fn retainer_history(
+    client_name: &str,
+    client_cfg: &ClientConfig,
+    retainer: &RetainerConfig,
+    since: NaiveDate,
+    until: NaiveDate,
+    settings: &Settings,
+) -> Result<(Vec<DayReport>, Vec<RetainerPeriod>)> {
+    if retainer.start > until {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let sessions = collect_sessions(client_cfg, Some(client_name), retainer.start.min(since), until, settings)?;
+    let (before, within): (Vec<Session>, Vec<Session>) = sessions.into_iter().partition(|s| s.date() < since);
+    let mut history = bill_by_month(before, client_cfg, settings);
+    history.extend(bill_sessions(within, client_cfg, settings));
+    let periods = apply_retainer(&mut history, retainer, until);
+    Ok((history, periods))
+}
+
+/// Read the client's repos and turn their commits into billed days:
+/// sessions → days → rounding → caps → rates.
+///
+/// Manual time entries of `manual_client` are merged in as sessions without
+/// commits (`None` = commits only, e.g. for budgets scoped to some repos).
+pub fn collect_days(
+    client_cfg: &ClientConfig,
+    manual_client: Option<&str>,
+    since: NaiveDate,
+    until: NaiveDate,
+    settings: &Settings,
+) -> Result<Vec<DayReport>> {
+    let sessions = collect_sessions(client_cfg, manual_client, since, until, settings)?;
+    Ok(bill_sessions(sessions, client_cfg, settings))
+}
+
+/// The client's git sessions between `since` and `until`, plus the manual
+/// time of `manual_client`, sorted by start
+fn collect_sessions(
+    client_cfg: &ClientConfig,
+    manual_client: Option<&str>,
+    since: NaiveDate,
+    until: NaiveDate,
+    settings: &Settings,
+) -> Result<Vec<Session>> {
     let mut all_commits = Vec::new();
 
     for repo_path in &client_cfg.repos {
         let path = Path::new(repo_path);
         if !path.exists() {
-            eprintln!(
-                "{}",
-                format!(
-                    "Advertencia: repositorio no encontrado: {}",
-                    path.display()
-                )
-                .yellow()
-            );
+            eprintln!("{}", tf("report.repo_not_found", &[("path", &path.display())]).yellow());
             continue;
         }
 
@@ -110,54 +397,46 @@ fn build_client_report(
         let raw = match run_git_log(path, Some(since), Some(until), author_email.as_deref()) {
             Ok(r) => r,
             Err(e) => {
-                eprintln!("{}", format!("Advertencia: error en {}: {}", path.display(), e).yellow());
+                eprintln!("{}", tf("report.repo_error", &[("path", &path.display()), ("error", &e)]).yellow());
                 continue;
             }
         };
 
-        let mut commits = parse_git_log(&raw, path, bot_authors)?;
+        let mut commits = parse_git_log(&raw, path, &settings.bot_authors)?;
 
         // Fetch and merge numstat (lines added/deleted per commit)
         if let Ok(numstat_raw) = run_git_log_numstat(path, Some(since), Some(until), author_email.as_deref()) {
             let numstat_map = parse_numstat(&numstat_raw);
             merge_numstat(&mut commits, &numstat_map);
+            merge_files(&mut commits, &parse_numstat_files(&numstat_raw));
+        }
+
+        // Branch names are only resolved when a rate rule needs them
+        if client_cfg.rate_rules.iter().any(|r| r.branch.is_some()) {
+            if let Ok(branch_raw) = run_git_branch_map(path) {
+                merge_branches(&mut commits, &parse_branch_map(&branch_raw));
+            }
         }
 
         all_commits.append(&mut commits);
     }
 
-    // Load actual settings from config
-    let config = load_config().unwrap_or_default();
-    let settings = config.settings;
-
-    let sessions = analyze(all_commits, &settings);
-    let days = group_by_day(sessions);
-
-    let total_minutes = days.iter().map(|d| d.total_minutes).sum();
-    let total_commits = days.iter().map(|d| d.total_commits).sum();
-    let total_lines_added = days.iter().map(|d| d.total_lines_added).sum();
-    let total_lines_deleted = days.iter().map(|d| d.total_lines_deleted).sum();
-
-    let total_lines = (total_lines_added + total_lines_deleted) as f64;
-    let llm_cost = (total_lines / 1000.0) * client_cfg.llm_cost_per_kloc;
-
-    Ok(ClientReport {
-        client_name: client_name.to_string(),
-        period_start: since,
-        period_end: until,
-        days,
-        total_minutes,
-        total_commits,
-        hourly_rate: client_cfg.hourly_rate,
-        currency: client_cfg.currency.clone(),
-        total_lines_added,
-        total_lines_deleted,
-        llm_cost_per_kloc: client_cfg.llm_cost_per_kloc,
-        llm_cost,
-    })
+    let mut sessions = analyze(all_commits, settings);
+    if let Some(client) = manual_client {
+        let manual = ManualTimeStore::load()?.for_client(client, since, until);
+        // Time tracked while committing is already billed by the git sessions
+        let (manual, trimmed) = trim_manual_overlaps(&sessions, manual.iter().map(|e| e.to_session()).collect());
+        if trimmed > 0 {
+            let duration = format_duration(trimmed);
+            eprintln!("{}", tf("report.manual_overlap", &[("client", &client), ("duration", &duration)]).yellow());
+        }
+        sessions.extend(manual);
+        sessions.sort_by_key(|s| s.start);
+    }
+    Ok(sessions)
 }
 
-fn resolve_date_range(args: &ReportArgs) -> Result<(NaiveDate, NaiveDate)> {
+pub fn resolve_date_range(args: &ReportArgs) -> Result<(NaiveDate, NaiveDate)> {
     let today = Local::now().date_naive();
 
     if args.last_week {
@@ -185,7 +464,7 @@ fn resolve_date_range(args: &ReportArgs) -> Result<(NaiveDate, NaiveDate)> {
 
     let since = if let Some(ref s) = args.since {
         NaiveDate::parse_from_str(s, "%Y-%m-%d")
-            .map_err(|_| GttError::DateParse(format!("Fecha inválida: '{}'. Usa YYYY-MM-DD.", s)))?
+            .map_err(|_| GttError::DateParse(tf("date.invalid", &[("value", s)])))?
     } else {
         // Default: current month
         NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap()
@@ -193,15 +472,13 @@ fn resolve_date_range(args: &ReportArgs) -> Result<(NaiveDate, NaiveDate)> {
 
     let until = if let Some(ref u) = args.until {
         NaiveDate::parse_from_str(u, "%Y-%m-%d")
-            .map_err(|_| GttError::DateParse(format!("Fecha inválida: '{}'. Usa YYYY-MM-DD.", u)))?
+            .map_err(|_| GttError::DateParse(tf("date.invalid", &[("value", u)])))?
     } else {
         today
     };
 
     if since > until {
-        return Err(GttError::InvalidDateRange(
-            format!("{} es posterior a {}", since, until)
-        ).into());
+        return Err(GttError::InvalidDateRange(tf("date.range_order", &[("since", &since), ("until", &until)])).into());
     }
 
     Ok((since, until))
@@ -210,7 +487,7 @@ fn resolve_date_range(args: &ReportArgs) -> Result<(NaiveDate, NaiveDate)> {
 fn output_data(data: &str, output: &Option<String>, _client_name: &str, _ext: &str) -> Result<()> {
     if let Some(path) = output {
         std::fs::write(path, data)?;
-        println!("{}", format!("Guardado en: {}", path).green());
+        println!("{}", tf("common.saved_to", &[("path", path)]).green());
     } else {
         print!("{}", data);
     }
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use crate::output::json_fmt::REPORT_SCHEMA;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SchemaAction {
+    /// JSON Schema de `gtt report --format json`
+    Report,
+}
+
+pub fn run(action: SchemaAction) -> Result<()> {
+    match action {
+        SchemaAction::Report => print!("{}", REPORT_SCHEMA),
+    }
+    Ok(())
+}
@@ -2,8 +2,10 @@ use anyhow::Result;
 use chrono::{Datelike, Local};
 use colored::Colorize;
 use std::path::Path;
+use crate::commands::budget::client_budget_statuses;
 use crate::config::load_config;
 use crate::git::{get_repo_user_email, parse_git_log, run_git_log};
+use crate::i18n::{format_date, t, tf};
 use crate::output::format_duration;
 use crate::session::analyze;
 
@@ -17,15 +19,18 @@ pub fn run() -> Result<()> {
 
     println!();
     println!("{}", "gtt status".bold());
-    println!("{}", format!("  Hoy: {}    Esta semana: {} — {}",
-        today.format("%d/%m/%Y"),
-        week_start.format("%d/%m/%Y"),
-        today.format("%d/%m/%Y")
-    ).dimmed());
+    println!(
+        "{}",
+        tf(
+            "status.period",
+            &[("today", &format_date(today)), ("week_start", &format_date(week_start))]
+        )
+        .dimmed()
+    );
     println!();
 
     if config.client.is_empty() {
-        println!("{}", "No hay clientes configurados. Ejecuta `gtt init`.".yellow());
+        println!("{}", t("status.no_clients").yellow());
         return Ok(());
     }
 
@@ -61,22 +66,46 @@ pub fn run() -> Result<()> {
             }
         }
 
+        let today_hours = if today_minutes > 0 {
+            format_duration(today_minutes).green().to_string()
+        } else {
+            "0m".dimmed().to_string()
+        };
+        let week_hours = if week_minutes > 0 {
+            format_duration(week_minutes).green().bold().to_string()
+        } else {
+            "0m".dimmed().to_string()
+        };
         println!(
-            "  {} — Hoy: {}  ({} commits)   Esta semana: {}  ({} commits)",
-            client_name.bold(),
-            if today_minutes > 0 {
-                format_duration(today_minutes).green().to_string()
-            } else {
-                "0m".dimmed().to_string()
-            },
-            today_commits,
-            if week_minutes > 0 {
-                format_duration(week_minutes).green().bold().to_string()
-            } else {
-                "0m".dimmed().to_string()
-            },
-            week_commits
+            "  {}",
+            tf(
+                "status.client",
+                &[
+                    ("client", &client_name.bold()),
+                    ("today", &today_hours),
+                    ("today_commits", &today_commits),
+                    ("week", &week_hours),
+                    ("week_commits", &week_commits),
+                ]
+            )
         );
+
+        if let Ok(statuses) = client_budget_statuses(client_name, client_cfg, &config.settings, today) {
+            for budget in statuses.iter().filter(|b| b.over_threshold()) {
+                println!(
+                    "{}",
+                    tf(
+                        "status.budget_alert",
+                        &[
+                            ("name", &budget.name),
+                            ("used", &format!("{:.0}", budget.fraction_used() * 100.0)),
+                            ("threshold", &format!("{:.0}", budget.alert_threshold * 100.0)),
+                        ]
+                    )
+                    .yellow()
+                );
+            }
+        }
     }
 
     println!();
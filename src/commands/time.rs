@@ -0,0 +1,188 @@
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Table};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use crate::config::{load_config, ClientConfig};
+use crate::errors::GttError;
+use crate::i18n::{format_date, format_time, t, tf};
+use crate::import::org::parse_org_clocks;
+use crate::import::timewarrior::parse_timewarrior;
+use crate::import::ImportedInterval;
+use crate::output::format_duration;
+use crate::store::ManualTimeStore;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum TimeAction {
+    /// Importa tiempo manual desde Timewarrior (timew export) o relojes CLOCK de org-mode
+    Import {
+        /// Archivo a importar
+        file: PathBuf,
+
+        /// Formato del archivo: timewarrior, org (default: según la extensión)
+        #[arg(long)]
+        format: Option<String>,
+
+        /// Cliente para los intervalos sin una etiqueta que lo identifique
+        #[arg(long)]
+        client: Option<String>,
+    },
+    /// Lista el tiempo manual registrado
+    List {
+        /// Nombre del cliente (todos si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
+    },
+    /// Elimina una entrada de tiempo manual por su número
+    Remove {
+        /// Número de la entrada (ver `gtt time list`)
+        id: u32,
+    },
+}
+
+pub fn run(action: TimeAction) -> Result<()> {
+    match action {
+        TimeAction::Import { file, format, client } => import(&file, format.as_deref(), client.as_deref()),
+        TimeAction::List { client } => list(client),
+        TimeAction::Remove { id } => remove(id),
+    }
+}
+
+fn import(file: &Path, format: Option<&str>, default_client: Option<&str>) -> Result<()> {
+    let config = load_config()?;
+    if let Some(client) = default_client {
+        if !config.client.contains_key(client) {
+            return Err(GttError::ClientNotFound(client.to_string()).into());
+        }
+    }
+    let content = std::fs::read_to_string(file)
+        .with_context(|| tf("common.read_failed", &[("path", &file.display())]))?;
+    let format = match format {
+        Some(f) => f.to_string(),
+        None if file.extension().is_some_and(|e| e == "org") => "org".to_string(),
+        None => "timewarrior".to_string(),
+    };
+    let intervals = match format.as_str() {
+        "timewarrior" => parse_timewarrior(&content)?,
+        "org" => parse_org_clocks(&content)?,
+        other => bail!(tf("time.unsupported_format", &[("format", &other)])),
+    };
+
+    let mut store = ManualTimeStore::load()?;
+    let (mut added, mut duplicates, mut unassigned) = (0, 0, 0);
+    for interval in &intervals {
+        let Some((client, client_cfg)) = resolve_client(interval, &config.client, default_client) else {
+            unassigned += 1;
+            continue;
+        };
+        let repo = interval
+            .tags
+            .iter()
+            .find(|t| repo_names(client_cfg).any(|r| r == t.as_str()))
+            .cloned();
+        let description = interval.description.clone().unwrap_or_else(|| {
+            let rest: Vec<&str> = interval
+                .tags
+                .iter()
+                .map(String::as_str)
+                .filter(|t| !t.eq_ignore_ascii_case(client) && Some(*t) != repo.as_deref())
+                .collect();
+            if rest.is_empty() { t("time.default_description").to_string() } else { rest.join(", ") }
+        });
+        if store.add(client, interval.start, interval.end, &description, repo, &format) {
+            added += 1;
+        } else {
+            duplicates += 1;
+        }
+    }
+    store.save()?;
+
+    if unassigned > 0 {
+        eprintln!(
+            "{}",
+            tf("time.unassigned", &[("count", &unassigned)]).yellow()
+        );
+    }
+    println!(
+        "{}",
+        tf(
+            "time.imported",
+            &[("added", &added), ("read", &intervals.len()), ("existing", &duplicates)]
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// The configured client named by one of the interval's tags (case-insensitive),
+/// or the `--client` fallback
+fn resolve_client<'a>(
+    interval: &ImportedInterval,
+    clients: &'a HashMap<String, ClientConfig>,
+    default_client: Option<&str>,
+) -> Option<(&'a str, &'a ClientConfig)> {
+    interval
+        .tags
+        .iter()
+        .find_map(|tag| clients.iter().find(|(name, _)| name.eq_ignore_ascii_case(tag)))
+        .or_else(|| default_client.and_then(|c| clients.get_key_value(c)))
+        .map(|(name, cfg)| (name.as_str(), cfg))
+}
+
+fn repo_names(client_cfg: &ClientConfig) -> impl Iterator<Item = String> + '_ {
+    client_cfg
+        .repos
+        .iter()
+        .filter_map(|p| p.file_name().map(|f| f.to_string_lossy().into_owned()))
+}
+
+fn list(client: Option<String>) -> Result<()> {
+    let store = ManualTimeStore::load()?;
+    let mut entries: Vec<_> = store
+        .entries
+        .iter()
+        .filter(|e| client.as_ref().is_none_or(|c| *c == e.client))
+        .collect();
+    entries.sort_by_key(|e| (e.start, e.id));
+
+    if entries.is_empty() {
+        println!("{}", t("time.none").yellow());
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic).set_header(vec![
+        Cell::new("#").fg(Color::Cyan),
+        Cell::new(t("col.date")).fg(Color::Cyan),
+        Cell::new(t("col.time")).fg(Color::Cyan),
+        Cell::new(t("col.client")).fg(Color::Cyan),
+        Cell::new(t("col.description")).fg(Color::Cyan),
+        Cell::new(t("col.repo")).fg(Color::Cyan),
+        Cell::new(t("col.duration")).fg(Color::Cyan),
+        Cell::new(t("col.source")).fg(Color::Cyan),
+    ]);
+    for e in entries {
+        table.add_row(vec![
+            Cell::new(e.id.to_string()).set_alignment(CellAlignment::Right),
+            Cell::new(format_date(e.date())),
+            Cell::new(format!("{}–{}", format_time(e.start), format_time(e.end))),
+            Cell::new(&e.client),
+            Cell::new(&e.description),
+            Cell::new(e.repo.as_deref().unwrap_or("")),
+            Cell::new(format_duration(e.duration_minutes())).set_alignment(CellAlignment::Right),
+            Cell::new(&e.source),
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+fn remove(id: u32) -> Result<()> {
+    let mut store = ManualTimeStore::load()?;
+    if !store.remove(id) {
+        bail!(tf("time.not_found", &[("id", &id)]));
+    }
+    store.save()?;
+    println!("{}", tf("time.removed", &[("id", &id)]).green());
+    Ok(())
+}
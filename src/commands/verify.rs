@@ -18,6 +18,12 @@ pub fn run(args: VerifyArgs) -> Result<()> {
         until: args.until,
         format: "table".to_string(),
         output: None,
+        in_currency: None,
+        rate_date: "invoice".to_string(),
+        sessions: false,
+        postings: "period".to_string(),
+        detail: None,
+        group_by: "day".to_string(),
         verify_mode: true,
     })
 }
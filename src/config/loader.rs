@@ -1,21 +1,31 @@
 use std::path::PathBuf;
 use anyhow::{Context, Result};
 use crate::errors::GttError;
-use super::types::Config;
+use crate::i18n::{t, tf};
+use super::types::{Config, Settings};
 
 pub fn config_path() -> Result<PathBuf> {
     let config_dir = dirs::config_dir()
-        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+        .ok_or_else(|| anyhow::anyhow!(t("config.no_config_dir")))?;
     Ok(config_dir.join("gtt").join("config.toml"))
 }
 
+/// Exchange-rate table location: `settings.exchange_rates_file`, or
+/// `exchange_rates.csv` in the config directory.
+pub fn exchange_rates_path(settings: &Settings) -> Result<PathBuf> {
+    match settings.exchange_rates_file {
+        Some(ref path) => Ok(path.clone()),
+        None => Ok(config_path()?.with_file_name("exchange_rates.csv")),
+    }
+}
+
 pub fn load_config() -> Result<Config> {
     let path = config_path()?;
     if !path.exists() {
         return Err(GttError::ConfigNotFound.into());
     }
     let content = std::fs::read_to_string(&path)
-        .with_context(|| format!("Failed to read config at {}", path.display()))?;
+        .with_context(|| tf("common.read_failed", &[("path", &path.display())]))?;
     let config: Config = toml::from_str(&content)
         .map_err(|e| GttError::ConfigParse(e.to_string()))?;
     Ok(config)
@@ -25,11 +35,11 @@ pub fn save_config(config: &Config) -> Result<()> {
     let path = config_path()?;
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create config directory {}", parent.display()))?;
+            .with_context(|| tf("common.create_dir_failed", &[("path", &parent.display())]))?;
     }
     let content = toml::to_string_pretty(config)
         .map_err(|e| GttError::ConfigParse(e.to_string()))?;
     std::fs::write(&path, content)
-        .with_context(|| format!("Failed to write config to {}", path.display()))?;
+        .with_context(|| tf("common.write_failed", &[("path", &path.display())]))?;
     Ok(())
 }
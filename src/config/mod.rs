@@ -1,5 +1,9 @@
 pub mod loader;
 pub mod types;
 
-pub use loader::{config_path, load_config, save_config};
-pub use types::{ClientConfig, Config, Settings};
+pub use loader::{config_path, exchange_rates_path, load_config, save_config};
+#[allow(unused_imports)]
+pub use types::{
+    account_component, AccountsConfig, BudgetConfig, ClientConfig, Config, DiscountRule, PrepaidBlock, RateEntry, RateRule, RetainerConfig,
+    RepoAttribution, RoundingConfig, RoundingMode, RoundingScope, Settings, TaxRule, TimeFormat,
+};
@@ -1,3 +1,5 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -13,23 +15,299 @@ pub struct Config {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfig {
     pub repos: Vec<PathBuf>,
-    #[serde(default = "default_hourly_rate")]
-    pub hourly_rate: f64,
+    #[serde(default)]
+    pub hourly_rate: Decimal,
+    /// Rate history: each entry applies from its date until the next one
+    #[serde(default)]
+    pub rates: Vec<RateEntry>,
+    /// Category rates matched per commit (first matching rule wins)
+    #[serde(default)]
+    pub rate_rules: Vec<RateRule>,
     #[serde(default = "default_currency")]
     pub currency: String,
     /// Cost per 1000 lines changed to offset LLM usage (default 0.0 = disabled)
     #[serde(default)]
-    pub llm_cost_per_kloc: f64,
+    pub llm_cost_per_kloc: Decimal,
+    /// Tags in imported LLM usage that belong to this client (besides its name)
+    #[serde(default)]
+    pub llm_tags: Vec<String>,
+    /// Rounding and minimum-billable-increment policy (default: bill raw minutes)
+    #[serde(default)]
+    pub rounding: Option<RoundingConfig>,
+    /// Maximum billable hours per calendar day
+    #[serde(default)]
+    pub max_hours_per_day: Option<f64>,
+    /// Maximum billable hours per ISO week (Monday to Sunday)
+    #[serde(default)]
+    pub max_hours_per_week: Option<f64>,
+    /// Maximum billable hours for the whole report period
+    #[serde(default)]
+    pub max_hours_per_period: Option<f64>,
+    /// Monthly retainer and prepaid hour blocks
+    #[serde(default)]
+    pub retainer: Option<RetainerConfig>,
+    /// Fixed budgets for the whole client or a set of its repos
+    #[serde(default)]
+    pub budgets: Vec<BudgetConfig>,
+    /// Discounts on the invoice subtotal, applied in order
+    #[serde(default)]
+    pub discounts: Vec<DiscountRule>,
+    /// Taxes on the discounted subtotal (VAT added, withholding subtracted)
+    #[serde(default)]
+    pub tax_rules: Vec<TaxRule>,
+    /// Commit URL template per repo (directory name); `{hash}` is replaced by the commit hash
+    #[serde(default)]
+    pub commit_urls: HashMap<String, String>,
+    /// Account names for the timeclock, ledger and beancount exports
+    #[serde(default)]
+    pub accounts: AccountsConfig,
 }
 
-fn default_hourly_rate() -> f64 {
-    0.0
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            repos: Vec::new(),
+            hourly_rate: Decimal::ZERO,
+            rates: Vec::new(),
+            rate_rules: Vec::new(),
+            currency: default_currency(),
+            llm_cost_per_kloc: Decimal::ZERO,
+            llm_tags: Vec::new(),
+            rounding: None,
+            max_hours_per_day: None,
+            max_hours_per_week: None,
+            max_hours_per_period: None,
+            retainer: None,
+            budgets: Vec::new(),
+            discounts: Vec::new(),
+            tax_rules: Vec::new(),
+            commit_urls: HashMap::new(),
+            accounts: AccountsConfig::default(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Hourly rate in effect on `date`: the latest `rates` entry starting on or
+    /// before that date, or `hourly_rate` when none applies.
+    pub fn rate_on(&self, date: NaiveDate) -> Decimal {
+        self.rates
+            .iter()
+            .filter(|r| r.from <= date)
+            .max_by_key(|r| r.from)
+            .map(|r| r.hourly_rate)
+            .unwrap_or(self.hourly_rate)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateEntry {
+    /// First day the rate applies (YYYY-MM-DD)
+    pub from: NaiveDate,
+    pub hourly_rate: Decimal,
+}
+
+/// Rate for a category of work. Every matcher that is set must match the commit.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RateRule {
+    /// Category name shown in reports (e.g. "maintenance")
+    pub name: String,
+    pub hourly_rate: Decimal,
+    /// Repository name (directory name of the repo path)
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// Branch name; `*` matches any characters (e.g. "hotfix/*")
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Path prefix, or a `*` pattern, matched against any file touched by the commit
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Conventional-commit types (`fix`, `feat`, `chore`, ...)
+    #[serde(default)]
+    pub commit_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetainerConfig {
+    /// First day covered by the retainer (YYYY-MM-DD); months are counted from here
+    pub start: NaiveDate,
+    /// Hours included every calendar month
+    #[serde(default)]
+    pub hours_per_month: f64,
+    /// Carry unused monthly hours into the next month
+    #[serde(default)]
+    pub rollover: bool,
+    /// Maximum hours carried into the next month (unlimited if not set)
+    #[serde(default)]
+    pub max_rollover_hours: Option<f64>,
+    /// Prepaid hour blocks; they never expire and are used after the monthly hours
+    #[serde(default)]
+    pub prepaid: Vec<PrepaidBlock>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepaidBlock {
+    /// Purchase date (YYYY-MM-DD); the block is usable from that day on
+    pub date: NaiveDate,
+    pub hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetConfig {
+    /// Budget name shown in reports (e.g. "Website redesign")
+    pub name: String,
+    /// First day counted against the budget (YYYY-MM-DD)
+    pub start: NaiveDate,
+    /// Budget in billed hours
+    #[serde(default)]
+    pub hours: Option<f64>,
+    /// Budget in money, in the client's currency
+    #[serde(default)]
+    pub amount: Option<Decimal>,
+    /// Repo names the budget applies to (all of the client's repos if empty)
+    #[serde(default)]
+    pub repos: Vec<String>,
+    /// Fraction consumed at which `gtt status` warns
+    #[serde(default = "default_alert_threshold")]
+    pub alert_threshold: f64,
+}
+
+fn default_alert_threshold() -> f64 {
+    0.8
 }
 
 fn default_currency() -> String {
     "USD".to_string()
 }
 
+/// Invoice discount: a percentage of the subtotal and/or a fixed amount
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DiscountRule {
+    /// Label shown on the invoice (e.g. "Loyalty discount")
+    pub name: String,
+    /// Percentage of the subtotal (10 = 10%)
+    #[serde(default)]
+    pub percent: Option<Decimal>,
+    /// Fixed amount in the client's currency
+    #[serde(default)]
+    pub amount: Option<Decimal>,
+}
+
+/// Tax computed on the discounted subtotal
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TaxRule {
+    /// Label shown on the invoice (e.g. "VAT")
+    pub name: String,
+    /// Percentage of the taxable base (21 = 21%)
+    pub percent: Decimal,
+    /// Withheld by the client (e.g. income tax withholding): subtracted instead of added
+    #[serde(default)]
+    pub withholding: bool,
+}
+
+/// Account names for plain-text accounting exports. Unset accounts are derived
+/// from the client name (e.g. `Income:Consulting:StartupX`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AccountsConfig {
+    /// Debited with what the client owes
+    pub receivable: Option<String>,
+    /// Credited with billable hours not covered by a retainer
+    pub income: Option<String>,
+    /// Credited with LLM costs passed through
+    pub llm: Option<String>,
+    /// Credited with reimbursable expenses
+    pub expenses: Option<String>,
+    /// Debited with invoice discounts
+    pub discounts: Option<String>,
+    /// Taxes collected (credited) or withheld (debited)
+    pub taxes: Option<String>,
+    /// Account clocked in and out of in timeclock files
+    pub timeclock: Option<String>,
+}
+
+impl AccountsConfig {
+    pub fn receivable(&self, client: &str) -> String {
+        self.receivable.clone().unwrap_or_else(|| format!("Assets:Receivable:{}", account_component(client)))
+    }
+
+    pub fn income(&self, client: &str) -> String {
+        self.income.clone().unwrap_or_else(|| format!("Income:Consulting:{}", account_component(client)))
+    }
+
+    pub fn llm(&self, client: &str) -> String {
+        self.llm.clone().unwrap_or_else(|| format!("Income:LLM:{}", account_component(client)))
+    }
+
+    pub fn expenses(&self, client: &str) -> String {
+        self.expenses.clone().unwrap_or_else(|| format!("Income:Reimbursed:{}", account_component(client)))
+    }
+
+    pub fn discounts(&self, client: &str) -> String {
+        self.discounts.clone().unwrap_or_else(|| format!("Expenses:Discounts:{}", account_component(client)))
+    }
+
+    pub fn taxes(&self) -> String {
+        self.taxes.clone().unwrap_or_else(|| "Liabilities:Taxes".to_string())
+    }
+
+    pub fn timeclock(&self, client: &str) -> String {
+        self.timeclock.clone().unwrap_or_else(|| format!("Clients:{}", account_component(client)))
+    }
+}
+
+/// Account name component valid in ledger and beancount: words capitalized and
+/// joined, other characters dropped ("startup x" → "StartupX")
+pub fn account_component(name: &str) -> String {
+    let component: String = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars.next().map_or(String::new(), |first| first.to_uppercase().chain(chars).collect())
+        })
+        .collect();
+    match component.chars().next() {
+        Some(c) if c.is_alphabetic() => component,
+        _ => format!("C{}", component),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RoundingConfig {
+    /// Billing increment in minutes (0 or 1 = no rounding)
+    #[serde(default)]
+    pub increment_minutes: u32,
+    #[serde(default)]
+    pub mode: RoundingMode,
+    #[serde(default)]
+    pub scope: RoundingScope,
+    /// Minimum billed minutes for any day with activity
+    #[serde(default)]
+    pub minimum_per_day: u32,
+    /// Minimum billed minutes for every session
+    #[serde(default)]
+    pub minimum_per_session: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingMode {
+    #[default]
+    Up,
+    Nearest,
+    Down,
+}
+
+/// Unit the increment is applied to: each session, each day, or the whole period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingScope {
+    Session,
+    #[default]
+    Day,
+    Period,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     /// Minutes of inactivity before starting a new session
@@ -53,6 +331,50 @@ pub struct Settings {
     /// Lines-changed normalization divisor
     #[serde(default = "default_volume_scale")]
     pub volume_scale: f64,
+    /// CSV of exchange rates (`date,from,to,rate`); default: exchange_rates.csv next to config.toml
+    #[serde(default)]
+    pub exchange_rates_file: Option<PathBuf>,
+    /// Language of messages and reports (`en`, `es`); default: from LANG
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// strftime pattern for dates in human-readable output; default `%d/%m/%Y`
+    #[serde(default)]
+    pub date_format: Option<String>,
+    /// Clock for times in human-readable output
+    #[serde(default)]
+    pub time_format: TimeFormat,
+    /// Decimal separator of amounts in human-readable output; default `.`
+    #[serde(default)]
+    pub decimal_separator: Option<char>,
+    /// Thousands separator of amounts in human-readable output; default none
+    #[serde(default)]
+    pub thousands_separator: Option<char>,
+    /// How the minutes of a session spanning several repos are split between them
+    #[serde(default)]
+    pub repo_attribution: RepoAttribution,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TimeFormat {
+    /// `14:05`
+    #[default]
+    #[serde(rename = "24h")]
+    H24,
+    /// `2:05 PM`
+    #[serde(rename = "12h")]
+    H12,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoAttribution {
+    /// In proportion to the commits in each repo
+    #[default]
+    Commits,
+    /// In proportion to the lines added and deleted in each repo
+    Lines,
+    /// The time before each commit goes to its repo
+    Time,
 }
 
 fn default_session_gap_minutes() -> u32 {
@@ -89,6 +411,13 @@ impl Default for Settings {
             volume_adjustment: false,
             volume_factor: default_volume_factor(),
             volume_scale: default_volume_scale(),
+            exchange_rates_file: None,
+            locale: None,
+            date_format: None,
+            time_format: TimeFormat::H24,
+            decimal_separator: None,
+            thousands_separator: None,
+            repo_attribution: RepoAttribution::Commits,
         }
     }
 }
@@ -1,35 +1,58 @@
+use std::fmt;
 use thiserror::Error;
+use crate::i18n::{t, tf};
 
 #[derive(Error, Debug)]
 pub enum GttError {
-    #[error("Configuration file not found. Run `gtt init` to get started.")]
     ConfigNotFound,
 
-    #[error("Configuration error: {0}")]
     ConfigParse(String),
 
-    #[error("Client '{0}' not found in configuration.")]
     ClientNotFound(String),
 
     #[allow(dead_code)]
-    #[error("Repository not found: {0}")]
     RepoNotFound(String),
 
-    #[error("Not a git repository: {0}")]
     NotAGitRepo(String),
 
-    #[error("Git command failed: {0}")]
     GitCommandFailed(String),
 
-    #[error("Failed to parse git log output: {0}")]
     GitParseFailed(String),
 
-    #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
-    #[error("Date parse error: {0}")]
     DateParse(String),
 
-    #[error("Invalid date range: {0}")]
     InvalidDateRange(String),
+
+    ExchangeRateParse(String),
+
+    ExchangeRateMissing(String),
+
+    StoreParse(String),
+
+    ImportParse(String),
+}
+
+/// Messages come from the catalog, in the current language
+impl fmt::Display for GttError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (key, detail): (&str, &dyn fmt::Display) = match self {
+            GttError::ConfigNotFound => return f.write_str(t("error.config_not_found")),
+            GttError::ConfigParse(d) => ("error.config_parse", d),
+            GttError::ClientNotFound(d) => ("error.client_not_found", d),
+            GttError::RepoNotFound(d) => ("error.repo_not_found", d),
+            GttError::NotAGitRepo(d) => ("error.not_a_git_repo", d),
+            GttError::GitCommandFailed(d) => ("error.git_command_failed", d),
+            GttError::GitParseFailed(d) => ("error.git_parse_failed", d),
+            GttError::Io(e) => ("error.io", e),
+            GttError::DateParse(d) => ("error.date_parse", d),
+            GttError::InvalidDateRange(d) => ("error.invalid_date_range", d),
+            GttError::ExchangeRateParse(d) => ("error.exchange_rate_parse", d),
+            GttError::ExchangeRateMissing(d) => ("error.exchange_rate_missing", d),
+            GttError::StoreParse(d) => ("error.store_parse", d),
+            GttError::ImportParse(d) => ("error.import_parse", d),
+        };
+        f.write_str(&tf(key, &[("detail", detail)]))
+    }
 }
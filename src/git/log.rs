@@ -111,3 +111,27 @@ pub fn get_repo_user_email(repo_path: &Path) -> Option<String> {
     }
     None
 }
+
+/// Map every commit reachable from a local branch to the branch it was reached from.
+/// Output: one `hash\x00refname` line per commit.
+pub fn run_git_branch_map(repo_path: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .args([
+            "-C",
+            &repo_path.to_string_lossy(),
+            "log",
+            "--branches",
+            "--source",
+            "--no-merges",
+            "--format=%H%x00%S",
+        ])
+        .output()
+        .map_err(|e| GttError::GitCommandFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GttError::GitCommandFailed(stderr.to_string()).into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
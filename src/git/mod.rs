@@ -1,5 +1,8 @@
 pub mod log;
 pub mod parser;
 
-pub use log::{get_repo_user_email, run_git_log, run_git_log_numstat};
-pub use parser::{parse_git_log, parse_numstat, merge_numstat, Commit};
+pub use log::{get_repo_user_email, run_git_branch_map, run_git_log, run_git_log_numstat};
+pub use parser::{
+    merge_branches, merge_files, merge_numstat, parse_branch_map, parse_git_log, parse_numstat,
+    parse_numstat_files, Commit,
+};
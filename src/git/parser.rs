@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use chrono::{DateTime, FixedOffset};
 use anyhow::Result;
 use crate::errors::GttError;
+use crate::i18n::tf;
 
 #[derive(Debug, Clone)]
 pub struct Commit {
@@ -19,6 +20,24 @@ pub struct Commit {
     pub repo_name: String,
     pub lines_added: u32,
     pub lines_deleted: u32,
+    /// Paths touched by the commit (from numstat)
+    pub files: Vec<String>,
+    /// Local branch the commit was first reached from, when resolved
+    pub branch: Option<String>,
+}
+
+impl Commit {
+    /// Conventional-commit type of the subject (`feat`, `fix(api)!:` → `fix`, ...)
+    pub fn conventional_type(&self) -> Option<&str> {
+        let (head, _) = self.subject.split_once(':')?;
+        let head = head.trim_end_matches('!');
+        let kind = head.split_once('(').map_or(head, |(k, _)| k);
+        if !kind.is_empty() && kind.chars().all(|c| c.is_ascii_alphanumeric()) {
+            Some(kind)
+        } else {
+            None
+        }
+    }
 }
 
 /// Parse raw git log output (NUL-separated records ending with END).
@@ -60,7 +79,7 @@ pub fn parse_git_log(raw: &str, repo_path: &Path, bot_authors: &[String]) -> Res
         }
 
         let author_date = DateTime::parse_from_rfc3339(date_str)
-            .map_err(|e| GttError::GitParseFailed(format!("Invalid date '{}': {}", date_str, e)))?;
+            .map_err(|e| GttError::GitParseFailed(tf("git.bad_date", &[("value", &date_str), ("error", &e)])))?;
 
         commits.push(Commit {
             hash,
@@ -72,6 +91,8 @@ pub fn parse_git_log(raw: &str, repo_path: &Path, bot_authors: &[String]) -> Res
             repo_name: repo_name.clone(),
             lines_added: 0,
             lines_deleted: 0,
+            files: Vec::new(),
+            branch: None,
         });
     }
 
@@ -113,6 +134,46 @@ pub fn parse_numstat(raw: &str) -> HashMap<String, (u32, u32)> {
     map
 }
 
+/// Parse `git log --format=%H --numstat` output into a map of hash → touched paths.
+/// Binary files (`-\t-\tpath`) are included.
+pub fn parse_numstat_files(raw: &str) -> HashMap<String, Vec<String>> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    let mut current_hash: Option<String> = None;
+
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(3, '\t').collect();
+        if parts.len() == 3 {
+            if let Some(ref hash) = current_hash {
+                map.entry(hash.clone()).or_default().push(parts[2].to_string());
+            }
+            continue;
+        }
+
+        if line.len() >= 4 && line.chars().all(|c| c.is_ascii_hexdigit()) {
+            current_hash = Some(line.to_string());
+            map.entry(line.to_string()).or_default();
+        }
+    }
+
+    map
+}
+
+/// Parse `git log --branches --source --format=%H%x00%S` output into hash → branch name.
+pub fn parse_branch_map(raw: &str) -> HashMap<String, String> {
+    raw.lines()
+        .filter_map(|line| line.split_once('\x00'))
+        .map(|(hash, source)| {
+            let branch = source.trim().trim_start_matches("refs/heads/");
+            (hash.trim().to_string(), branch.to_string())
+        })
+        .collect()
+}
+
 /// Merge numstat data into already-parsed commits by hash.
 pub fn merge_numstat(commits: &mut [Commit], numstat: &HashMap<String, (u32, u32)>) {
     for commit in commits.iter_mut() {
@@ -123,6 +184,24 @@ pub fn merge_numstat(commits: &mut [Commit], numstat: &HashMap<String, (u32, u32
     }
 }
 
+/// Merge touched paths into already-parsed commits by hash.
+pub fn merge_files(commits: &mut [Commit], files: &HashMap<String, Vec<String>>) {
+    for commit in commits.iter_mut() {
+        if let Some(paths) = files.get(&commit.hash) {
+            commit.files = paths.clone();
+        }
+    }
+}
+
+/// Merge branch names into already-parsed commits by hash.
+pub fn merge_branches(commits: &mut [Commit], branches: &HashMap<String, String>) {
+    for commit in commits.iter_mut() {
+        if let Some(branch) = branches.get(&commit.hash) {
+            commit.branch = Some(branch.clone());
+        }
+    }
+}
+
 fn is_bot(name: &str, email: &str, bot_authors: &[String]) -> bool {
     for bot in bot_authors {
         let bot_lower = bot.to_lowercase();
@@ -0,0 +1,382 @@
+//! English messages
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Weekdays
+    ("weekday.short.mon", "Mon"),
+    ("weekday.short.tue", "Tue"),
+    ("weekday.short.wed", "Wed"),
+    ("weekday.short.thu", "Thu"),
+    ("weekday.short.fri", "Fri"),
+    ("weekday.short.sat", "Sat"),
+    ("weekday.short.sun", "Sun"),
+    ("weekday.long.mon", "Monday"),
+    ("weekday.long.tue", "Tuesday"),
+    ("weekday.long.wed", "Wednesday"),
+    ("weekday.long.thu", "Thursday"),
+    ("weekday.long.fri", "Friday"),
+    ("weekday.long.sat", "Saturday"),
+    ("weekday.long.sun", "Sunday"),
+    // Errors
+    ("error.config_not_found", "Configuration file not found. Run `gtt init` to get started."),
+    ("error.config_parse", "Configuration error: {detail}"),
+    ("error.client_not_found", "Client '{detail}' not found in configuration."),
+    ("error.repo_not_found", "Repository not found: {detail}"),
+    ("error.not_a_git_repo", "Not a git repository: {detail}"),
+    ("error.git_command_failed", "Git command failed: {detail}"),
+    ("error.git_parse_failed", "Failed to parse git log output: {detail}"),
+    ("error.io", "IO error: {detail}"),
+    ("error.date_parse", "Date parse error: {detail}"),
+    ("error.invalid_date_range", "Invalid date range: {detail}"),
+    ("error.exchange_rate_parse", "Exchange rate file error: {detail}"),
+    ("error.exchange_rate_missing", "No exchange rate for {detail}"),
+    ("error.store_parse", "Data file error: {detail}"),
+    ("error.import_parse", "Import error: {detail}"),
+    ("date.invalid", "Invalid date: '{value}'. Use YYYY-MM-DD."),
+    ("date.range_order", "{since} is after {until}"),
+    // Common
+    ("common.unsupported_locale", "Warning: unsupported locale '{locale}' (available: en, es)"),
+    ("common.saved_to", "Saved to: {path}"),
+    // gtt report
+    ("report.no_clients", "No clients configured. Run `gtt init` to get started."),
+    ("report.binary_format", "The {format} format is binary: give the file with --output or use `gtt export`."),
+    ("report.no_commits", "No commits for '{client}' between {since} and {until}."),
+    ("report.unsupported_format", "Unsupported format: '{format}'. Use: {formats}"),
+    ("report.rates_unreadable", "could not read {path} (expected columns date,from,to,rate)"),
+    ("report.unsupported_rate_date", "Unsupported exchange rate date: '{value}'. Use: invoice, day"),
+    ("report.unsupported_postings", "Unsupported postings grouping: '{value}'. Use: period, day"),
+    ("report.unsupported_detail", "Unsupported detail level: '{value}'. Use: sessions, commits"),
+    ("report.repo_not_found", "Warning: repository not found: {path}"),
+    ("report.repo_error", "Warning: error in {path}: {error}"),
+    // gtt status
+    ("status.period", "  Today: {today}    This week: {week_start} — {today}"),
+    ("status.no_clients", "No clients configured. Run `gtt init`."),
+    ("status.client", "{client} — Today: {today}  ({today_commits} commits)   This week: {week}  ({week_commits} commits)"),
+    ("status.budget_alert", "    ⚠ Budget '{name}': {used}% used (threshold {threshold}%)"),
+    // gtt init
+    ("init.subtitle", "Interactive configuration setup"),
+    ("init.gap", "Minutes of inactivity before a new session?"),
+    ("init.gap_help", "Default 120 min (2 hours). Adjust to your working style."),
+    ("init.first_commit", "Base minutes for the first commit of a session?"),
+    ("init.first_commit_help", "Time assumed worked before the first commit. Default 30 min."),
+    ("init.client_name", "Client name (empty to finish):"),
+    ("init.repo_path", "  Repository path (empty to finish):"),
+    ("init.repo_path_help", "Absolute path, e.g. /home/user/my-project"),
+    ("init.repo_missing", "  Warning: the path does not exist (it will be added anyway)"),
+    ("init.rate", "  Hourly rate (0 to skip):"),
+    ("init.currency", "  Currency:"),
+    ("init.currency_other", "Other"),
+    ("init.currency_code", "  Currency code (e.g. CLP):"),
+    ("init.add_another", "Add another client?"),
+    ("init.no_clients", "No client was configured. You can edit the config by hand."),
+    ("init.saved", "Configuration saved."),
+    ("init.next_steps", "Next steps:"),
+    ("init.next_status", "Today's and this week's hours"),
+    ("init.next_report", "Last month's report"),
+    // gtt config
+    ("config.missing", "No configuration. Run `gtt init`."),
+    ("config.missing_edit", "No configuration. Run `gtt init` first."),
+    ("config.editor_failed", "The editor '{editor}' exited with an error."),
+    // gtt balance
+    ("balance.no_retainer", "Client '{client}' has no retainer configured."),
+    ("balance.no_retainers", "No client has a retainer configured."),
+    // gtt budget
+    ("budget.no_budget", "Client '{client}' has no budgets configured."),
+    ("budget.no_budgets", "No client has budgets configured."),
+    // Column headers
+    ("col.date", "Date"),
+    ("col.client", "Client"),
+    ("col.description", "Description"),
+    ("col.repo", "Repo"),
+    ("col.repos", "Repos"),
+    ("col.amount", "Amount"),
+    ("col.sessions", "Sessions"),
+    ("col.hours", "Hours"),
+    ("col.billed", "Billed"),
+    ("col.overflow", "Overflow"),
+    ("col.commits", "Commits"),
+    ("col.invoice", "Invoice"),
+    ("col.issued", "Issued"),
+    ("col.paid", "Paid"),
+    ("col.outstanding", "Outstanding"),
+    ("col.days", "Days"),
+    ("col.time", "Time"),
+    ("col.duration", "Duration"),
+    ("col.source", "Source"),
+    // gtt expense
+    ("common.amount_not_positive", "The amount must be greater than 0."),
+    ("expense.unknown_repo", "Client '{client}' has no repository named '{repo}'."),
+    ("expense.added", "Expense #{id} recorded: {date} — {amount} ({description})"),
+    ("expense.none", "No expenses recorded."),
+    ("expense.not_found", "There is no expense #{id}."),
+    ("expense.removed", "Expense #{id} removed."),
+    // gtt invoice
+    ("invoice.nothing_to_bill", "Nothing to bill for '{client}' between {since} and {until}."),
+    ("invoice.overlaps", "Invoice {number} already covers part of that period ({since} — {until})."),
+    ("invoice.added", "Invoice {number} recorded: {client} — {amount} (period {since} — {until})"),
+    ("invoice.none", "No invoices recorded."),
+    // gtt llm
+    ("llm.imported", "Imported {added} of {read} usage records ({existing} already existed). File cost: {cost}"),
+    // gtt payments
+    ("payments.paid", "Invoice {number} paid in full."),
+    ("payments.recorded", "Payment recorded on {number}: {outstanding} left of {amount}."),
+    // gtt receivables
+    ("receivables.none", "No invoices pending collection."),
+    // gtt time
+    ("time.unsupported_format", "Unsupported format: '{format}'. Use: timewarrior, org"),
+    ("time.default_description", "Manual time"),
+    ("time.unassigned", "Warning: {count} intervals without a client (add a tag with the client's name or use --client)."),
+    ("time.imported", "Imported {added} of {read} time entries ({existing} already existed)."),
+    ("time.none", "No manual time recorded."),
+    ("time.not_found", "There is no time entry #{id}."),
+    ("time.removed", "Time entry #{id} removed."),
+    // Files
+    ("common.read_failed", "Failed to read {path}"),
+    ("common.write_failed", "Failed to write {path}"),
+    ("common.create_dir_failed", "Failed to create directory {path}"),
+    ("config.no_config_dir", "Could not determine the config directory"),
+    // Invoice store
+    ("invoice.exists", "Invoice '{number}' already exists."),
+    ("invoice.not_found", "There is no invoice '{number}'."),
+    ("invoice.overpaid", "The payment ({amount}) exceeds the outstanding balance of invoice '{number}' ({outstanding})."),
+    // Imports
+    ("import.unknown_format", "unknown format '{format}' (available: {formats})"),
+    ("import.unrecognized", "unrecognized export format"),
+    ("import.bad_timestamp", "record {record}: missing or invalid timestamp"),
+    ("import.bad_cost", "record {record}: missing or invalid cost"),
+    ("import.expected_records", "expected an array of usage records"),
+    ("import.expected_buckets", "expected a `data` array of cost buckets"),
+    ("import.bad_interval_date", "interval {interval}: invalid date '{value}'"),
+    ("import.bad_clock", "line {line}: invalid clock time '{value}'"),
+    ("import.nonexistent_time", "line {line}: nonexistent local time '{value}'"),
+    // Exchange rates and git
+    ("rates.line", "line {line}: {error}"),
+    ("rates.columns", "line {line}: expected date,from,to,rate"),
+    ("rates.bad_date", "line {line}: invalid date '{value}'"),
+    ("rates.bad_rate", "line {line}: invalid rate '{value}'"),
+    ("rates.missing", "{from} → {to} on or before {date}"),
+    ("git.bad_date", "Invalid date '{value}': {error}"),
+    // Reports
+    ("common.total", "Total"),
+    ("common.subtotal", "Subtotal"),
+    ("report.title", "Hours report: {client}"),
+    ("report.client", "Client: {client}"),
+    ("report.period", "Period: {since} — {until}"),
+    ("report.logged_billed", "Logged time: {logged} → billed: {billed}"),
+    ("report.overflow", "Unbilled overflow (over the hour cap): {duration}"),
+    ("report.llm_actual", "LLM (actual usage)"),
+    ("report.expenses", "Expenses"),
+    ("report.expense", "Expense"),
+    ("report.amount", "Amount"),
+    ("report.covered", "Covered by retainer: {duration} ({amount})"),
+    ("report.overage", "Billable extra: {duration} = {amount}"),
+    ("report.rate_on", "exchange rate {rate} on {date}"),
+    ("report.rate", "exchange rate {rate}"),
+    ("report.rate_daily", "each day's exchange rate"),
+    ("report.converted", "In {currency} ({rate}): Total: {total}  (original: {original})"),
+    ("report.no_rate", "no hourly rate configured"),
+    ("report.total_due", "Total due"),
+    ("report.grand_total", "Grand total ({clients} clients): {total}"),
+    ("report.day_sessions", "{day} ({count} sessions, {duration})"),
+    ("verify.title", "Verify sessions: {client}"),
+    ("verify.session", "Session {number}"),
+    // Retainers, receivables and budgets
+    ("retainer.title", "Retainer: {client}"),
+    ("retainer.month", "Month"),
+    ("retainer.included", "Included"),
+    ("retainer.rollover", "Rollover"),
+    ("retainer.prepaid", "Prepaid"),
+    ("retainer.used", "Used"),
+    ("retainer.remaining", "Remaining"),
+    ("retainer.overage", "Overage"),
+    ("retainer.available", "Available now: {now}  (carries over to next month: {next})"),
+    ("receivables.title", "Receivable: {client} ({currency})"),
+    ("budget.title", "Budgets: {client}"),
+    ("budget.heading", "{name} (since {since}) — {used}% used"),
+    ("budget.hours", "Hours:  {used} of {limit}  ({remaining} left)   pace: {pace}/day"),
+    ("budget.amount", "Amount:  {used} of {limit}  ({remaining} left)   pace: {pace}/day"),
+    ("budget.exhausted", "Exhausted on {date}"),
+    ("budget.runs_out", "Runs out around {date}"),
+    ("budget.no_projection", "No recent activity: no projection"),
+    // Charts
+    ("chart.hours_per_day", "Hours per day"),
+    ("chart.overflow", "overflow"),
+    ("chart.overflow_legend", "unbilled overflow"),
+    ("chart.metrics_per_day", "Metrics per day"),
+    ("chart.no_activity", "no activity"),
+    ("chart.code", "Code"),
+    ("chart.heatmap", "Heat map"),
+    // Markdown and HTML
+    ("report.period_label", "Period"),
+    ("report.overage_label", "Billable extra"),
+    ("report.concept", "Item"),
+    ("report.in_currency", "In {currency}"),
+    // HTML report
+    ("report.charts", "Charts"),
+    ("report.summary", "Summary"),
+    ("report.by_day", "By day"),
+    ("report.logged_hours", "Hours logged"),
+    ("report.billed_hours", "Hours billed"),
+    ("report.unbilled_overflow", "Unbilled overflow"),
+    ("report.covered_by", "Covered by retainer ({duration})"),
+    ("report.rate_label", "Rate"),
+    ("col.lines", "Lines"),
+    // Spreadsheets
+    ("sheet.days", "{name} - days"),
+    ("sheet.sessions", "{name} - sessions"),
+    ("sheet.billed_minutes", "Billed min."),
+    ("sheet.covered_minutes", "Covered min."),
+    ("sheet.amount", "Amount"),
+    ("sheet.llm", "LLM"),
+    ("sheet.start", "Start"),
+    ("sheet.end", "End"),
+    ("sheet.base", "Base"),
+    ("sheet.intervals", "Gaps"),
+    ("sheet.volume", "Volume"),
+    ("sheet.manual", "Manual"),
+    ("sheet.from", "From"),
+    ("sheet.to", "To"),
+    ("sheet.currency", "Currency"),
+    ("sheet.hourly_rate", "Hourly rate"),
+    ("sheet.detected_hours", "Hours detected"),
+    ("sheet.covered_hours", "Hours covered"),
+    ("sheet.hours_amount", "Hours amount"),
+    ("sheet.taxable_base", "Taxable base"),
+    // Journals
+    ("ledger.period", "Hours {since} — {until}"),
+    ("ledger.day", "Billable {date}"),
+    // Months
+    ("month.short.1", "Jan"),
+    ("month.short.2", "Feb"),
+    ("month.short.3", "Mar"),
+    ("month.short.4", "Apr"),
+    ("month.short.5", "May"),
+    ("month.short.6", "Jun"),
+    ("month.short.7", "Jul"),
+    ("month.short.8", "Aug"),
+    ("month.short.9", "Sep"),
+    ("month.short.10", "Oct"),
+    ("month.short.11", "Nov"),
+    ("month.short.12", "Dec"),
+    ("month.long.1", "January"),
+    ("month.long.2", "February"),
+    ("month.long.3", "March"),
+    ("month.long.4", "April"),
+    ("month.long.5", "May"),
+    ("month.long.6", "June"),
+    ("month.long.7", "July"),
+    ("month.long.8", "August"),
+    ("month.long.9", "September"),
+    ("month.long.10", "October"),
+    ("month.long.11", "November"),
+    ("month.long.12", "December"),
+    // Formats
+    ("common.invalid_date_format", "Warning: invalid date_format '{pattern}', using {default}"),
+    // Grouping (--group-by)
+    ("col.week", "Week"),
+    ("col.month", "Month"),
+    ("col.weekday", "Weekday"),
+    ("group.week", "Week {week} ({start})"),
+    ("group.month", "{month} {year}"),
+    ("group.no_repo", "(no repo)"),
+    ("report.unsupported_group_by", "Unsupported grouping: '{value}'. Use: {values}"),
+    ("report.group_by_detail", "--group-by {value} can't be combined with --detail in CSV"),
+    // Repo attribution
+    ("report.repos", "Repos"),
+    // Ledger
+    ("ledger.day_adjustments", "{client} has discounts or taxes, which apply to the whole invoice: use --postings period"),
+    // Manual time
+    ("report.manual_overlap", "{client}: {duration} of imported time overlapping commit sessions was left out, as the commits already bill it"),
+    // Command-line help, by command path and argument
+    ("help", "Git Time Tracker — Estimate hours worked from git commits"),
+    ("help.init", "Interactive setup: creates the client and repo configuration"),
+    ("help.status", "Quick summary: hours today and this week per client"),
+    ("help.report", "Hours report per client (table, CSV, JSON, Markdown or HTML)"),
+    ("help.report.client", "Client name (all when not given)"),
+    ("help.report.last_week", "Report for last week"),
+    ("help.report.last_month", "Report for last month"),
+    ("help.report.since", "Start date (YYYY-MM-DD)"),
+    ("help.report.until", "End date (YYYY-MM-DD)"),
+    ("help.report.format", "Output format: table, csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods"),
+    ("help.report.output", "Output file (default: stdout for table, automatic file for csv/json)"),
+    ("help.report.in_currency", "Convert amounts to another currency (e.g. EUR) with the exchange rate table"),
+    ("help.report.rate_date", "Exchange rate date: invoice (end of the period) or day (each day)"),
+    ("help.report.sessions", "Include the per-session detail with its commits (markdown)"),
+    ("help.report.postings", "Ledger/beancount postings: period (one per period) or day (one per day)"),
+    ("help.report.detail", "Detail in JSON, CSV and NDJSON: sessions (each session) or commits (each session with its commits)"),
+    ("help.report.group_by", "Rows of the table, CSV and JSON: day, week (ISO week), month, repo or weekday (day of the week)"),
+    ("help.verify", "List the detected sessions with timestamps to check before invoicing"),
+    ("help.verify.client", "Client name"),
+    ("help.verify.last_week", "Sessions from last week"),
+    ("help.verify.last_month", "Sessions from last month"),
+    ("help.verify.since", "Start date (YYYY-MM-DD)"),
+    ("help.verify.until", "End date (YYYY-MM-DD)"),
+    ("help.export", "Export the report to a file (alias for report --format)"),
+    ("help.export.client", "Client name"),
+    ("help.export.last_week", "Last week"),
+    ("help.export.last_month", "Last month"),
+    ("help.export.since", "Start date (YYYY-MM-DD)"),
+    ("help.export.until", "End date (YYYY-MM-DD)"),
+    ("help.export.format", "Format: csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods"),
+    ("help.export.output", "Output file (generated automatically when not given)"),
+    ("help.export.in_currency", "Convert amounts to another currency (e.g. EUR) with the exchange rate table"),
+    ("help.export.rate_date", "Exchange rate date: invoice (end of the period) or day (each day)"),
+    ("help.export.sessions", "Include the per-session detail with its commits (markdown)"),
+    ("help.export.postings", "Ledger/beancount postings: period (one per period) or day (one per day)"),
+    ("help.export.detail", "Detail in JSON, CSV and NDJSON: sessions (each session) or commits (each session with its commits)"),
+    ("help.export.group_by", "Rows of the table, CSV and JSON: day, week (ISO week), month, repo or weekday (day of the week)"),
+    ("help.balance", "Retainer and prepaid hours balance per month"),
+    ("help.balance.client", "Client name (all with a retainer when not given)"),
+    ("help.budget", "Budgets: usage, pace and estimated exhaustion date"),
+    ("help.budget.client", "Client name (all with budgets when not given)"),
+    ("help.expense", "Reimbursable expenses invoiced along with the hours"),
+    ("help.expense.add", "Record a reimbursable expense (hosting, domains, paid APIs)"),
+    ("help.expense.add.client", "Client name"),
+    ("help.expense.add.date", "Expense date (YYYY-MM-DD, default: today)"),
+    ("help.expense.add.amount", "Amount in the client's currency"),
+    ("help.expense.add.description", "Description shown in the report"),
+    ("help.expense.add.repo", "Repository the expense belongs to (directory name)"),
+    ("help.expense.list", "List the recorded expenses"),
+    ("help.expense.list.client", "Client name (all when not given)"),
+    ("help.expense.remove", "Remove an expense by its number"),
+    ("help.expense.remove.id", "Expense number (see `gtt expense list`)"),
+    ("help.llm", "Actual LLM cost imported from the providers' exports"),
+    ("help.llm.import", "Import actual LLM usage from a provider export (CSV or JSON)"),
+    ("help.llm.import.file", "File exported by the provider"),
+    ("help.llm.import.format", "File format: csv, json, openai (default: detect)"),
+    ("help.llm.import.provider", "Provider name stored with each record (default: the format)"),
+    ("help.llm.import.tag", "Tag (client or one of its llm_tags) for records without a tag"),
+    ("help.schema", "JSON schemas of gtt's outputs"),
+    ("help.schema.report", "JSON Schema of `gtt report --format json`"),
+    ("help.time", "Manual time (meetings, planning) imported from Timewarrior or org-mode"),
+    ("help.time.import", "Import manual time from Timewarrior (timew export) or org-mode CLOCK lines"),
+    ("help.time.import.file", "File to import"),
+    ("help.time.import.format", "File format: timewarrior, org (default: from the extension)"),
+    ("help.time.import.client", "Client for intervals without a tag naming one"),
+    ("help.time.list", "List the recorded manual time"),
+    ("help.time.list.client", "Client name (all when not given)"),
+    ("help.time.remove", "Remove a manual time entry by its number"),
+    ("help.time.remove.id", "Entry number (see `gtt time list`)"),
+    ("help.invoice", "Record of issued invoices from the report total"),
+    ("help.invoice.create", "Record an invoice with the total of the period's report"),
+    ("help.invoice.create.client", "Client name"),
+    ("help.invoice.create.last_week", "Last week"),
+    ("help.invoice.create.last_month", "Last month"),
+    ("help.invoice.create.since", "Start date (YYYY-MM-DD)"),
+    ("help.invoice.create.until", "End date (YYYY-MM-DD)"),
+    ("help.invoice.create.number", "Invoice number (default: sequential YYYY-NNN)"),
+    ("help.invoice.create.date", "Issue date (YYYY-MM-DD, default: today)"),
+    ("help.invoice.list", "List the recorded invoices with the amounts paid and outstanding"),
+    ("help.invoice.list.client", "Client name (all when not given)"),
+    ("help.payments", "Record invoice payments"),
+    ("help.payments.record", "Record a full or partial payment of an invoice"),
+    ("help.payments.record.invoice", "Invoice number (see `gtt invoice list`)"),
+    ("help.payments.record.amount", "Amount received, in the invoice's currency"),
+    ("help.payments.record.date", "Payment date (YYYY-MM-DD, default: today)"),
+    ("help.receivables", "Unpaid invoices by age (0-30, 31-60, 61-90+ days)"),
+    ("help.receivables.client", "Client name (all when not given)"),
+    ("help.config", "Manage gtt's configuration"),
+    ("help.config.show", "Show current configuration"),
+    ("help.config.edit", "Open config in $EDITOR"),
+    // Separators
+    ("common.same_separators", "thousands_separator '{separator}' is also the decimal separator; amounts are shown without digit grouping"),
+];
@@ -0,0 +1,382 @@
+//! Spanish messages
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Weekdays
+    ("weekday.short.mon", "Lun"),
+    ("weekday.short.tue", "Mar"),
+    ("weekday.short.wed", "Mié"),
+    ("weekday.short.thu", "Jue"),
+    ("weekday.short.fri", "Vie"),
+    ("weekday.short.sat", "Sáb"),
+    ("weekday.short.sun", "Dom"),
+    ("weekday.long.mon", "Lunes"),
+    ("weekday.long.tue", "Martes"),
+    ("weekday.long.wed", "Miércoles"),
+    ("weekday.long.thu", "Jueves"),
+    ("weekday.long.fri", "Viernes"),
+    ("weekday.long.sat", "Sábado"),
+    ("weekday.long.sun", "Domingo"),
+    // Errors
+    ("error.config_not_found", "No se encontró el archivo de configuración. Ejecuta `gtt init` para comenzar."),
+    ("error.config_parse", "Error de configuración: {detail}"),
+    ("error.client_not_found", "El cliente '{detail}' no está en la configuración."),
+    ("error.repo_not_found", "Repositorio no encontrado: {detail}"),
+    ("error.not_a_git_repo", "No es un repositorio git: {detail}"),
+    ("error.git_command_failed", "Falló el comando git: {detail}"),
+    ("error.git_parse_failed", "No se pudo interpretar la salida de git log: {detail}"),
+    ("error.io", "Error de E/S: {detail}"),
+    ("error.date_parse", "Error de fecha: {detail}"),
+    ("error.invalid_date_range", "Rango de fechas inválido: {detail}"),
+    ("error.exchange_rate_parse", "Error en el archivo de tipos de cambio: {detail}"),
+    ("error.exchange_rate_missing", "No hay tipo de cambio para {detail}"),
+    ("error.store_parse", "Error en el archivo de datos: {detail}"),
+    ("error.import_parse", "Error de importación: {detail}"),
+    ("date.invalid", "Fecha inválida: '{value}'. Usa YYYY-MM-DD."),
+    ("date.range_order", "{since} es posterior a {until}"),
+    // Common
+    ("common.unsupported_locale", "Advertencia: locale '{locale}' no soportado (disponibles: en, es)"),
+    ("common.saved_to", "Guardado en: {path}"),
+    // gtt report
+    ("report.no_clients", "No hay clientes configurados. Ejecuta `gtt init` para comenzar."),
+    ("report.binary_format", "El formato {format} es binario: indica el archivo con --output o usa `gtt export`."),
+    ("report.no_commits", "Sin commits para '{client}' entre {since} y {until}."),
+    ("report.unsupported_format", "Formato no soportado: '{format}'. Usa: {formats}"),
+    ("report.rates_unreadable", "no se pudo leer {path} (se esperan las columnas date,from,to,rate)"),
+    ("report.unsupported_rate_date", "Fecha de tipo de cambio no soportada: '{value}'. Usa: invoice, day"),
+    ("report.unsupported_postings", "Agrupación de asientos no soportada: '{value}'. Usa: period, day"),
+    ("report.unsupported_detail", "Nivel de detalle no soportado: '{value}'. Usa: sessions, commits"),
+    ("report.repo_not_found", "Advertencia: repositorio no encontrado: {path}"),
+    ("report.repo_error", "Advertencia: error en {path}: {error}"),
+    // gtt status
+    ("status.period", "  Hoy: {today}    Esta semana: {week_start} — {today}"),
+    ("status.no_clients", "No hay clientes configurados. Ejecuta `gtt init`."),
+    ("status.client", "{client} — Hoy: {today}  ({today_commits} commits)   Esta semana: {week}  ({week_commits} commits)"),
+    ("status.budget_alert", "    ⚠ Presupuesto '{name}': {used}% consumido (umbral {threshold}%)"),
+    // gtt init
+    ("init.subtitle", "Setup interactivo de configuración"),
+    ("init.gap", "¿Minutos de inactividad para iniciar nueva sesión?"),
+    ("init.gap_help", "Default 120 min (2 horas). Ajusta según tu estilo de trabajo."),
+    ("init.first_commit", "¿Minutos base por primer commit de sesión?"),
+    ("init.first_commit_help", "Tiempo que se asume trabajado antes del primer commit. Default 30 min."),
+    ("init.client_name", "Nombre del cliente (vacío para terminar):"),
+    ("init.repo_path", "  Ruta al repositorio (vacío para terminar):"),
+    ("init.repo_path_help", "Ruta absoluta, ej: /home/user/mi-proyecto"),
+    ("init.repo_missing", "  Advertencia: la ruta no existe (se agregará de todas formas)"),
+    ("init.rate", "  Tasa horaria (0 para no configurar):"),
+    ("init.currency", "  Moneda:"),
+    ("init.currency_other", "Otra"),
+    ("init.currency_code", "  Código de moneda (ej: CLP):"),
+    ("init.add_another", "¿Agregar otro cliente?"),
+    ("init.no_clients", "No se configuró ningún cliente. Puedes editar el config manualmente."),
+    ("init.saved", "Configuración guardada exitosamente."),
+    ("init.next_steps", "Próximos pasos:"),
+    ("init.next_status", "Ver horas de hoy y esta semana"),
+    ("init.next_report", "Ver reporte del mes anterior"),
+    // gtt config
+    ("config.missing", "No hay configuración. Ejecuta `gtt init`."),
+    ("config.missing_edit", "No hay configuración. Ejecuta `gtt init` primero."),
+    ("config.editor_failed", "El editor '{editor}' terminó con error."),
+    // gtt balance
+    ("balance.no_retainer", "El cliente '{client}' no tiene retainer configurado."),
+    ("balance.no_retainers", "Ningún cliente tiene retainer configurado."),
+    // gtt budget
+    ("budget.no_budget", "El cliente '{client}' no tiene presupuestos configurados."),
+    ("budget.no_budgets", "Ningún cliente tiene presupuestos configurados."),
+    // Column headers
+    ("col.date", "Fecha"),
+    ("col.client", "Cliente"),
+    ("col.description", "Descripción"),
+    ("col.repo", "Repo"),
+    ("col.repos", "Repos"),
+    ("col.amount", "Monto"),
+    ("col.sessions", "Sesiones"),
+    ("col.hours", "Horas"),
+    ("col.billed", "Facturado"),
+    ("col.overflow", "Excedente"),
+    ("col.commits", "Commits"),
+    ("col.invoice", "Factura"),
+    ("col.issued", "Emitida"),
+    ("col.paid", "Cobrado"),
+    ("col.outstanding", "Pendiente"),
+    ("col.days", "Días"),
+    ("col.time", "Horario"),
+    ("col.duration", "Duración"),
+    ("col.source", "Origen"),
+    // gtt expense
+    ("common.amount_not_positive", "El monto debe ser mayor que 0."),
+    ("expense.unknown_repo", "El cliente '{client}' no tiene un repositorio llamado '{repo}'."),
+    ("expense.added", "Gasto #{id} registrado: {date} — {amount} ({description})"),
+    ("expense.none", "No hay gastos registrados."),
+    ("expense.not_found", "No existe el gasto #{id}."),
+    ("expense.removed", "Gasto #{id} eliminado."),
+    // gtt invoice
+    ("invoice.nothing_to_bill", "No hay nada que facturar para '{client}' entre {since} y {until}."),
+    ("invoice.overlaps", "La factura {number} ya cubre parte de ese periodo ({since} — {until})."),
+    ("invoice.added", "Factura {number} registrada: {client} — {amount} (periodo {since} — {until})"),
+    ("invoice.none", "No hay facturas registradas."),
+    // gtt llm
+    ("llm.imported", "Importados {added} de {read} registros de uso ({existing} ya existían). Costo del archivo: {cost}"),
+    // gtt payments
+    ("payments.paid", "Factura {number} cobrada por completo."),
+    ("payments.recorded", "Pago registrado en {number}: quedan {outstanding} de {amount}."),
+    // gtt receivables
+    ("receivables.none", "No hay facturas pendientes de cobro."),
+    // gtt time
+    ("time.unsupported_format", "Formato no soportado: '{format}'. Usa: timewarrior, org"),
+    ("time.default_description", "Tiempo manual"),
+    ("time.unassigned", "Advertencia: {count} intervalos sin cliente (agrega una etiqueta con el nombre del cliente o usa --client)."),
+    ("time.imported", "Importadas {added} de {read} entradas de tiempo ({existing} ya existían)."),
+    ("time.none", "No hay tiempo manual registrado."),
+    ("time.not_found", "No existe la entrada de tiempo #{id}."),
+    ("time.removed", "Entrada de tiempo #{id} eliminada."),
+    // Files
+    ("common.read_failed", "No se pudo leer {path}"),
+    ("common.write_failed", "No se pudo escribir {path}"),
+    ("common.create_dir_failed", "No se pudo crear el directorio {path}"),
+    ("config.no_config_dir", "No se pudo determinar el directorio de configuración"),
+    // Invoice store
+    ("invoice.exists", "La factura '{number}' ya existe."),
+    ("invoice.not_found", "No existe la factura '{number}'."),
+    ("invoice.overpaid", "El pago ({amount}) supera el saldo pendiente de la factura '{number}' ({outstanding})."),
+    // Imports
+    ("import.unknown_format", "formato desconocido '{format}' (disponibles: {formats})"),
+    ("import.unrecognized", "formato de exportación no reconocido"),
+    ("import.bad_timestamp", "registro {record}: fecha y hora ausente o inválida"),
+    ("import.bad_cost", "registro {record}: costo ausente o inválido"),
+    ("import.expected_records", "se esperaba un array de registros de uso"),
+    ("import.expected_buckets", "se esperaba un array `data` de costos"),
+    ("import.bad_interval_date", "intervalo {interval}: fecha inválida '{value}'"),
+    ("import.bad_clock", "línea {line}: hora de CLOCK inválida '{value}'"),
+    ("import.nonexistent_time", "línea {line}: la hora local '{value}' no existe"),
+    // Exchange rates and git
+    ("rates.line", "línea {line}: {error}"),
+    ("rates.columns", "línea {line}: se esperaba date,from,to,rate"),
+    ("rates.bad_date", "línea {line}: fecha inválida '{value}'"),
+    ("rates.bad_rate", "línea {line}: tipo de cambio inválido '{value}'"),
+    ("rates.missing", "{from} → {to} al {date} o antes"),
+    ("git.bad_date", "Fecha inválida '{value}': {error}"),
+    // Reports
+    ("common.total", "Total"),
+    ("common.subtotal", "Subtotal"),
+    ("report.title", "Reporte de horas: {client}"),
+    ("report.client", "Cliente: {client}"),
+    ("report.period", "Periodo: {since} — {until}"),
+    ("report.logged_billed", "Tiempo registrado: {logged} → facturado: {billed}"),
+    ("report.overflow", "Excedente no facturado (sobre el tope de horas): {duration}"),
+    ("report.llm_actual", "LLM (uso real)"),
+    ("report.expenses", "Gastos"),
+    ("report.expense", "Gasto"),
+    ("report.amount", "Monto"),
+    ("report.covered", "Cubierto por retainer: {duration} ({amount})"),
+    ("report.overage", "Extra facturable: {duration} = {amount}"),
+    ("report.rate_on", "tipo de cambio {rate} al {date}"),
+    ("report.rate", "tipo de cambio {rate}"),
+    ("report.rate_daily", "tipo de cambio de cada día"),
+    ("report.converted", "En {currency} ({rate}): Total: {total}  (original: {original})"),
+    ("report.no_rate", "sin tasa horaria configurada"),
+    ("report.total_due", "Total a facturar"),
+    ("report.grand_total", "Total general ({clients} clientes): {total}"),
+    ("report.day_sessions", "{day} ({count} sesiones, {duration})"),
+    ("verify.title", "Verificar sesiones: {client}"),
+    ("verify.session", "Sesión {number}"),
+    // Retainers, receivables and budgets
+    ("retainer.title", "Retainer: {client}"),
+    ("retainer.month", "Mes"),
+    ("retainer.included", "Incluidas"),
+    ("retainer.rollover", "Rollover"),
+    ("retainer.prepaid", "Prepago"),
+    ("retainer.used", "Usadas"),
+    ("retainer.remaining", "Restantes"),
+    ("retainer.overage", "Extra"),
+    ("retainer.available", "Disponible ahora: {now}  (pasa al próximo mes: {next})"),
+    ("receivables.title", "Por cobrar: {client} ({currency})"),
+    ("budget.title", "Presupuestos: {client}"),
+    ("budget.heading", "{name} (desde {since}) — {used}% consumido"),
+    ("budget.hours", "Horas:  {used} de {limit}  (quedan {remaining})   ritmo: {pace}/día"),
+    ("budget.amount", "Monto:  {used} de {limit}  (quedan {remaining})   ritmo: {pace}/día"),
+    ("budget.exhausted", "Agotado el {date}"),
+    ("budget.runs_out", "Se agota aprox. el {date}"),
+    ("budget.no_projection", "Sin actividad reciente: no hay proyección"),
+    // Charts
+    ("chart.hours_per_day", "Horas por día"),
+    ("chart.overflow", "excedente"),
+    ("chart.overflow_legend", "excedente no facturado"),
+    ("chart.metrics_per_day", "Métricas por día"),
+    ("chart.no_activity", "sin actividad"),
+    ("chart.code", "Código"),
+    ("chart.heatmap", "Mapa de calor"),
+    // Markdown and HTML
+    ("report.period_label", "Periodo"),
+    ("report.overage_label", "Extra facturable"),
+    ("report.concept", "Concepto"),
+    ("report.in_currency", "En {currency}"),
+    // HTML report
+    ("report.charts", "Gráficos"),
+    ("report.summary", "Resumen"),
+    ("report.by_day", "Por día"),
+    ("report.logged_hours", "Horas registradas"),
+    ("report.billed_hours", "Horas facturadas"),
+    ("report.unbilled_overflow", "Excedente no facturado"),
+    ("report.covered_by", "Cubierto por retainer ({duration})"),
+    ("report.rate_label", "Tarifa"),
+    ("col.lines", "Líneas"),
+    // Spreadsheets
+    ("sheet.days", "{name} - días"),
+    ("sheet.sessions", "{name} - sesiones"),
+    ("sheet.billed_minutes", "Min. facturados"),
+    ("sheet.covered_minutes", "Min. cubiertos"),
+    ("sheet.amount", "Importe"),
+    ("sheet.llm", "LLM"),
+    ("sheet.start", "Inicio"),
+    ("sheet.end", "Fin"),
+    ("sheet.base", "Base"),
+    ("sheet.intervals", "Intervalos"),
+    ("sheet.volume", "Volumen"),
+    ("sheet.manual", "Manual"),
+    ("sheet.from", "Desde"),
+    ("sheet.to", "Hasta"),
+    ("sheet.currency", "Moneda"),
+    ("sheet.hourly_rate", "Tarifa por hora"),
+    ("sheet.detected_hours", "Horas detectadas"),
+    ("sheet.covered_hours", "Horas cubiertas"),
+    ("sheet.hours_amount", "Importe horas"),
+    ("sheet.taxable_base", "Base imponible"),
+    // Journals
+    ("ledger.period", "Horas {since} — {until}"),
+    ("ledger.day", "Facturable {date}"),
+    // Months
+    ("month.short.1", "ene"),
+    ("month.short.2", "feb"),
+    ("month.short.3", "mar"),
+    ("month.short.4", "abr"),
+    ("month.short.5", "may"),
+    ("month.short.6", "jun"),
+    ("month.short.7", "jul"),
+    ("month.short.8", "ago"),
+    ("month.short.9", "sep"),
+    ("month.short.10", "oct"),
+    ("month.short.11", "nov"),
+    ("month.short.12", "dic"),
+    ("month.long.1", "enero"),
+    ("month.long.2", "febrero"),
+    ("month.long.3", "marzo"),
+    ("month.long.4", "abril"),
+    ("month.long.5", "mayo"),
+    ("month.long.6", "junio"),
+    ("month.long.7", "julio"),
+    ("month.long.8", "agosto"),
+    ("month.long.9", "septiembre"),
+    ("month.long.10", "octubre"),
+    ("month.long.11", "noviembre"),
+    ("month.long.12", "diciembre"),
+    // Formats
+    ("common.invalid_date_format", "Advertencia: date_format '{pattern}' no válido, se usa {default}"),
+    // Grouping (--group-by)
+    ("col.week", "Semana"),
+    ("col.month", "Mes"),
+    ("col.weekday", "Día"),
+    ("group.week", "Semana {week} ({start})"),
+    ("group.month", "{month} de {year}"),
+    ("group.no_repo", "(sin repo)"),
+    ("report.unsupported_group_by", "Agrupación no soportada: '{value}'. Usa: {values}"),
+    ("report.group_by_detail", "--group-by {value} no se puede combinar con --detail en CSV"),
+    // Repo attribution
+    ("report.repos", "Repos"),
+    // Ledger
+    ("ledger.day_adjustments", "{client} tiene descuentos o impuestos, que se aplican a la factura entera: usa --postings period"),
+    // Manual time
+    ("report.manual_overlap", "{client}: se descontaron {duration} de tiempo importado que se solapa con sesiones de commits, ya facturadas por los commits"),
+    // Command-line help, by command path and argument
+    ("help", "Git Time Tracker — Estima horas trabajadas desde commits de git"),
+    ("help.init", "Setup interactivo: crea la configuración de clientes y repos"),
+    ("help.status", "Resumen rápido: horas de hoy y esta semana por cliente"),
+    ("help.report", "Reporte de horas por cliente (tabla, CSV, JSON, Markdown o HTML)"),
+    ("help.report.client", "Nombre del cliente (todos si no se especifica)"),
+    ("help.report.last_week", "Reporte de la semana pasada"),
+    ("help.report.last_month", "Reporte del mes pasado"),
+    ("help.report.since", "Fecha de inicio (YYYY-MM-DD)"),
+    ("help.report.until", "Fecha de fin (YYYY-MM-DD)"),
+    ("help.report.format", "Formato de salida: table, csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods"),
+    ("help.report.output", "Archivo de salida (default: stdout para table, archivo auto para csv/json)"),
+    ("help.report.in_currency", "Convierte los montos a otra moneda (p. ej. EUR) con la tabla de tipos de cambio"),
+    ("help.report.rate_date", "Fecha del tipo de cambio: invoice (fin del periodo) o day (cada día)"),
+    ("help.report.sessions", "Incluye el detalle por sesión con sus commits (markdown)"),
+    ("help.report.postings", "Asientos de ledger/beancount: period (uno por periodo) o day (uno por día)"),
+    ("help.report.detail", "Detalle en JSON, CSV y NDJSON: sessions (cada sesión) o commits (cada sesión con sus commits)"),
+    ("help.report.group_by", "Filas de la tabla, CSV y JSON: day, week (semana ISO), month, repo o weekday (día de la semana)"),
+    ("help.verify", "Lista las sesiones detectadas con timestamps para validar antes de facturar"),
+    ("help.verify.client", "Nombre del cliente"),
+    ("help.verify.last_week", "Sesiones de la semana pasada"),
+    ("help.verify.last_month", "Sesiones del mes pasado"),
+    ("help.verify.since", "Fecha de inicio (YYYY-MM-DD)"),
+    ("help.verify.until", "Fecha de fin (YYYY-MM-DD)"),
+    ("help.export", "Exporta el reporte a archivo (alias de report --format)"),
+    ("help.export.client", "Nombre del cliente"),
+    ("help.export.last_week", "Semana pasada"),
+    ("help.export.last_month", "Mes pasado"),
+    ("help.export.since", "Fecha de inicio (YYYY-MM-DD)"),
+    ("help.export.until", "Fecha de fin (YYYY-MM-DD)"),
+    ("help.export.format", "Formato: csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods"),
+    ("help.export.output", "Archivo de salida (auto-generado si no se especifica)"),
+    ("help.export.in_currency", "Convierte los montos a otra moneda (p. ej. EUR) con la tabla de tipos de cambio"),
+    ("help.export.rate_date", "Fecha del tipo de cambio: invoice (fin del periodo) o day (cada día)"),
+    ("help.export.sessions", "Incluye el detalle por sesión con sus commits (markdown)"),
+    ("help.export.postings", "Asientos de ledger/beancount: period (uno por periodo) o day (uno por día)"),
+    ("help.export.detail", "Detalle en JSON, CSV y NDJSON: sessions (cada sesión) o commits (cada sesión con sus commits)"),
+    ("help.export.group_by", "Filas de la tabla, CSV y JSON: day, week (semana ISO), month, repo o weekday (día de la semana)"),
+    ("help.balance", "Saldo de retainer y horas prepagas por mes"),
+    ("help.balance.client", "Nombre del cliente (todos los que tengan retainer si no se especifica)"),
+    ("help.budget", "Presupuestos: consumo, ritmo y fecha estimada de agotamiento"),
+    ("help.budget.client", "Nombre del cliente (todos los que tengan presupuestos si no se especifica)"),
+    ("help.expense", "Gastos reembolsables que se facturan junto a las horas"),
+    ("help.expense.add", "Registra un gasto reembolsable (hosting, dominios, APIs pagas)"),
+    ("help.expense.add.client", "Nombre del cliente"),
+    ("help.expense.add.date", "Fecha del gasto (YYYY-MM-DD, default: hoy)"),
+    ("help.expense.add.amount", "Monto en la moneda del cliente"),
+    ("help.expense.add.description", "Descripción que aparece en el reporte"),
+    ("help.expense.add.repo", "Repositorio al que corresponde el gasto (nombre del directorio)"),
+    ("help.expense.list", "Lista los gastos registrados"),
+    ("help.expense.list.client", "Nombre del cliente (todos si no se especifica)"),
+    ("help.expense.remove", "Elimina un gasto por su número"),
+    ("help.expense.remove.id", "Número del gasto (ver `gtt expense list`)"),
+    ("help.llm", "Costo real de LLM importado desde los exports de los proveedores"),
+    ("help.llm.import", "Importa el uso real de LLM desde un export del proveedor (CSV o JSON)"),
+    ("help.llm.import.file", "Archivo exportado por el proveedor"),
+    ("help.llm.import.format", "Formato del archivo: csv, json, openai (default: autodetectar)"),
+    ("help.llm.import.provider", "Nombre del proveedor guardado con cada registro (default: el formato)"),
+    ("help.llm.import.tag", "Etiqueta (cliente o uno de sus llm_tags) para los registros sin etiqueta"),
+    ("help.schema", "Esquemas JSON de las salidas de gtt"),
+    ("help.schema.report", "JSON Schema de `gtt report --format json`"),
+    ("help.time", "Tiempo manual (reuniones, planificación) importado de Timewarrior u org-mode"),
+    ("help.time.import", "Importa tiempo manual desde Timewarrior (timew export) o relojes CLOCK de org-mode"),
+    ("help.time.import.file", "Archivo a importar"),
+    ("help.time.import.format", "Formato del archivo: timewarrior, org (default: según la extensión)"),
+    ("help.time.import.client", "Cliente para los intervalos sin una etiqueta que lo identifique"),
+    ("help.time.list", "Lista el tiempo manual registrado"),
+    ("help.time.list.client", "Nombre del cliente (todos si no se especifica)"),
+    ("help.time.remove", "Elimina una entrada de tiempo manual por su número"),
+    ("help.time.remove.id", "Número de la entrada (ver `gtt time list`)"),
+    ("help.invoice", "Registro de facturas emitidas a partir del total del reporte"),
+    ("help.invoice.create", "Registra una factura con el total del reporte del periodo"),
+    ("help.invoice.create.client", "Nombre del cliente"),
+    ("help.invoice.create.last_week", "Semana pasada"),
+    ("help.invoice.create.last_month", "Mes pasado"),
+    ("help.invoice.create.since", "Fecha de inicio (YYYY-MM-DD)"),
+    ("help.invoice.create.until", "Fecha de fin (YYYY-MM-DD)"),
+    ("help.invoice.create.number", "Número de factura (default: AAAA-NNN correlativo)"),
+    ("help.invoice.create.date", "Fecha de emisión (YYYY-MM-DD, default: hoy)"),
+    ("help.invoice.list", "Lista las facturas registradas con lo cobrado y lo pendiente"),
+    ("help.invoice.list.client", "Nombre del cliente (todos si no se especifica)"),
+    ("help.payments", "Registra cobros de facturas"),
+    ("help.payments.record", "Registra un pago (total o parcial) de una factura"),
+    ("help.payments.record.invoice", "Número de factura (ver `gtt invoice list`)"),
+    ("help.payments.record.amount", "Monto cobrado, en la moneda de la factura"),
+    ("help.payments.record.date", "Fecha del cobro (YYYY-MM-DD, default: hoy)"),
+    ("help.receivables", "Facturas pendientes de cobro por antigüedad (0-30, 31-60, 61-90+ días)"),
+    ("help.receivables.client", "Nombre del cliente (todos si no se especifica)"),
+    ("help.config", "Gestiona la configuración de gtt"),
+    ("help.config.show", "Muestra la configuración actual"),
+    ("help.config.edit", "Abre la configuración en $EDITOR"),
+    // Separators
+    ("common.same_separators", "thousands_separator '{separator}' es también el separador decimal; los montos se muestran sin separador de miles"),
+];
@@ -0,0 +1,193 @@
+//! Dates, times and amounts in human-readable output, following the
+//! `date_format`, `time_format`, `decimal_separator` and `thousands_separator`
+//! settings. CSV, JSON and the other machine-readable formats keep ISO dates
+//! and plain numbers.
+
+use std::cell::RefCell;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, NaiveDate, Timelike};
+use rust_decimal::Decimal;
+use crate::config::{Settings, TimeFormat};
+use crate::session::invoice::round_money;
+use super::{t, weekday_long, weekday_short};
+
+pub const DEFAULT_DATE_FORMAT: &str = "%d/%m/%Y";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formats {
+    /// strftime pattern of full dates
+    pub date: String,
+    pub time: TimeFormat,
+    pub decimal_separator: char,
+    pub thousands_separator: Option<char>,
+}
+
+impl Default for Formats {
+    fn default() -> Self {
+        Self {
+            date: DEFAULT_DATE_FORMAT.to_string(),
+            time: TimeFormat::H24,
+            decimal_separator: '.',
+            thousands_separator: None,
+        }
+    }
+}
+
+impl Formats {
+    /// Formats from the settings; an invalid `date_format` falls back to the
+    /// default and is returned as the error. A `thousands_separator` equal to
+    /// the decimal separator is dropped, as amounts couldn't be read back.
+    pub fn from_settings(settings: &Settings) -> (Formats, Option<String>) {
+        let mut invalid = None;
+        let date = match settings.date_format {
+            Some(ref pattern) if valid_date_format(pattern) => pattern.clone(),
+            Some(ref pattern) => {
+                invalid = Some(pattern.clone());
+                DEFAULT_DATE_FORMAT.to_string()
+            }
+            None => DEFAULT_DATE_FORMAT.to_string(),
+        };
+        let decimal_separator = settings.decimal_separator.unwrap_or('.');
+        let formats = Formats {
+            date,
+            time: settings.time_format,
+            decimal_separator,
+            thousands_separator: settings.thousands_separator.filter(|sep| *sep != decimal_separator),
+        };
+        (formats, invalid)
+    }
+}
+
+/// Whether chrono understands every specifier of `pattern`
+pub fn valid_date_format(pattern: &str) -> bool {
+    !pattern.is_empty() && StrftimeItems::new(pattern).all(|item| !matches!(item, Item::Error))
+}
+
+thread_local! {
+    static CURRENT: RefCell<Formats> = RefCell::new(Formats::default());
+}
+
+/// Formats of human-readable output on this thread
+pub fn formats() -> Formats {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+pub fn set_formats(formats: Formats) {
+    CURRENT.with(|c| *c.borrow_mut() = formats);
+}
+
+/// Full date (`05/01/2026`)
+pub fn format_date(date: NaiveDate) -> String {
+    strftime(date, &formats().date)
+}
+
+/// Date without the year, for day labels (`05/01`)
+pub fn format_day(date: NaiveDate) -> String {
+    strftime(date, &without_year(&formats().date))
+}
+
+/// Month and year (`01/2026`)
+pub fn format_month(date: NaiveDate) -> String {
+    strftime(date, &without_day(&formats().date))
+}
+
+/// Time of day (`14:05` or `2:05 PM`)
+pub fn format_time(time: impl Timelike) -> String {
+    match formats().time {
+        TimeFormat::H24 => format!("{:02}:{:02}", time.hour(), time.minute()),
+        TimeFormat::H12 => {
+            let (pm, hour) = time.hour12();
+            format!("{}:{:02} {}", hour, time.minute(), if pm { "PM" } else { "AM" })
+        }
+    }
+}
+
+/// Amount rounded to cents (`1,234.50`)
+pub fn format_money(amount: Decimal) -> String {
+    format_decimal(round_money(amount), 2)
+}
+
+/// Amount with an explicit sign (`+21.00`, `-10.00`)
+pub fn format_signed_money(amount: Decimal) -> String {
+    let text = format_money(amount);
+    if amount.is_sign_negative() && !amount.is_zero() { text } else { format!("+{}", text) }
+}
+
+/// Rates and other amounts shown as entered, without trailing zeros (`62.5`)
+pub fn format_amount(amount: Decimal) -> String {
+    let amount = amount.normalize();
+    format_decimal(amount, amount.scale() as usize)
+}
+
+/// Number with `places` decimals (`1.50`)
+pub fn format_number(value: f64, places: usize) -> String {
+    separate(&format!("{:.*}", places, value))
+}
+
+fn format_decimal(value: Decimal, places: usize) -> String {
+    separate(&format!("{:.*}", places, value))
+}
+
+/// Apply the separators to a plain number such as `-1234.50`
+fn separate(plain: &str) -> String {
+    let formats = formats();
+    let (sign, digits) = match plain.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", plain),
+    };
+    let (integer, fraction) = digits.split_once('.').map_or((digits, None), |(i, f)| (i, Some(f)));
+    let mut grouped = String::new();
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            if let Some(sep) = formats.thousands_separator {
+                grouped.push(sep);
+            }
+        }
+        grouped.push(c);
+    }
+    match fraction {
+        Some(fraction) => format!("{}{}{}{}", sign, grouped, formats.decimal_separator, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+/// chrono's strftime with weekday and month names from the catalog
+fn strftime(date: NaiveDate, pattern: &str) -> String {
+    let month = date.month();
+    let localized = pattern
+        .replace("%A", weekday_long(date.weekday()))
+        .replace("%a", weekday_short(date.weekday()))
+        .replace("%B", t(&format!("month.long.{}", month)))
+        .replace("%b", t(&format!("month.short.{}", month)))
+        .replace("%h", t(&format!("month.short.{}", month)));
+    date.format(&localized).to_string()
+}
+
+/// `pattern` without the specifier `remove` and the separator next to it
+fn without(pattern: &str, remove: &[&str]) -> String {
+    let mut result = pattern.to_string();
+    for spec in remove {
+        let Some(at) = result.find(spec) else {
+            continue;
+        };
+        let end = at + spec.len();
+        let before = result[..at].chars().next_back().filter(|c| !c.is_alphanumeric() && *c != '%');
+        let after = result[end..].chars().next().filter(|c| !c.is_alphanumeric() && *c != '%');
+        let (start, end) = match (before, after) {
+            (Some(c), _) => (at - c.len_utf8(), end),
+            (None, Some(c)) => (at, end + c.len_utf8()),
+            (None, None) => (at, end),
+        };
+        result.replace_range(start..end, "");
+    }
+    let trimmed = result.trim_matches(|c: char| c.is_whitespace() || c == ',');
+    if trimmed.is_empty() { pattern.to_string() } else { trimmed.to_string() }
+}
+
+fn without_year(pattern: &str) -> String {
+    without(pattern, &["%Y", "%y"])
+}
+
+fn without_day(pattern: &str) -> String {
+    without(pattern, &["%A", "%a", "%d", "%e"])
+}
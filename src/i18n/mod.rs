@@ -0,0 +1,154 @@
+//! Message catalog for user-facing text: terminal output, table headers, chart
+//! labels, the human-readable report formats (Markdown, HTML, spreadsheets) and
+//! errors. Machine-readable formats (CSV, JSON, calendars, journals) keep their
+//! fixed field names.
+//!
+//! Messages are looked up by key and may contain `{name}` placeholders, filled
+//! by `tf`. A key missing from a catalog falls back to English.
+
+mod en;
+mod es;
+mod format;
+
+use std::cell::Cell;
+use std::fmt::Display;
+use chrono::{NaiveDate, Weekday};
+
+#[allow(unused_imports)]
+pub use format::{
+    format_amount, format_date, format_day, format_money, format_month, format_number, format_signed_money, format_time,
+    formats, set_formats, valid_date_format, Formats, DEFAULT_DATE_FORMAT,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    En,
+    /// gtt's original language, used when neither the `locale` setting nor the
+    /// environment pick one
+    #[default]
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Language of a locale name such as `es`, `en-US` or `es_AR.UTF-8`
+    pub fn parse(name: &str) -> Option<Locale> {
+        let language = name
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or("");
+        Locale::ALL.into_iter().find(|l| l.code().eq_ignore_ascii_case(language))
+    }
+
+    /// The `locale` setting if supported, otherwise the first of `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG` that is set, otherwise the default
+    pub fn resolve(setting: Option<&str>) -> Locale {
+        if let Some(locale) = setting.and_then(Locale::parse) {
+            return locale;
+        }
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Locale::parse(&value))
+            .unwrap_or_default()
+    }
+
+    /// Every `(key, message)` pair of the catalog
+    pub fn messages(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::En => en::MESSAGES,
+            Locale::Es => es::MESSAGES,
+        }
+    }
+
+    fn lookup(self, key: &str) -> Option<&'static str> {
+        self.messages().iter().find(|(k, _)| *k == key).map(|(_, m)| *m)
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Locale> = const { Cell::new(Locale::Es) };
+}
+
+/// Language of the messages on this thread
+pub fn locale() -> Locale {
+    CURRENT.with(|c| c.get())
+}
+
+pub fn set_locale(locale: Locale) {
+    CURRENT.with(|c| c.set(locale));
+}
+
+/// Message for `key` in the current language
+pub fn t(key: &str) -> &'static str {
+    try_t(key).unwrap_or_else(|| panic!("missing message '{}'", key))
+}
+
+/// Message for `key` in the current language, or `None` when no catalog has it
+pub fn try_t(key: &str) -> Option<&'static str> {
+    locale().lookup(key).or_else(|| Locale::En.lookup(key))
+}
+
+/// Message for `key` with each `{name}` placeholder replaced by its value
+pub fn tf(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut message = t(key).to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+fn weekday_key(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "mon",
+        Weekday::Tue => "tue",
+        Weekday::Wed => "wed",
+        Weekday::Thu => "thu",
+        Weekday::Fri => "fri",
+        Weekday::Sat => "sat",
+        Weekday::Sun => "sun",
+    }
+}
+
+/// Abbreviated weekday name (`Lun`, `Mon`)
+pub fn weekday_short(weekday: Weekday) -> &'static str {
+    t(&format!("weekday.short.{}", weekday_key(weekday)))
+}
+
+/// Full weekday name (`Lunes`, `Monday`)
+pub fn weekday_long(weekday: Weekday) -> &'static str {
+    t(&format!("weekday.long.{}", weekday_key(weekday)))
+}
+
+/// Short day label for tables and charts (`Lun 05/01`)
+pub fn day_label(date: NaiveDate) -> String {
+    use chrono::Datelike;
+    if shows_weekday() {
+        return format_day(date);
+    }
+    format!("{} {}", weekday_short(date.weekday()), format_day(date))
+}
+
+/// Day heading for session listings (`Lunes 05/01/2026`)
+pub fn day_title(date: NaiveDate) -> String {
+    use chrono::Datelike;
+    if shows_weekday() {
+        return format_date(date);
+    }
+    format!("{} {}", weekday_long(date.weekday()), format_date(date))
+}
+
+/// Whether `date_format` already names the weekday
+fn shows_weekday() -> bool {
+    let pattern = formats().date;
+    pattern.contains("%a") || pattern.contains("%A")
+}
@@ -0,0 +1,212 @@
+use rust_decimal::Decimal;
+use serde_json::Value;
+use crate::errors::GttError;
+use crate::i18n::{t, tf};
+use crate::store::LlmUsageEntry;
+use super::parse_timestamp;
+
+/// A parser for one shape of LLM usage export
+pub trait UsageParser {
+    /// Name accepted by `gtt llm import --format`
+    fn name(&self) -> &'static str;
+    /// Whether `content` looks like this parser's shape
+    fn detect(&self, content: &str) -> bool;
+    fn parse(&self, content: &str) -> Result<Vec<LlmUsageEntry>, GttError>;
+}
+
+/// Available parsers, most specific first (auto-detection picks the first match)
+pub fn parsers() -> Vec<Box<dyn UsageParser>> {
+    vec![Box::new(OpenAiCosts), Box::new(JsonRecords), Box::new(CsvRecords)]
+}
+
+/// Parse an export with the parser named `format`, or auto-detect the shape.
+pub fn parse_usage(content: &str, format: Option<&str>) -> Result<Vec<LlmUsageEntry>, GttError> {
+    let all = parsers();
+    let parser = match format {
+        Some(name) => all.iter().find(|p| p.name() == name).ok_or_else(|| {
+            let names: Vec<_> = all.iter().map(|p| p.name()).collect();
+            GttError::ImportParse(tf("import.unknown_format", &[("format", &name), ("formats", &names.join(", "))]))
+        })?,
+        None => all
+            .iter()
+            .find(|p| p.detect(content))
+            .ok_or_else(|| GttError::ImportParse(t("import.unrecognized").to_string()))?,
+    };
+    parser.parse(content)
+}
+
+const TIMESTAMP_KEYS: &[&str] = &["timestamp", "time", "date", "created_at", "start_time", "usage_date_utc"];
+const COST_KEYS: &[&str] = &["cost", "cost_usd", "total_cost", "amount"];
+const MODEL_KEYS: &[&str] = &["model", "line_item"];
+const TAG_KEYS: &[&str] = &["tag", "project", "project_id", "workspace"];
+
+fn build_entry(
+    provider: &str,
+    timestamp: Option<&str>,
+    cost: Option<&str>,
+    currency: Option<&str>,
+    model: Option<&str>,
+    tag: Option<&str>,
+    row: usize,
+) -> Result<LlmUsageEntry, GttError> {
+    let timestamp = timestamp
+        .and_then(parse_timestamp)
+        .ok_or_else(|| GttError::ImportParse(tf("import.bad_timestamp", &[("record", &row)])))?;
+    let cost: Decimal = cost
+        .and_then(|c| c.trim().trim_start_matches('$').parse().ok())
+        .ok_or_else(|| GttError::ImportParse(tf("import.bad_cost", &[("record", &row)])))?;
+    let non_empty = |v: Option<&str>| v.map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+    Ok(LlmUsageEntry {
+        timestamp,
+        cost,
+        currency: non_empty(currency).map_or_else(|| "USD".to_string(), |c| c.to_uppercase()),
+        provider: provider.to_string(),
+        model: non_empty(model),
+        tag: non_empty(tag),
+    })
+}
+
+/// CSV with a header row; column names are matched loosely
+/// (`timestamp`/`date`, `cost`/`cost_usd`/`amount`, `currency`, `model`, `tag`/`project`).
+pub struct CsvRecords;
+
+impl UsageParser for CsvRecords {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        let header = content.lines().next().unwrap_or("").to_lowercase();
+        let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+        columns.iter().any(|c| TIMESTAMP_KEYS.contains(c)) && columns.iter().any(|c| COST_KEYS.contains(c))
+    }
+
+    fn parse(&self, content: &str) -> Result<Vec<LlmUsageEntry>, GttError> {
+        let mut reader = csv::Reader::from_reader(content.as_bytes());
+        let headers: Vec<String> = reader
+            .headers()
+            .map_err(|e| GttError::ImportParse(e.to_string()))?
+            .iter()
+            .map(|h| h.trim().to_lowercase())
+            .collect();
+        let column = |keys: &[&str]| keys.iter().find_map(|k| headers.iter().position(|h| h == k));
+        let (ts, cost, currency, model, tag) = (
+            column(TIMESTAMP_KEYS),
+            column(COST_KEYS),
+            column(&["currency"]),
+            column(MODEL_KEYS),
+            column(TAG_KEYS),
+        );
+
+        let mut entries = Vec::new();
+        for (i, record) in reader.records().enumerate() {
+            let record = record.map_err(|e| GttError::ImportParse(e.to_string()))?;
+            let field = |idx: Option<usize>| idx.and_then(|i| record.get(i));
+            entries.push(build_entry(
+                self.name(),
+                field(ts),
+                field(cost),
+                field(currency),
+                field(model),
+                field(tag),
+                i + 1,
+            )?);
+        }
+        Ok(entries)
+    }
+}
+
+/// JSON array of flat records (or `{"data": [...]}`) with the same keys as the CSV shape.
+pub struct JsonRecords;
+
+impl JsonRecords {
+    fn records(value: &Value) -> Option<&Vec<Value>> {
+        value.as_array().or_else(|| value.get("data")?.as_array())
+    }
+}
+
+impl UsageParser for JsonRecords {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        serde_json::from_str::<Value>(content).is_ok_and(|v| {
+            Self::records(&v).is_some_and(|r| r.iter().all(|item| COST_KEYS.iter().any(|k| item.get(k).is_some())))
+        })
+    }
+
+    fn parse(&self, content: &str) -> Result<Vec<LlmUsageEntry>, GttError> {
+        let value: Value = serde_json::from_str(content).map_err(|e| GttError::ImportParse(e.to_string()))?;
+        let records = Self::records(&value)
+            .ok_or_else(|| GttError::ImportParse(t("import.expected_records").to_string()))?;
+        records
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let field = |keys: &[&str]| keys.iter().find_map(|k| item.get(*k)).map(json_text);
+                build_entry(
+                    self.name(),
+                    field(TIMESTAMP_KEYS).as_deref(),
+                    field(COST_KEYS).as_deref(),
+                    field(&["currency"]).as_deref(),
+                    field(MODEL_KEYS).as_deref(),
+                    field(TAG_KEYS).as_deref(),
+                    i + 1,
+                )
+            })
+            .collect()
+    }
+}
+
+/// OpenAI organization costs export: daily buckets with `start_time` (Unix
+/// seconds) and `results[].amount.{value,currency}`; `project_id` becomes the tag.
+pub struct OpenAiCosts;
+
+impl UsageParser for OpenAiCosts {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        serde_json::from_str::<Value>(content).is_ok_and(|v| {
+            v.get("data")
+                .and_then(Value::as_array)
+                .is_some_and(|buckets| buckets.iter().all(|b| b.get("results").is_some()))
+        })
+    }
+
+    fn parse(&self, content: &str) -> Result<Vec<LlmUsageEntry>, GttError> {
+        let value: Value = serde_json::from_str(content).map_err(|e| GttError::ImportParse(e.to_string()))?;
+        let buckets = value
+            .get("data")
+            .and_then(Value::as_array)
+            .ok_or_else(|| GttError::ImportParse(t("import.expected_buckets").to_string()))?;
+
+        let mut entries = Vec::new();
+        for bucket in buckets {
+            let start = bucket.get("start_time").map(json_text);
+            for result in bucket.get("results").and_then(Value::as_array).into_iter().flatten() {
+                let amount = result.get("amount");
+                let text = |v: Option<&Value>| v.filter(|v| !v.is_null()).map(json_text);
+                entries.push(build_entry(
+                    self.name(),
+                    start.as_deref(),
+                    text(amount.and_then(|a| a.get("value"))).as_deref(),
+                    text(amount.and_then(|a| a.get("currency"))).as_deref(),
+                    text(result.get("line_item")).as_deref(),
+                    text(result.get("project_id")).as_deref(),
+                    entries.len() + 1,
+                )?);
+            }
+        }
+        Ok(entries)
+    }
+}
+
+fn json_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
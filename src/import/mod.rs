@@ -0,0 +1,38 @@
+pub mod llm;
+pub mod org;
+pub mod timewarrior;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+/// Parse the timestamp formats found in provider exports: RFC 3339, naive
+/// date-times and dates (taken as UTC) and Unix seconds.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt);
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(naive.and_utc().fixed_offset());
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date.and_hms_opt(0, 0, 0)?.and_utc().fixed_offset());
+    }
+    let seconds: i64 = value.parse().ok()?;
+    Some(DateTime::<Utc>::from_timestamp(seconds, 0)?.fixed_offset())
+}
+
+/// Tag marking time that gtt itself exported; importers skip it so git
+/// sessions are not counted twice.
+pub const GTT_TAG: &str = "gtt";
+
+/// A closed time interval read from another time tracker
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedInterval {
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+    /// Timewarrior tags, or the org heading path and its tags
+    pub tags: Vec<String>,
+    pub description: Option<String>,
+}
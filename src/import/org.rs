@@ -0,0 +1,83 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, TimeZone};
+use crate::errors::GttError;
+use crate::i18n::tf;
+use super::{ImportedInterval, GTT_TAG};
+
+struct Heading {
+    level: usize,
+    title: String,
+    tags: Vec<String>,
+}
+
+/// Parse closed `CLOCK: [start]--[end]` lines from an org-mode file. Each
+/// interval gets its heading path (titles and inherited tags) as tags and the
+/// innermost heading title as description. Timestamps are local time; clocks
+/// under a heading tagged `:gtt:` are skipped.
+pub fn parse_org_clocks(content: &str) -> Result<Vec<ImportedInterval>, GttError> {
+    let mut path: Vec<Heading> = Vec::new();
+    let mut intervals = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let level = line.chars().take_while(|c| *c == '*').count();
+        if level > 0 && line[level..].starts_with(' ') {
+            let (title, tags) = split_tags(line[level..].trim());
+            path.retain(|h| h.level < level);
+            path.push(Heading { level, title, tags });
+            continue;
+        }
+
+        let Some(clock) = line.trim_start().strip_prefix("CLOCK:") else {
+            continue;
+        };
+        // Running clocks have no end yet
+        let Some((start, rest)) = clock.trim().split_once("]--[") else {
+            continue;
+        };
+        let end = rest.split(']').next().unwrap_or("");
+        let line_no = i + 1;
+        let (start, end) = (parse_org_time(start.trim_start_matches('['), line_no)?, parse_org_time(end, line_no)?);
+
+        let tags: Vec<String> = path
+            .iter()
+            .flat_map(|h| std::iter::once(h.title.clone()).chain(h.tags.iter().cloned()))
+            .filter(|t| !t.is_empty())
+            .collect();
+        if path.iter().any(|h| h.tags.iter().any(|t| t == GTT_TAG)) {
+            continue;
+        }
+        intervals.push(ImportedInterval {
+            start,
+            end,
+            tags,
+            description: path.last().map(|h| h.title.clone()).filter(|t| !t.is_empty()),
+        });
+    }
+    Ok(intervals)
+}
+
+/// Split trailing org tags (`Title   :a:b:`) from a heading title
+fn split_tags(heading: &str) -> (String, Vec<String>) {
+    if let Some((title, last)) = heading.rsplit_once(char::is_whitespace) {
+        if last.len() > 2 && last.starts_with(':') && last.ends_with(':') {
+            let tags = last.trim_matches(':').split(':').map(str::to_string).collect();
+            return (title.trim().to_string(), tags);
+        }
+    }
+    (heading.to_string(), Vec::new())
+}
+
+/// `2026-01-05 Mon 08:30` (the weekday is optional), in local time
+fn parse_org_time(value: &str, line: usize) -> Result<DateTime<FixedOffset>, GttError> {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    let (date, time) = match parts.as_slice() {
+        [date, _, time] | [date, time] => (*date, *time),
+        _ => return Err(GttError::ImportParse(tf("import.bad_clock", &[("line", &line), ("value", &value)]))),
+    };
+    let naive = NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M")
+        .map_err(|_| GttError::ImportParse(tf("import.bad_clock", &[("line", &line), ("value", &value)])))?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.fixed_offset())
+        .ok_or_else(|| GttError::ImportParse(tf("import.nonexistent_time", &[("line", &line), ("value", &value)])))
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use crate::errors::GttError;
+use crate::i18n::tf;
+use super::{ImportedInterval, GTT_TAG};
+
+#[derive(Deserialize)]
+struct TimewInterval {
+    start: String,
+    end: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    annotation: Option<String>,
+}
+
+/// Parse `timew export` JSON. Open intervals (still running) and intervals
+/// tagged `gtt` are skipped.
+pub fn parse_timewarrior(content: &str) -> Result<Vec<ImportedInterval>, GttError> {
+    let raw: Vec<TimewInterval> =
+        serde_json::from_str(content).map_err(|e| GttError::ImportParse(format!("Timewarrior JSON: {}", e)))?;
+    let mut intervals = Vec::new();
+    for (i, interval) in raw.into_iter().enumerate() {
+        let Some(ref end) = interval.end else {
+            continue;
+        };
+        if interval.tags.iter().any(|t| t == GTT_TAG) {
+            continue;
+        }
+        intervals.push(ImportedInterval {
+            start: parse_timew_date(&interval.start, i + 1)?,
+            end: parse_timew_date(end, i + 1)?,
+            tags: interval.tags,
+            description: interval.annotation.filter(|a| !a.trim().is_empty()),
+        });
+    }
+    Ok(intervals)
+}
+
+/// Timewarrior dates are UTC as `YYYYMMDDTHHMMSSZ`
+fn parse_timew_date(value: &str, record: usize) -> Result<chrono::DateTime<chrono::FixedOffset>, GttError> {
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .map(|naive| naive.and_utc().fixed_offset())
+        .map_err(|_| GttError::ImportParse(tf("import.bad_interval_date", &[("interval", &record), ("value", &value)])))
+}
@@ -1,5 +1,8 @@
 pub mod config;
 pub mod errors;
 pub mod git;
+pub mod i18n;
+pub mod import;
 pub mod output;
 pub mod session;
+pub mod store;
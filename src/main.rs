@@ -2,14 +2,27 @@ mod commands;
 mod config;
 mod errors;
 mod git;
+mod i18n;
+mod import;
 mod output;
 mod session;
+mod store;
 
-use clap::{Parser, Subcommand};
+use clap::{Command, CommandFactory, FromArgMatches, Parser, Subcommand};
 use anyhow::Result;
+use colored::Colorize;
 
 use commands::config_cmd::ConfigAction;
-use commands::{export, init, report, status, verify};
+use commands::expense::ExpenseAction;
+use commands::invoice::InvoiceAction;
+use commands::llm::LlmAction;
+use commands::payments::PaymentsAction;
+use commands::schema::SchemaAction;
+use commands::time::TimeAction;
+use commands::{balance, budget, expense, export, init, invoice, llm, payments, receivables, report, schema, status, time, verify};
+use balance::BalanceArgs;
+use budget::BudgetArgs;
+use receivables::ReceivablesArgs;
 use report::ReportArgs;
 use verify::VerifyArgs;
 use export::ExportArgs;
@@ -34,7 +47,7 @@ enum Commands {
     /// Resumen rápido: horas de hoy y esta semana por cliente
     Status,
 
-    /// Reporte de horas por cliente (tabla, CSV o JSON)
+    /// Reporte de horas por cliente (tabla, CSV, JSON, Markdown o HTML)
     Report {
         /// Nombre del cliente (todos si no se especifica)
         #[arg(long)]
@@ -56,13 +69,37 @@ enum Commands {
         #[arg(long)]
         until: Option<String>,
 
-        /// Formato de salida: table, csv, json
+        /// Formato de salida: table, csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods
         #[arg(long, default_value = "table")]
         format: String,
 
         /// Archivo de salida (default: stdout para table, archivo auto para csv/json)
         #[arg(long)]
         output: Option<String>,
+
+        /// Convierte los montos a otra moneda (p. ej. EUR) con la tabla de tipos de cambio
+        #[arg(long)]
+        in_currency: Option<String>,
+
+        /// Fecha del tipo de cambio: invoice (fin del periodo) o day (cada día)
+        #[arg(long, default_value = "invoice")]
+        rate_date: String,
+
+        /// Incluye el detalle por sesión con sus commits (markdown)
+        #[arg(long)]
+        sessions: bool,
+
+        /// Asientos de ledger/beancount: period (uno por periodo) o day (uno por día)
+        #[arg(long, default_value = "period")]
+        postings: String,
+
+        /// Detalle en JSON, CSV y NDJSON: sessions (cada sesión) o commits (cada sesión con sus commits)
+        #[arg(long)]
+        detail: Option<String>,
+
+        /// Filas de la tabla, CSV y JSON: day, week (semana ISO), month, repo o weekday (día de la semana)
+        #[arg(long, default_value = "day")]
+        group_by: String,
     },
 
     /// Lista las sesiones detectadas con timestamps para validar antes de facturar
@@ -110,13 +147,94 @@ enum Commands {
         #[arg(long)]
         until: Option<String>,
 
-        /// Formato: csv, json
+        /// Formato: csv, json, markdown, html, ics, toggl, clockify, harvest, timeclock, ledger, beancount, timewarrior, org, ndjson, xlsx, ods
         #[arg(long, default_value = "csv")]
         format: String,
 
         /// Archivo de salida (auto-generado si no se especifica)
         #[arg(long)]
         output: Option<String>,
+
+        /// Convierte los montos a otra moneda (p. ej. EUR) con la tabla de tipos de cambio
+        #[arg(long)]
+        in_currency: Option<String>,
+
+        /// Fecha del tipo de cambio: invoice (fin del periodo) o day (cada día)
+        #[arg(long, default_value = "invoice")]
+        rate_date: String,
+
+        /// Incluye el detalle por sesión con sus commits (markdown)
+        #[arg(long)]
+        sessions: bool,
+
+        /// Asientos de ledger/beancount: period (uno por periodo) o day (uno por día)
+        #[arg(long, default_value = "period")]
+        postings: String,
+
+        /// Detalle en JSON, CSV y NDJSON: sessions (cada sesión) o commits (cada sesión con sus commits)
+        #[arg(long)]
+        detail: Option<String>,
+
+        /// Filas de la tabla, CSV y JSON: day, week (semana ISO), month, repo o weekday (día de la semana)
+        #[arg(long, default_value = "day")]
+        group_by: String,
+    },
+
+    /// Saldo de retainer y horas prepagas por mes
+    Balance {
+        /// Nombre del cliente (todos los que tengan retainer si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
+    },
+
+    /// Presupuestos: consumo, ritmo y fecha estimada de agotamiento
+    Budget {
+        /// Nombre del cliente (todos los que tengan presupuestos si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
+    },
+
+    /// Gastos reembolsables que se facturan junto a las horas
+    Expense {
+        #[command(subcommand)]
+        action: ExpenseAction,
+    },
+
+    /// Costo real de LLM importado desde los exports de los proveedores
+    Llm {
+        #[command(subcommand)]
+        action: LlmAction,
+    },
+
+    /// Esquemas JSON de las salidas de gtt
+    Schema {
+        #[command(subcommand)]
+        action: SchemaAction,
+    },
+
+    /// Tiempo manual (reuniones, planificación) importado de Timewarrior u org-mode
+    Time {
+        #[command(subcommand)]
+        action: TimeAction,
+    },
+
+    /// Registro de facturas emitidas a partir del total del reporte
+    Invoice {
+        #[command(subcommand)]
+        action: InvoiceAction,
+    },
+
+    /// Registra cobros de facturas
+    Payments {
+        #[command(subcommand)]
+        action: PaymentsAction,
+    },
+
+    /// Facturas pendientes de cobro por antigüedad (0-30, 31-60, 61-90+ días)
+    Receivables {
+        /// Nombre del cliente (todos si no se especifica)
+        #[arg(long)]
+        client: Option<String>,
     },
 
     /// Gestiona la configuración de gtt
@@ -127,7 +245,9 @@ enum Commands {
 }
 
 fn main() {
-    let cli = Cli::parse();
+    init_locale();
+    let matches = localize_help(Cli::command(), "help").get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     if let Err(e) = run(cli) {
         eprintln!("Error: {}", e);
@@ -135,6 +255,50 @@ fn main() {
     }
 }
 
+/// Pick the message language from the `locale` setting or the environment, and
+/// the date and number formats from the settings
+fn init_locale() {
+    let settings = config::load_config().map(|c| c.settings).unwrap_or_default();
+    let setting = settings.locale.as_deref();
+    i18n::set_locale(i18n::Locale::resolve(setting));
+    if let Some(name) = setting {
+        if i18n::Locale::parse(name).is_none() {
+            eprintln!("{}", i18n::tf("common.unsupported_locale", &[("locale", &name)]).yellow());
+        }
+    }
+    let (formats, invalid) = i18n::Formats::from_settings(&settings);
+    i18n::set_formats(formats);
+    if let Some(pattern) = invalid {
+        let message =
+            i18n::tf("common.invalid_date_format", &[("pattern", &pattern), ("default", &i18n::DEFAULT_DATE_FORMAT)]);
+        eprintln!("{}", message.yellow());
+    }
+    if let Some(separator) = settings.thousands_separator.filter(|_| i18n::formats().thousands_separator.is_none()) {
+        eprintln!("{}", i18n::tf("common.same_separators", &[("separator", &separator)]).yellow());
+    }
+}
+
+/// Replace the help of `command`, its arguments and its subcommands with the
+/// catalog's `help.<subcommand>.<argument>` messages. The doc comments above
+/// stay as the text of anything the catalogs lack.
+fn localize_help(mut command: Command, key: &str) -> Command {
+    if let Some(about) = i18n::try_t(key) {
+        command = command.about(about);
+    }
+    let args: Vec<String> = command.get_arguments().map(|a| a.get_id().to_string()).collect();
+    for id in args {
+        if let Some(help) = i18n::try_t(&format!("{}.{}", key, id)) {
+            command = command.mut_arg(id, |a| a.help(help));
+        }
+    }
+    let subcommands: Vec<String> = command.get_subcommands().map(|c| c.get_name().to_string()).collect();
+    for name in subcommands {
+        let sub_key = format!("{}.{}", key, name);
+        command = command.mut_subcommand(name, |c| localize_help(c, &sub_key));
+    }
+    command
+}
+
 fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::Init => init::run(),
@@ -149,6 +313,12 @@ fn run(cli: Cli) -> Result<()> {
             until,
             format,
             output,
+            in_currency,
+            rate_date,
+            sessions,
+            postings,
+            detail,
+            group_by,
         } => report::run(ReportArgs {
             client,
             last_week,
@@ -157,6 +327,12 @@ fn run(cli: Cli) -> Result<()> {
             until,
             format,
             output,
+            in_currency,
+            rate_date,
+            sessions,
+            postings,
+            detail,
+            group_by,
             verify_mode: false,
         }),
 
@@ -182,6 +358,12 @@ fn run(cli: Cli) -> Result<()> {
             until,
             format,
             output,
+            in_currency,
+            rate_date,
+            sessions,
+            postings,
+            detail,
+            group_by,
         } => export::run(ExportArgs {
             client,
             last_week,
@@ -190,8 +372,32 @@ fn run(cli: Cli) -> Result<()> {
             until,
             format,
             output,
+            in_currency,
+            rate_date,
+            sessions,
+            postings,
+            detail,
+            group_by,
         }),
 
+        Commands::Balance { client } => balance::run(BalanceArgs { client }),
+
+        Commands::Budget { client } => budget::run(BudgetArgs { client }),
+
+        Commands::Expense { action } => expense::run(action),
+
+        Commands::Llm { action } => llm::run(action),
+
+        Commands::Schema { action } => schema::run(action),
+
+        Commands::Time { action } => time::run(action),
+
+        Commands::Invoice { action } => invoice::run(action),
+
+        Commands::Payments { action } => payments::run(action),
+
+        Commands::Receivables { client } => receivables::run(ReceivablesArgs { client }),
+
         Commands::Config { action } => commands::config_cmd::run(action),
     }
 }
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use colored::Colorize;
+use crate::i18n::{day_label, format_day, t, weekday_short};
 use crate::output::table::format_duration;
 use crate::session::types::{ClientReport, DayReport};
 
-type DenseDay = (NaiveDate, u32, usize, u32); // (date, minutes, commits, lines_added)
+pub(crate) type DenseDay = (NaiveDate, u32, usize, u32, u32); // (date, minutes, commits, lines_added, overflow)
 
 pub fn print_charts(report: &ClientReport) {
     if report.days.is_empty() {
@@ -17,15 +18,22 @@ pub fn print_charts(report: &ClientReport) {
     print_heatmap(&dense);
 }
 
-fn build_dense_days(days: &[DayReport], start: NaiveDate, end: NaiveDate) -> Vec<DenseDay> {
+/// One entry per calendar day of the period, zero-filled on days without commits
+pub(crate) fn build_dense_days(days: &[DayReport], start: NaiveDate, end: NaiveDate) -> Vec<DenseDay> {
     let map: HashMap<NaiveDate, &DayReport> = days.iter().map(|d| (d.date, d)).collect();
     let mut result = Vec::new();
     let mut cur = start;
     while cur <= end {
         if let Some(day) = map.get(&cur) {
-            result.push((cur, day.total_minutes, day.total_commits, day.total_lines_added));
+            result.push((
+                cur,
+                day.total_minutes,
+                day.total_commits,
+                day.total_lines_added,
+                day.overflow_minutes,
+            ));
         } else {
-            result.push((cur, 0, 0, 0));
+            result.push((cur, 0, 0, 0, 0));
         }
         cur += Duration::days(1);
     }
@@ -76,23 +84,33 @@ fn section_header(title: &str) -> String {
 
 // Chart 1: Horizontal bar chart by day (hours)
 fn print_hours_chart(dense: &[DenseDay]) {
-    println!("{}", section_header("Horas por día").bold());
+    println!("{}", section_header(t("chart.hours_per_day")).bold());
     let max = dense.iter().map(|d| d.1).max().unwrap_or(0);
-    for (date, minutes, _, _) in dense {
-        let dur = if *minutes == 0 {
+    let any_overflow = dense.iter().any(|d| d.4 > 0);
+    for (date, minutes, _, _, overflow) in dense {
+        let mut dur = if *minutes == 0 {
             "0m".to_string()
         } else {
             format_duration(*minutes)
         };
-        let label = date.format("%a %d/%m").to_string();
+        if *overflow > 0 {
+            dur.push_str(&format!(" ({} {})", format_duration(*overflow), t("chart.overflow")).red().to_string());
+        }
+        let label = day_label(*date);
         let filled = filled_len(*minutes as f64, max as f64, 24);
+        // The tail of the bar covering the overflow is drawn in red
+        let over = filled_len((*overflow).min(*minutes) as f64, max as f64, 24).min(filled);
         let bar = format!(
-            "{}{}",
-            "█".repeat(filled).cyan(),
+            "{}{}{}",
+            "█".repeat(filled - over).cyan(),
+            "█".repeat(over).red(),
             "░".repeat(24 - filled).dimmed()
         );
         println!("  {label:10}  {bar}  {dur}");
     }
+    if any_overflow {
+        println!("  {} {}", "█".red(), t("chart.overflow_legend"));
+    }
     println!();
 }
 
@@ -101,11 +119,11 @@ fn print_sparklines(dense: &[DenseDay]) {
     println!("{}", section_header("Sparklines").bold());
     let start_label = dense
         .first()
-        .map(|d| d.0.format("%d/%m").to_string())
+        .map(|d| format_day(d.0))
         .unwrap_or_default();
     let end_label = dense
         .last()
-        .map(|d| d.0.format("%d/%m").to_string())
+        .map(|d| format_day(d.0))
         .unwrap_or_default();
 
     let max_hours = dense.iter().map(|d| d.1).max().unwrap_or(0);
@@ -120,17 +138,18 @@ fn print_sparklines(dense: &[DenseDay]) {
         .map(|d| spark_char(d.2 as f64, max_commits as f64))
         .collect();
 
-    println!("  Horas   {} {}  {}", start_label, spark_hours.cyan(), end_label);
-    println!("  Commits {} {}  {}", start_label, spark_commits.yellow(), end_label);
+    let width = [t("col.hours"), t("col.commits")].iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    println!("  {:width$} {} {}  {}", t("col.hours"), start_label, spark_hours.cyan(), end_label);
+    println!("  {:width$} {} {}  {}", t("col.commits"), start_label, spark_commits.yellow(), end_label);
     println!();
 }
 
 // Chart 3: Multi-metric per day (only active days)
 fn print_multi_metric(dense: &[DenseDay]) {
-    println!("{}", section_header("Métricas por día").bold());
+    println!("{}", section_header(t("chart.metrics_per_day")).bold());
     let active: Vec<&DenseDay> = dense.iter().filter(|d| d.1 > 0).collect();
     if active.is_empty() {
-        println!("  (sin actividad)");
+        println!("  ({})", t("chart.no_activity"));
         println!();
         return;
     }
@@ -138,13 +157,15 @@ fn print_multi_metric(dense: &[DenseDay]) {
     let max_commits = active.iter().map(|d| d.2).max().unwrap_or(0);
     let max_lines = active.iter().map(|d| d.3).max().unwrap_or(0);
 
-    for (date, minutes, commits, lines) in &active {
-        let label = date.format("%a %d/%m").to_string();
-        println!("  {label}");
+    let labels = [t("col.hours"), t("col.commits"), t("chart.code")];
+    let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    for (date, minutes, commits, lines, _) in &active {
+        println!("  {}", day_label(*date));
 
         let fh = filled_len(*minutes as f64, max_minutes as f64, 20);
         println!(
-            "    Horas   │{}{}│ {}",
+            "    {:width$} │{}{}│ {}",
+            labels[0],
             "█".repeat(fh).green(),
             "░".repeat(20 - fh).dimmed(),
             format_duration(*minutes)
@@ -152,7 +173,8 @@ fn print_multi_metric(dense: &[DenseDay]) {
 
         let fc = filled_len(*commits as f64, max_commits as f64, 20);
         println!(
-            "    Commits │{}{}│ {}",
+            "    {:width$} │{}{}│ {}",
+            labels[1],
             "█".repeat(fc).yellow(),
             "░".repeat(20 - fc).dimmed(),
             commits
@@ -160,7 +182,8 @@ fn print_multi_metric(dense: &[DenseDay]) {
 
         let fl = filled_len(*lines as f64, max_lines as f64, 20);
         println!(
-            "    Código  │{}{}│ +{}",
+            "    {:width$} │{}{}│ +{}",
+            labels[2],
             "█".repeat(fl).blue(),
             "░".repeat(20 - fl).dimmed(),
             lines
@@ -171,12 +194,12 @@ fn print_multi_metric(dense: &[DenseDay]) {
 
 // Chart 4: Weekly heat map (rows = Mon–Sun, columns = ISO weeks)
 fn print_heatmap(dense: &[DenseDay]) {
-    println!("{}", section_header("Mapa de calor").bold());
+    println!("{}", section_header(t("chart.heatmap")).bold());
 
     // Collect ISO weeks in chronological order (dense is already sorted)
     let mut weeks: Vec<(i32, u32)> = Vec::new();
     let mut seen = std::collections::HashSet::new();
-    for (date, _, _, _) in dense {
+    for (date, _, _, _, _) in dense {
         let iw = date.iso_week();
         let key = (iw.year(), iw.week());
         if seen.insert(key) {
@@ -186,7 +209,7 @@ fn print_heatmap(dense: &[DenseDay]) {
 
     // Build lookup: (iso_year, iso_week, weekday_0=Mon) -> minutes
     let mut heat: HashMap<(i32, u32, u32), u32> = HashMap::new();
-    for (date, minutes, _, _) in dense {
+    for (date, minutes, _, _, _) in dense {
         let iw = date.iso_week();
         let wd = date.weekday().num_days_from_monday();
         heat.insert((iw.year(), iw.week(), wd), *minutes);
@@ -198,9 +221,8 @@ fn print_heatmap(dense: &[DenseDay]) {
     let header: String = weeks.iter().map(|(_, w)| format!(" W{w:02}")).collect();
     println!("      {}", header.dimmed());
 
-    let day_names = ["Lun", "Mar", "Mié", "Jue", "Vie", "Sáb", "Dom"];
     for wd in 0u32..7 {
-        let name = day_names[wd as usize];
+        let name = weekday_short(Weekday::try_from(wd as u8).unwrap_or(Weekday::Mon));
         let mut row = String::new();
         for (iy, iw) in &weeks {
             if heat.contains_key(&(*iy, *iw, wd)) {
@@ -1,38 +1,215 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use crate::i18n::tf;
+use crate::session::grouping::{group_report, GroupBy};
+use crate::session::invoice::round_money;
 use crate::session::types::ClientReport;
+use super::Detail;
 
-pub fn serialize_csv(report: &ClientReport) -> Result<String> {
+/// The report as CSV: one row per day, per `group_by` group, or per session or
+/// commit with `detail`, followed by the rate, expense and invoice sections.
+pub fn serialize_csv(report: &ClientReport, detail: Option<Detail>, group_by: GroupBy) -> Result<String> {
+    let mut data = match (detail, group_by) {
+        (None, GroupBy::Day) => day_rows(report)?,
+        (None, _) => group_rows(report, group_by)?,
+        (Some(Detail::Sessions), GroupBy::Day) => session_rows(report)?,
+        (Some(Detail::Commits), GroupBy::Day) => commit_rows(report)?,
+        (Some(_), _) => bail!(tf("report.group_by_detail", &[("value", &group_by.name())])),
+    };
+
+    // Mixed-rate periods get a summary section with one subtotal per category and rate
+    let subtotals = report.rate_subtotals();
+    if subtotals.len() > 1 {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+        wtr.write_record(["category", "hourly_rate", "billed_minutes", "billed_hours", "amount", "currency"])?;
+        for sub in &subtotals {
+            wtr.write_record([
+                &sub.category,
+                &format!("{:.2}", round_money(sub.hourly_rate)),
+                &sub.billed_minutes.to_string(),
+                &format!("{:.4}", sub.billed_hours()),
+                &format!("{:.2}", round_money(sub.amount)),
+                &report.currency,
+            ])?;
+        }
+        data.push('\n');
+        data.push_str(&String::from_utf8(wtr.into_inner()?)?);
+    }
+
+    // Expenses are itemized in their own section
+    if !report.expenses.is_empty() {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+        wtr.write_record(["expense_date", "description", "repo", "amount", "currency"])?;
+        for e in &report.expenses {
+            wtr.write_record([
+                &e.date.format("%Y-%m-%d").to_string(),
+                &e.description,
+                e.repo.as_deref().unwrap_or(""),
+                &format!("{:.2}", round_money(e.amount)),
+                &report.currency,
+            ])?;
+        }
+        data.push('\n');
+        data.push_str(&String::from_utf8(wtr.into_inner()?)?);
+    }
+
+    // Discounts and taxes get an invoice section: subtotal, one row per line, total
+    let invoice = report.invoice();
+    if invoice.has_adjustments() {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+        wtr.write_record(["line", "percent", "amount", "currency"])?;
+        wtr.write_record(["subtotal", "", &format!("{:.2}", invoice.subtotal), &report.currency])?;
+        for line in invoice.discounts.iter().chain(&invoice.taxes) {
+            wtr.write_record([
+                &line.name,
+                &line.percent.map(|p| p.normalize().to_string()).unwrap_or_default(),
+                &format!("{:.2}", line.amount),
+                &report.currency,
+            ])?;
+        }
+        wtr.write_record(["total", "", &format!("{:.2}", invoice.total()), &report.currency])?;
+        data.push('\n');
+        data.push_str(&String::from_utf8(wtr.into_inner()?)?);
+    }
+
+    Ok(data)
+}
+
+fn day_rows(report: &ClientReport) -> Result<String> {
     let mut wtr = csv::Writer::from_writer(vec![]);
 
-    wtr.write_record(["date", "sessions", "hours", "minutes", "commits", "repos", "amount", "llm_cost", "total_amount", "currency", "lines_added", "lines_deleted"])?;
+    let mut header = vec!["date", "sessions", "hours", "minutes", "billed_minutes", "overflow_minutes", "covered_minutes", "commits", "repos", "hourly_rate", "amount", "llm_cost", "total_amount", "currency", "lines_added", "lines_deleted"];
+    if report.converted.is_some() {
+        header.extend(["exchange_rate", "converted_total_amount", "converted_currency"]);
+    }
+    wtr.write_record(&header)?;
 
-    for day in &report.days {
+    for (i, day) in report.days.iter().enumerate() {
         let repos = day.repos.join("|");
         let hours = day.total_hours();
-        let amount = if report.hourly_rate > 0.0 {
-            hours * report.hourly_rate
-        } else {
-            0.0
-        };
-        let day_lines = (day.total_lines_added + day.total_lines_deleted) as f64;
-        let day_llm = (day_lines / 1000.0) * report.llm_cost_per_kloc;
-
-        wtr.write_record([
-            &day.date.format("%Y-%m-%d").to_string(),
-            &day.sessions.len().to_string(),
-            &format!("{:.4}", hours),
-            &day.total_minutes.to_string(),
-            &day.total_commits.to_string(),
-            &repos,
-            &format!("{:.2}", amount),
-            &format!("{:.2}", day_llm),
-            &format!("{:.2}", amount + day_llm),
-            &report.currency,
-            &day.total_lines_added.to_string(),
-            &day.total_lines_deleted.to_string(),
-        ])?;
-    }
-
-    let data = String::from_utf8(wtr.into_inner()?)?;
-    Ok(data)
+        let amount = day.amount();
+        let day_llm = report.day_llm_cost(day);
+
+        let mut record = vec![
+            day.date.format("%Y-%m-%d").to_string(),
+            day.sessions.len().to_string(),
+            format!("{:.4}", hours),
+            day.total_minutes.to_string(),
+            day.billed_minutes.to_string(),
+            day.overflow_minutes.to_string(),
+            day.covered_minutes.to_string(),
+            day.total_commits.to_string(),
+            repos,
+            format!("{:.2}", round_money(day.hourly_rate)),
+            format!("{:.2}", round_money(amount)),
+            format!("{:.2}", round_money(day_llm)),
+            format!("{:.2}", round_money(report.day_total_amount(day))),
+            report.currency.clone(),
+            day.total_lines_added.to_string(),
+            day.total_lines_deleted.to_string(),
+        ];
+        if let Some(ref conv) = report.converted {
+            let rate = conv.day_rates[i];
+            record.push(format!("{:.6}", rate));
+            record.push(format!("{:.2}", round_money(report.day_total_amount(day) * rate)));
+            record.push(conv.currency.clone());
+        }
+        wtr.write_record(&record)?;
+    }
+
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+/// One row per group, named after the dimension in the first column
+fn group_rows(report: &ClientReport, group_by: GroupBy) -> Result<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+
+    let mut header = vec![group_by.name(), "days", "sessions", "hours", "minutes", "billed_minutes", "overflow_minutes", "covered_minutes", "commits", "repos", "amount", "llm_cost", "total_amount", "currency", "lines_added", "lines_deleted"];
+    if report.converted.is_some() {
+        header.extend(["converted_total_amount", "converted_currency"]);
+    }
+    wtr.write_record(&header)?;
+
+    for group in group_report(report, group_by) {
+        let mut record = vec![
+            group.key.id(),
+            group.days.to_string(),
+            group.sessions.to_string(),
+            format!("{:.4}", group.total_hours()),
+            group.total_minutes.to_string(),
+            group.billed_minutes.to_string(),
+            group.overflow_minutes.to_string(),
+            group.covered_minutes.to_string(),
+            group.total_commits.to_string(),
+            group.repos.join("|"),
+            format!("{:.2}", round_money(group.amount)),
+            format!("{:.2}", round_money(group.llm_cost)),
+            format!("{:.2}", round_money(group.total_amount())),
+            report.currency.clone(),
+            group.lines_added.to_string(),
+            group.lines_deleted.to_string(),
+        ];
+        if let (Some(conv), Some(total)) = (&report.converted, group.converted_total) {
+            record.push(format!("{:.2}", round_money(total)));
+            record.push(conv.currency.clone());
+        }
+        wtr.write_record(&record)?;
+    }
+
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+/// One row per session with its duration components
+fn session_rows(report: &ClientReport) -> Result<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    wtr.write_record([
+        "date", "session", "start", "end", "minutes", "base_minutes", "gap_minutes", "volume_minutes",
+        "manual_minutes", "commits", "repos", "lines_added", "lines_deleted", "description",
+    ])?;
+    for day in &report.days {
+        for (i, session) in day.sessions.iter().enumerate() {
+            wtr.write_record([
+                day.date.format("%Y-%m-%d").to_string(),
+                (i + 1).to_string(),
+                session.start.to_rfc3339(),
+                session.end.to_rfc3339(),
+                session.duration_minutes.to_string(),
+                session.parts.base.to_string(),
+                session.parts.gap.to_string(),
+                session.parts.volume.to_string(),
+                session.parts.manual.to_string(),
+                session.commits.len().to_string(),
+                session.repos.join("|"),
+                session.lines_added.to_string(),
+                session.lines_deleted.to_string(),
+                session.description(),
+            ])?;
+        }
+    }
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+/// One row per commit, with the session it was counted in
+fn commit_rows(report: &ClientReport) -> Result<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    wtr.write_record([
+        "date", "session", "session_minutes", "time", "hash", "repo", "subject", "lines_added", "lines_deleted",
+    ])?;
+    for day in &report.days {
+        for (i, session) in day.sessions.iter().enumerate() {
+            for commit in &session.commits {
+                wtr.write_record([
+                    day.date.format("%Y-%m-%d").to_string(),
+                    (i + 1).to_string(),
+                    session.duration_minutes.to_string(),
+                    commit.author_date.to_rfc3339(),
+                    commit.hash.clone(),
+                    commit.repo_name.clone(),
+                    commit.subject.clone(),
+                    commit.lines_added.to_string(),
+                    commit.lines_deleted.to_string(),
+                ])?;
+            }
+        }
+    }
+    Ok(String::from_utf8(wtr.into_inner()?)?)
 }
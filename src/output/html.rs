@@ -0,0 +1,466 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use chrono::{Datelike, Weekday};
+use crate::i18n::{
+    day_label, format_amount, format_date, format_day, format_money, format_number, format_signed_money, format_time,
+    locale, t, tf, weekday_short,
+};
+use crate::session::billing::DEFAULT_CATEGORY;
+use crate::session::types::ClientReport;
+use super::chart::{build_dense_days, DenseDay};
+use super::table::{day_sessions, format_duration};
+
+const STYLE: &str = "\
+body{font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;color:#1f2937;max-width:960px;margin:2rem auto;padding:0 1rem}
+h1{margin-bottom:.2rem}h2{margin-top:2rem;border-bottom:1px solid #e5e7eb;padding-bottom:.3rem}
+.period{color:#6b7280;margin-top:0}
+table{border-collapse:collapse;width:100%;margin:.5rem 0}
+th,td{padding:.35rem .6rem;border-bottom:1px solid #e5e7eb;text-align:left}
+th{background:#f3f4f6}td.num,th.num{text-align:right;font-variant-numeric:tabular-nums}
+tr.total td{font-weight:bold;border-top:2px solid #9ca3af}
+.overflow{color:#dc2626}.amount{font-weight:bold;color:#047857}
+.charts svg{display:block;margin:.5rem 0 1.5rem}
+details{margin:.3rem 0}summary{cursor:pointer}
+ul.commits{list-style:none;padding-left:1.2rem}code{color:#92400e}
+";
+
+/// Render the report as a single self-contained HTML page: summary, per-day
+/// table, amounts, the four charts as inline SVG and a collapsible drill-down
+/// of sessions and commits. No external assets are referenced.
+pub fn serialize_html(report: &ClientReport) -> String {
+    let mut html = String::new();
+    let title = tf("report.title", &[("client", &report.client_name)]);
+    let _ = writeln!(html, "<!DOCTYPE html>");
+    let _ = writeln!(html, "<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">", locale().code());
+    let _ = writeln!(html, "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+    let _ = writeln!(html, "<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>", esc(&title), STYLE);
+    let _ = writeln!(html, "<h1>{}</h1>", esc(&title));
+    let _ = writeln!(
+        html,
+        "<p class=\"period\">{}</p>",
+        tf(
+            "report.period",
+            &[
+                ("since", &format_date(report.period_start)),
+                ("until", &format_date(report.period_end)),
+            ]
+        )
+    );
+
+    write_summary(&mut html, report);
+    write_days(&mut html, report);
+    if !report.days.is_empty() {
+        let dense = build_dense_days(&report.days, report.period_start, report.period_end);
+        let _ = writeln!(html, "<h2>{}</h2>\n<div class=\"charts\">", t("report.charts"));
+        html += &hours_chart(&dense);
+        html += &sparklines(&dense);
+        html += &multi_metric(&dense);
+        html += &heatmap(&dense);
+        let _ = writeln!(html, "</div>");
+        write_sessions(&mut html, report);
+    }
+    let _ = writeln!(html, "</body>\n</html>");
+    html
+}
+
+fn write_summary(html: &mut String, report: &ClientReport) {
+    let currency = &report.currency;
+    let _ = writeln!(html, "<h2>{}</h2>\n<table>", t("report.summary"));
+    let row = |html: &mut String, label: &str, value: String| {
+        let _ = writeln!(html, "<tr><th>{}</th><td class=\"num\">{}</td></tr>", label, value);
+    };
+    row(html, t("report.logged_hours"), format_duration(report.total_minutes));
+    if report.billed_minutes != report.total_minutes {
+        row(html, t("report.billed_hours"), format_duration(report.billed_minutes));
+    }
+    if report.overflow_minutes > 0 {
+        row(html, t("report.unbilled_overflow"), format_duration(report.overflow_minutes));
+    }
+    let sessions: usize = report.days.iter().map(|d| d.sessions.len()).sum();
+    row(html, t("col.sessions"), sessions.to_string());
+    row(html, t("col.commits"), report.total_commits.to_string());
+    row(html, t("col.lines"), format!("+{} -{}", report.total_lines_added, report.total_lines_deleted));
+
+    if report.has_rate() {
+        let subtotals = report.rate_subtotals();
+        let show_category = subtotals.iter().any(|s| s.category != DEFAULT_CATEGORY);
+        for sub in &subtotals {
+            let label = if show_category { format!("{}: ", esc(&sub.category)) } else { String::new() };
+            row(
+                html,
+                &format!("{}{}h × {}/h", label, format_number(sub.billed_hours(), 2), format_amount(sub.hourly_rate)),
+                format!("{} {}", format_money(sub.amount), currency),
+            );
+        }
+        if report.covered_minutes > 0 {
+            row(
+                html,
+                &tf("report.covered_by", &[("duration", &format_duration(report.covered_minutes))]),
+                format!("-{} {}", format_money(report.covered_amount()), currency),
+            );
+        }
+        if report.shows_llm_cost() {
+            let label = if report.has_llm_usage() { t("report.llm_actual") } else { "LLM" };
+            row(html, label, format!("{} {}", format_money(report.llm_cost), currency));
+        }
+        for e in &report.expenses {
+            row(
+                html,
+                &format!("{} {}: {}", t("report.expense"), format_date(e.date), esc(&e.description)),
+                format!("{} {}", format_money(e.amount), currency),
+            );
+        }
+        let invoice = report.invoice();
+        if invoice.has_adjustments() {
+            row(html, t("common.subtotal"), format!("{} {}", format_money(invoice.subtotal), currency));
+            for line in invoice.discounts.iter().chain(&invoice.taxes) {
+                let label = match line.percent {
+                    Some(percent) => format!("{} ({}%)", esc(&line.name), format_amount(percent)),
+                    None => esc(&line.name),
+                };
+                row(html, &label, format!("{} {}", format_signed_money(line.amount), currency));
+            }
+        }
+        let _ = writeln!(
+            html,
+            "<tr class=\"total\"><th>{}</th><td class=\"num amount\">{} {}</td></tr>",
+            t("report.total_due"),
+            format_money(invoice.total()),
+            currency
+        );
+        if let Some(ref conv) = report.converted {
+            row(html, &tf("report.in_currency", &[("currency", &esc(&conv.currency))]), format!("{} {}", format_money(conv.total_amount), esc(&conv.currency)));
+        }
+    } else {
+        row(html, t("report.rate_label"), t("report.no_rate").to_string());
+    }
+    let _ = writeln!(html, "</table>");
+}
+
+fn write_days(html: &mut String, report: &ClientReport) {
+    if report.days.is_empty() {
+        return;
+    }
+    let show_llm = report.shows_llm_cost();
+    let show_billed = report.days.iter().any(|d| d.billed_minutes != d.total_minutes);
+    let show_overflow = report.overflow_minutes > 0;
+
+    let _ = writeln!(html, "<h2>{}</h2>\n<table>\n<thead><tr>", t("report.by_day"));
+    let _ = write!(
+        html,
+        "<th>{}</th><th class=\"num\">{}</th><th class=\"num\">{}</th>",
+        t("col.date"),
+        t("col.sessions"),
+        t("col.hours")
+    );
+    if show_billed {
+        let _ = write!(html, "<th class=\"num\">{}</th>", t("col.billed"));
+    }
+    if show_overflow {
+        let _ = write!(html, "<th class=\"num\">{}</th>", t("col.overflow"));
+    }
+    let _ = write!(html, "<th class=\"num\">{}</th><th class=\"num\">+/-</th>", t("col.commits"));
+    if show_llm {
+        let _ = write!(html, "<th class=\"num\">LLM</th>");
+    }
+    let _ = writeln!(html, "<th>{}</th></tr></thead>\n<tbody>", t("col.repos"));
+
+    for day in &report.days {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>",
+            day_label(day.date),
+            day.sessions.len(),
+            format_duration(day.total_minutes)
+        );
+        if show_billed {
+            let _ = write!(html, "<td class=\"num\">{}</td>", format_duration(day.billed_minutes));
+        }
+        if show_overflow {
+            let overflow = if day.overflow_minutes > 0 { format_duration(day.overflow_minutes) } else { String::new() };
+            let _ = write!(html, "<td class=\"num overflow\">{}</td>", overflow);
+        }
+        let _ = write!(
+            html,
+            "<td class=\"num\">{}</td><td class=\"num\">+{} -{}</td>",
+            day.total_commits, day.total_lines_added, day.total_lines_deleted
+        );
+        if show_llm {
+            let _ = write!(html, "<td class=\"num\">{}</td>", format_money(report.day_llm_cost(day)));
+        }
+        let _ = writeln!(html, "<td>{}</td></tr>", esc(&day.repos.join(", ")));
+    }
+
+    let sessions: usize = report.days.iter().map(|d| d.sessions.len()).sum();
+    let _ = write!(
+        html,
+        "<tr class=\"total\"><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>",
+        t("common.total"),
+        sessions,
+        format_duration(report.total_minutes)
+    );
+    if show_billed {
+        let _ = write!(html, "<td class=\"num\">{}</td>", format_duration(report.billed_minutes));
+    }
+    if show_overflow {
+        let _ = write!(html, "<td class=\"num overflow\">{}</td>", format_duration(report.overflow_minutes));
+    }
+    let _ = write!(
+        html,
+        "<td class=\"num\">{}</td><td class=\"num\">+{} -{}</td>",
+        report.total_commits, report.total_lines_added, report.total_lines_deleted
+    );
+    if show_llm {
+        let _ = write!(html, "<td class=\"num\">{}</td>", format_money(report.llm_cost));
+    }
+    let _ = writeln!(html, "<td></td></tr>\n</tbody>\n</table>");
+}
+
+/// Collapsible drill-down: day → session → commits
+fn write_sessions(html: &mut String, report: &ClientReport) {
+    let _ = writeln!(html, "<h2>{}</h2>", t("col.sessions"));
+    for day in &report.days {
+        let _ = writeln!(html, "<details>\n<summary>{}</summary>", esc(&day_sessions(day)));
+        for (i, session) in day.sessions.iter().enumerate() {
+            let _ = writeln!(
+                html,
+                "<details style=\"margin-left:1.2rem\">\n<summary>{}: {} → {} ({}, {} commits, +{} -{})</summary>\n<ul class=\"commits\">",
+                tf("verify.session", &[("number", &(i + 1))]),
+                format_time(session.start),
+                format_time(session.end),
+                format_duration(session.duration_minutes),
+                session.commits.len(),
+                session.lines_added,
+                session.lines_deleted
+            );
+            if let Some(ref note) = session.note {
+                let _ = writeln!(html, "<li><em>manual:</em> {}</li>", esc(note));
+            }
+            for commit in &session.commits {
+                let short = esc(&commit.hash[..commit.hash.len().min(7)]);
+                let hash = match report.commit_url(commit) {
+                    Some(url) => format!("<a href=\"{}\"><code>{}</code></a>", esc(&url), short),
+                    None => format!("<code>{}</code>", short),
+                };
+                let _ = writeln!(
+                    html,
+                    "<li>{} {} {} <small>[{}] +{} -{}</small></li>",
+                    format_time(commit.author_date),
+                    hash,
+                    esc(&commit.subject),
+                    esc(&commit.repo_name),
+                    commit.lines_added,
+                    commit.lines_deleted
+                );
+            }
+            let _ = writeln!(html, "</ul>\n</details>");
+        }
+        let _ = writeln!(html, "</details>");
+    }
+}
+
+fn scaled(value: f64, max: f64, width: f64) -> f64 {
+    if max == 0.0 {
+        0.0
+    } else {
+        value / max * width
+    }
+}
+
+fn chart_title(title: &str) -> String {
+    format!("<h3>{}</h3>\n", title)
+}
+
+// Chart 1: horizontal bars per day, overflow tail in red
+fn hours_chart(dense: &[DenseDay]) -> String {
+    let (label_w, bar_w, row_h) = (80.0, 420.0, 18.0);
+    let max = dense.iter().map(|d| d.1).max().unwrap_or(0) as f64;
+    let height = dense.len() as f64 * row_h + 4.0;
+    let mut svg = chart_title(t("chart.hours_per_day"));
+    let _ = writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"640\" height=\"{}\" font-size=\"11\">", height);
+    for (i, (date, minutes, _, _, overflow)) in dense.iter().enumerate() {
+        let y = i as f64 * row_h;
+        let filled = scaled(*minutes as f64, max, bar_w);
+        let over = scaled((*overflow).min(*minutes) as f64, max, bar_w);
+        let _ = writeln!(svg, "<text x=\"0\" y=\"{:.1}\">{}</text>", y + 12.0, day_label(*date));
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{:.1}\" width=\"{}\" height=\"13\" fill=\"#f3f4f6\"/>",
+            label_w,
+            y + 2.0,
+            bar_w
+        );
+        if filled > 0.0 {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{:.1}\" width=\"{:.1}\" height=\"13\" fill=\"#0891b2\"/>",
+                label_w,
+                y + 2.0,
+                filled - over
+            );
+        }
+        if over > 0.0 {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"13\" fill=\"#dc2626\"/>",
+                label_w + filled - over,
+                y + 2.0,
+                over
+            );
+        }
+        let dur = if *minutes == 0 { "0m".to_string() } else { format_duration(*minutes) };
+        let _ = writeln!(svg, "<text x=\"{}\" y=\"{:.1}\">{}</text>", label_w + bar_w + 8.0, y + 12.0, dur);
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+// Chart 2: sparklines of hours and commits across the period
+fn sparklines(dense: &[DenseDay]) -> String {
+    let (label_w, width, line_h) = (60.0, 480.0, 36.0);
+    let step = if dense.len() > 1 { width / (dense.len() - 1) as f64 } else { 0.0 };
+    let line = |values: Vec<f64>, y0: f64, color: &str| {
+        let max = values.iter().cloned().fold(0.0, f64::max);
+        let points: Vec<String> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| format!("{:.1},{:.1}", label_w + i as f64 * step, y0 + line_h - scaled(*v, max, line_h - 4.0)))
+            .collect();
+        format!(
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1.5\"/>\n",
+            points.join(" "),
+            color
+        )
+    };
+    let mut svg = chart_title("Sparklines");
+    let _ = writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"640\" height=\"{}\" font-size=\"11\">", line_h * 2.0 + 20.0);
+    let _ = writeln!(svg, "<text x=\"0\" y=\"{}\">{}</text>", line_h - 4.0, t("col.hours"));
+    svg += &line(dense.iter().map(|d| d.1 as f64).collect(), 0.0, "#0891b2");
+    let _ = writeln!(svg, "<text x=\"0\" y=\"{}\">{}</text>", line_h * 2.0 + 4.0, t("col.commits"));
+    svg += &line(dense.iter().map(|d| d.2 as f64).collect(), line_h + 8.0, "#ca8a04");
+    if let (Some(first), Some(last)) = (dense.first(), dense.last()) {
+        let y = line_h * 2.0 + 18.0;
+        let _ = writeln!(svg, "<text x=\"{}\" y=\"{}\" fill=\"#6b7280\">{}</text>", label_w, y, format_day(first.0));
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" fill=\"#6b7280\" text-anchor=\"end\">{}</text>",
+            label_w + width,
+            y,
+            format_day(last.0)
+        );
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+// Chart 3: hours, commits and lines per active day
+fn multi_metric(dense: &[DenseDay]) -> String {
+    let active: Vec<&DenseDay> = dense.iter().filter(|d| d.1 > 0).collect();
+    let (label_w, bar_w, group_h) = (80.0, 360.0, 48.0);
+    let max_minutes = active.iter().map(|d| d.1).max().unwrap_or(0) as f64;
+    let max_commits = active.iter().map(|d| d.2).max().unwrap_or(0) as f64;
+    let max_lines = active.iter().map(|d| d.3).max().unwrap_or(0) as f64;
+
+    let mut svg = chart_title(t("chart.metrics_per_day"));
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"640\" height=\"{}\" font-size=\"11\">",
+        active.len() as f64 * group_h + 4.0
+    );
+    for (i, (date, minutes, commits, lines, _)) in active.iter().enumerate() {
+        let y = i as f64 * group_h;
+        let _ = writeln!(svg, "<text x=\"0\" y=\"{:.1}\">{}</text>", y + 12.0, day_label(*date));
+        let metrics = [
+            (scaled(*minutes as f64, max_minutes, bar_w), "#16a34a", format_duration(*minutes)),
+            (scaled(*commits as f64, max_commits, bar_w), "#ca8a04", format!("{} commits", commits)),
+            (scaled(*lines as f64, max_lines, bar_w), "#2563eb", format!("+{}", lines)),
+        ];
+        for (j, (width, color, label)) in metrics.iter().enumerate() {
+            let row_y = y + 2.0 + j as f64 * 14.0;
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{:.1}\" width=\"{:.1}\" height=\"11\" fill=\"{}\"/>",
+                label_w, row_y, width, color
+            );
+            let _ = writeln!(svg, "<text x=\"{}\" y=\"{:.1}\">{}</text>", label_w + bar_w + 8.0, row_y + 10.0, label);
+        }
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+// Chart 4: heat map, rows Monday to Sunday, one column per ISO week
+fn heatmap(dense: &[DenseDay]) -> String {
+    let mut weeks: Vec<(i32, u32)> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut heat: HashMap<(i32, u32, u32), u32> = HashMap::new();
+    for (date, minutes, _, _, _) in dense {
+        let iw = date.iso_week();
+        if seen.insert((iw.year(), iw.week())) {
+            weeks.push((iw.year(), iw.week()));
+        }
+        heat.insert((iw.year(), iw.week(), date.weekday().num_days_from_monday()), *minutes);
+    }
+    let max = dense.iter().map(|d| d.1).max().unwrap_or(0) as f64;
+    let (label_w, cell, top) = (36.0, 18.0, 16.0);
+
+    let mut svg = chart_title(t("chart.heatmap"));
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-size=\"10\">",
+        label_w + weeks.len() as f64 * cell + 4.0,
+        top + 7.0 * cell + 4.0
+    );
+    for (col, (_, week)) in weeks.iter().enumerate() {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"11\" fill=\"#6b7280\">W{:02}</text>",
+            label_w + col as f64 * cell,
+            week
+        );
+    }
+    for wd in 0..7 {
+        let y = top + wd as f64 * cell;
+        let name = weekday_short(Weekday::try_from(wd as u8).unwrap_or(Weekday::Mon));
+        let _ = writeln!(svg, "<text x=\"0\" y=\"{:.1}\">{}</text>", y + 12.0, name);
+        for (col, (year, week)) in weeks.iter().enumerate() {
+            let Some(minutes) = heat.get(&(*year, *week, wd as u32)) else {
+                continue;
+            };
+            let x = label_w + col as f64 * cell;
+            let fill = if *minutes == 0 {
+                "fill=\"#f3f4f6\"".to_string()
+            } else {
+                format!("fill=\"#0891b2\" fill-opacity=\"{:.2}\"", 0.2 + 0.8 * scaled(*minutes as f64, max, 1.0))
+            };
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{}\" height=\"{}\" rx=\"2\" {}><title>{}</title></rect>",
+                x,
+                y,
+                cell - 3.0,
+                cell - 3.0,
+                fill,
+                format_duration(*minutes)
+            );
+        }
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+/// Escape text for HTML content and attribute values
+pub fn esc(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
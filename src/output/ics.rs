@@ -0,0 +1,83 @@
+use chrono::{DateTime, Duration, Utc};
+use crate::session::types::{ClientReport, Session};
+
+/// Render every session of the report as an iCalendar VEVENT.
+///
+/// Events start the session's base minutes (credited before the first commit)
+/// before its first commit and end at its last commit; manual time keeps its
+/// own interval. The UID comes
+/// from the hash of the session's first commit, so re-importing an export after
+/// more commits joined the session updates the event instead of duplicating it.
+pub fn serialize_ics(report: &ClientReport, stamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//gtt//Git Time Tracker//ES".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(&format!("gtt — {}", report.client_name))),
+    ];
+    for session in report.days.iter().flat_map(|d| &d.sessions) {
+        lines.extend(event(report, session, stamp));
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = String::new();
+    for line in lines {
+        ics += &fold(&line);
+        ics += "\r\n";
+    }
+    ics
+}
+
+fn event(report: &ClientReport, session: &Session, stamp: DateTime<Utc>) -> Vec<String> {
+    let uid = session.commits.first().map_or_else(
+        || format!("{}-{}", session.start.timestamp(), report.client_name.replace(' ', "-")),
+        |c| c.hash.clone(),
+    );
+    let start = session.start - Duration::minutes(session.parts.base as i64);
+    let mut description: Vec<String> = session
+        .commits
+        .iter()
+        .map(|c| format!("{} {} {}", c.author_date.format("%H:%M"), &c.hash[..c.hash.len().min(7)], c.subject))
+        .collect();
+    description.extend(session.note.clone());
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@gtt", uid),
+        format!("DTSTAMP:{}", utc(stamp)),
+        format!("DTSTART:{}", utc(start.with_timezone(&Utc))),
+        format!("DTEND:{}", utc(session.end.with_timezone(&Utc))),
+        format!("SUMMARY:{}", escape(&format!("{} — {}", report.client_name, session.repos.join(", ")))),
+        format!("DESCRIPTION:{}", escape(&description.join("\n"))),
+        format!("CATEGORIES:{}", escape(&report.client_name)),
+        "TRANSP:OPAQUE".to_string(),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+fn utc(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11)
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold lines longer than 75 octets, continuing with a leading space (RFC 5545 §3.1)
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded += "\r\n ";
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
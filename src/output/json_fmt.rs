@@ -1,75 +1,363 @@
 use anyhow::Result;
+use rust_decimal::Decimal;
 use serde::Serialize;
-use crate::session::types::ClientReport;
+use crate::session::currency::RateDate;
+use crate::session::grouping::{group_report, Group, GroupBy, GroupKey};
+use crate::session::invoice::{round_money, InvoiceLine};
+use crate::git::Commit;
+use crate::session::types::{ClientReport, DayReport, Session};
+use super::Detail;
+
+/// Version of the JSON report layout. Fields may be added within a version;
+/// renaming, removing or retyping one bumps it (and `schema/report.schema.json`).
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// JSON Schema of the report, printed by `gtt schema report`
+pub const REPORT_SCHEMA: &str = include_str!("../../schema/report.schema.json");
 
 #[derive(Serialize)]
 struct JsonReport<'a> {
+    schema_version: u32,
     client: &'a str,
     period_start: String,
     period_end: String,
     total_minutes: u32,
     total_hours: f64,
+    billed_minutes: u32,
+    billed_hours: f64,
+    overflow_minutes: u32,
+    covered_minutes: u32,
     total_commits: usize,
-    hourly_rate: f64,
+    hourly_rate: Decimal,
     currency: &'a str,
-    billable_amount: f64,
+    billable_amount: Decimal,
+    covered_amount: Decimal,
+    overage_amount: Decimal,
+    rate_subtotals: Vec<JsonRateSubtotal>,
+    llm_cost: Decimal,
+    /// "usage" when imported provider usage was attributed, else "per_kloc"
+    llm_cost_source: &'static str,
+    expenses: Vec<JsonExpense<'a>>,
+    expenses_total: Decimal,
+    subtotal: Decimal,
+    discounts: Vec<JsonInvoiceLine>,
+    taxes: Vec<JsonInvoiceLine>,
+    total_amount: Decimal,
     total_lines_added: u32,
     total_lines_deleted: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    converted: Option<JsonConversion<'a>>,
     days: Vec<JsonDay<'a>>,
+    /// Set with `--group-by` other than `day`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_by: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Vec<JsonGroup>>,
+}
+
+#[derive(Serialize)]
+struct JsonGroup {
+    /// `2026-W02`, `2026-01`, `monday` or the repo name (empty without one)
+    key: String,
+    /// First day of a week or month
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<String>,
+    days: usize,
+    sessions: usize,
+    total_minutes: u32,
+    total_hours: f64,
+    billed_minutes: u32,
+    billed_hours: f64,
+    overflow_minutes: u32,
+    covered_minutes: u32,
+    total_commits: usize,
+    repos: Vec<String>,
+    amount: Decimal,
+    llm_cost: Decimal,
+    total_amount: Decimal,
+    lines_added: u32,
+    lines_deleted: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    converted_total_amount: Option<Decimal>,
 }
 
 #[derive(Serialize)]
-struct JsonDay<'a> {
+struct JsonConversion<'a> {
+    currency: &'a str,
+    rate_date: &'static str,
+    /// Set when a single rate was used for the whole report
+    exchange_rate: Option<Decimal>,
+    billable_amount: Decimal,
+    covered_amount: Decimal,
+    overage_amount: Decimal,
+    llm_cost: Decimal,
+    expenses: Decimal,
+    total_amount: Decimal,
+}
+
+#[derive(Serialize)]
+struct JsonExpense<'a> {
+    date: String,
+    description: &'a str,
+    repo: Option<&'a str>,
+    amount: Decimal,
+}
+
+#[derive(Serialize)]
+struct JsonInvoiceLine {
+    name: String,
+    percent: Option<Decimal>,
+    amount: Decimal,
+}
+
+#[derive(Serialize)]
+struct JsonRateSubtotal {
+    category: String,
+    hourly_rate: Decimal,
+    billed_minutes: u32,
+    billed_hours: f64,
+    amount: Decimal,
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonDay<'a> {
     date: String,
     sessions: usize,
     total_minutes: u32,
     total_hours: f64,
+    billed_minutes: u32,
+    billed_hours: f64,
+    overflow_minutes: u32,
+    covered_minutes: u32,
     total_commits: usize,
     repos: &'a [String],
-    amount: f64,
+    repo_minutes: Vec<JsonRepoMinutes<'a>>,
+    hourly_rate: Decimal,
+    amount: Decimal,
+    llm_cost: Decimal,
+    total_amount: Decimal,
     lines_added: u32,
     lines_deleted: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_detail: Option<Vec<JsonSession<'a>>>,
 }
 
-pub fn serialize_json(report: &ClientReport) -> Result<String> {
+#[derive(Serialize)]
+struct JsonRepoMinutes<'a> {
+    repo: &'a str,
+    minutes: u32,
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonSession<'a> {
+    start: String,
+    end: String,
+    duration_minutes: u32,
+    components: JsonComponents,
+    repos: &'a [String],
+    total_commits: usize,
+    lines_added: u32,
+    lines_deleted: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commits: Option<Vec<JsonCommit<'a>>>,
+}
+
+#[derive(Serialize)]
+struct JsonComponents {
+    base_minutes: u32,
+    gap_minutes: u32,
+    volume_minutes: u32,
+    manual_minutes: u32,
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonCommit<'a> {
+    hash: &'a str,
+    time: String,
+    subject: &'a str,
+    repo: &'a str,
+    lines_added: u32,
+    lines_deleted: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+/// The report as JSON. With `detail`, each day also lists its sessions
+/// (`session_detail`), and with `Detail::Commits` each session its commits.
+/// Grouping by anything but the day adds the `groups` next to the days.
+pub fn serialize_json(report: &ClientReport, detail: Option<Detail>, group_by: GroupBy) -> Result<String> {
     let days = report
         .days
         .iter()
-        .map(|day| {
-            let hours = day.total_hours();
-            let amount = if report.hourly_rate > 0.0 {
-                hours * report.hourly_rate
-            } else {
-                0.0
-            };
-            JsonDay {
-                date: day.date.format("%Y-%m-%d").to_string(),
-                sessions: day.sessions.len(),
-                total_minutes: day.total_minutes,
-                total_hours: (hours * 100.0).round() / 100.0,
-                total_commits: day.total_commits,
-                repos: &day.repos,
-                amount: (amount * 100.0).round() / 100.0,
-                lines_added: day.total_lines_added,
-                lines_deleted: day.total_lines_deleted,
-            }
-        })
+        .map(|day| json_day(report, day, detail))
         .collect();
 
+    let invoice = report.invoice();
     let json_report = JsonReport {
+        schema_version: SCHEMA_VERSION,
         client: &report.client_name,
         period_start: report.period_start.format("%Y-%m-%d").to_string(),
         period_end: report.period_end.format("%Y-%m-%d").to_string(),
         total_minutes: report.total_minutes,
         total_hours: (report.total_hours() * 100.0).round() / 100.0,
+        billed_minutes: report.billed_minutes,
+        billed_hours: (report.billed_hours() * 100.0).round() / 100.0,
+        overflow_minutes: report.overflow_minutes,
+        covered_minutes: report.covered_minutes,
         total_commits: report.total_commits,
         hourly_rate: report.hourly_rate,
         currency: &report.currency,
-        billable_amount: (report.billable_amount() * 100.0).round() / 100.0,
+        billable_amount: round_money(report.billable_amount()),
+        covered_amount: round_money(report.covered_amount()),
+        overage_amount: round_money(report.overage_amount()),
+        rate_subtotals: report
+            .rate_subtotals()
+            .iter()
+            .map(|sub| JsonRateSubtotal {
+                category: sub.category.clone(),
+                hourly_rate: sub.hourly_rate,
+                billed_minutes: sub.billed_minutes,
+                billed_hours: (sub.billed_hours() * 100.0).round() / 100.0,
+                amount: round_money(sub.amount),
+            })
+            .collect(),
+        llm_cost: round_money(report.llm_cost),
+        llm_cost_source: if report.has_llm_usage() { "usage" } else { "per_kloc" },
+        expenses: report
+            .expenses
+            .iter()
+            .map(|e| JsonExpense {
+                date: e.date.format("%Y-%m-%d").to_string(),
+                description: &e.description,
+                repo: e.repo.as_deref(),
+                amount: round_money(e.amount),
+            })
+            .collect(),
+        expenses_total: round_money(report.expenses_total()),
+        subtotal: invoice.subtotal,
+        discounts: invoice.discounts.iter().map(json_line).collect(),
+        taxes: invoice.taxes.iter().map(json_line).collect(),
+        total_amount: invoice.total(),
         total_lines_added: report.total_lines_added,
         total_lines_deleted: report.total_lines_deleted,
+        converted: report.converted.as_ref().map(|conv| JsonConversion {
+            currency: &conv.currency,
+            rate_date: match conv.rate_date {
+                RateDate::Invoice => "invoice",
+                RateDate::Day => "day",
+            },
+            exchange_rate: conv.single_rate(),
+            billable_amount: round_money(conv.billable_amount),
+            covered_amount: round_money(conv.covered_amount),
+            overage_amount: round_money(conv.overage_amount()),
+            llm_cost: round_money(conv.llm_cost),
+            expenses: round_money(conv.expenses),
+            total_amount: conv.total_amount,
+        }),
         days,
+        group_by: (group_by != GroupBy::Day).then(|| group_by.name()),
+        groups: (group_by != GroupBy::Day).then(|| group_report(report, group_by).into_iter().map(json_group).collect()),
     };
 
     Ok(serde_json::to_string_pretty(&json_report)?)
 }
+
+fn json_group(group: Group) -> JsonGroup {
+    JsonGroup {
+        key: group.key.id(),
+        start: match group.key {
+            GroupKey::Week { .. } | GroupKey::Month { .. } => group.key.start().map(|d| d.format("%Y-%m-%d").to_string()),
+            _ => None,
+        },
+        days: group.days,
+        sessions: group.sessions,
+        total_minutes: group.total_minutes,
+        total_hours: (group.total_hours() * 100.0).round() / 100.0,
+        billed_minutes: group.billed_minutes,
+        billed_hours: (group.billed_hours() * 100.0).round() / 100.0,
+        overflow_minutes: group.overflow_minutes,
+        covered_minutes: group.covered_minutes,
+        total_commits: group.total_commits,
+        amount: round_money(group.amount),
+        llm_cost: round_money(group.llm_cost),
+        total_amount: round_money(group.total_amount()),
+        lines_added: group.lines_added,
+        lines_deleted: group.lines_deleted,
+        converted_total_amount: group.converted_total.map(round_money),
+        repos: group.repos,
+    }
+}
+
+fn json_line(line: &InvoiceLine) -> JsonInvoiceLine {
+    JsonInvoiceLine {
+        name: line.name.clone(),
+        percent: line.percent,
+        amount: line.amount,
+    }
+}
+
+pub(crate) fn json_day<'a>(report: &ClientReport, day: &'a DayReport, detail: Option<Detail>) -> JsonDay<'a> {
+    JsonDay {
+        date: day.date.format("%Y-%m-%d").to_string(),
+        sessions: day.sessions.len(),
+        total_minutes: day.total_minutes,
+        total_hours: (day.total_hours() * 100.0).round() / 100.0,
+        billed_minutes: day.billed_minutes,
+        billed_hours: (day.billed_hours() * 100.0).round() / 100.0,
+        overflow_minutes: day.overflow_minutes,
+        covered_minutes: day.covered_minutes,
+        total_commits: day.total_commits,
+        repos: &day.repos,
+        repo_minutes: day
+            .repo_minutes
+            .iter()
+            .map(|r| JsonRepoMinutes { repo: &r.repo, minutes: r.minutes })
+            .collect(),
+        hourly_rate: day.hourly_rate,
+        amount: round_money(day.amount()),
+        llm_cost: round_money(report.day_llm_cost(day)),
+        total_amount: round_money(report.day_total_amount(day)),
+        lines_added: day.total_lines_added,
+        lines_deleted: day.total_lines_deleted,
+        session_detail: detail.map(|detail| {
+            day.sessions
+                .iter()
+                .map(|session| json_session(report, session, detail == Detail::Commits))
+                .collect()
+        }),
+    }
+}
+
+pub(crate) fn json_session<'a>(report: &ClientReport, session: &'a Session, with_commits: bool) -> JsonSession<'a> {
+    JsonSession {
+        start: session.start.to_rfc3339(),
+        end: session.end.to_rfc3339(),
+        duration_minutes: session.duration_minutes,
+        components: JsonComponents {
+            base_minutes: session.parts.base,
+            gap_minutes: session.parts.gap,
+            volume_minutes: session.parts.volume,
+            manual_minutes: session.parts.manual,
+        },
+        repos: &session.repos,
+        total_commits: session.commits.len(),
+        lines_added: session.lines_added,
+        lines_deleted: session.lines_deleted,
+        note: session.note.as_deref(),
+        commits: with_commits.then(|| session.commits.iter().map(|c| json_commit(report, c)).collect()),
+    }
+}
+
+pub(crate) fn json_commit<'a>(report: &ClientReport, commit: &'a Commit) -> JsonCommit<'a> {
+    JsonCommit {
+        hash: &commit.hash,
+        time: commit.author_date.to_rfc3339(),
+        subject: &commit.subject,
+        repo: &commit.repo_name,
+        lines_added: commit.lines_added,
+        lines_deleted: commit.lines_deleted,
+        url: report.commit_url(commit),
+    }
+}
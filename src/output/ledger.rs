@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use anyhow::{bail, Result};
+use chrono::{Duration, NaiveDate};
+use rust_decimal::Decimal;
+use crate::config::{account_component, AccountsConfig};
+use crate::i18n::{format_date, tf};
+use crate::session::invoice::round_money;
+use crate::session::llm::usage_date;
+use crate::session::types::ClientReport;
+
+/// Plain-text accounting syntax to write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerFlavor {
+    /// ledger-cli / hledger journal
+    Ledger,
+    Beancount,
+}
+
+/// How receivable postings are grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Postings {
+    /// One transaction per day with billable time, LLM cost or expenses
+    Day,
+    /// One transaction for the whole period, dated on its last day, with the
+    /// invoice's discounts and taxes
+    Period,
+}
+
+struct Transaction {
+    date: NaiveDate,
+    description: String,
+    /// Credits and debits other than the receivable, which balances them
+    postings: Vec<(String, Decimal)>,
+}
+
+/// Clock-in/clock-out pairs per session (hledger timeclock format). Each pair
+/// spans the session's duration, ending at its last commit.
+pub fn serialize_timeclock(report: &ClientReport, accounts: &AccountsConfig) -> String {
+    let account = accounts.timeclock(&report.client_name);
+    let mut out = String::new();
+    for session in report.days.iter().flat_map(|d| &d.sessions) {
+        let start = session.end - Duration::minutes(session.duration_minutes as i64);
+        let _ = writeln!(
+            out,
+            "i {} {}  {}: {}",
+            start.format("%Y/%m/%d %H:%M:%S"),
+            account,
+            session.repos.join(", "),
+            session.description()
+        );
+        let _ = writeln!(out, "o {}", session.end.format("%Y/%m/%d %H:%M:%S"));
+    }
+    out
+}
+
+/// Receivable transactions from the report amounts: billable overage, LLM cost
+/// and expenses credited to income accounts, balanced by the receivable account.
+/// Beancount journals open every account they use before the first transaction.
+///
+/// Discounts and taxes apply to the period's subtotal, so `Postings::Day` is
+/// refused when the invoice has any: the receivable would not match it.
+pub fn serialize_ledger(
+    report: &ClientReport,
+    accounts: &AccountsConfig,
+    flavor: LedgerFlavor,
+    postings: Postings,
+) -> Result<String> {
+    let transactions = match postings {
+        Postings::Period => vec![period_transaction(report, accounts)],
+        Postings::Day if report.invoice().has_adjustments() => {
+            bail!(tf("ledger.day_adjustments", &[("client", &report.client_name)]))
+        }
+        Postings::Day => day_transactions(report, accounts),
+    };
+    let receivable = accounts.receivable(&report.client_name);
+    let transactions: Vec<Transaction> = transactions
+        .into_iter()
+        .map(|tx| Transaction {
+            postings: tx.postings.into_iter().filter(|(_, a)| !a.is_zero()).collect(),
+            ..tx
+        })
+        .filter(|tx| !tx.postings.is_empty())
+        .collect();
+
+    let mut out = String::new();
+    if let (LedgerFlavor::Beancount, Some(first)) = (flavor, transactions.iter().map(|tx| tx.date).min()) {
+        let mut opened: Vec<&str> = vec![&receivable];
+        for (account, _) in transactions.iter().flat_map(|tx| &tx.postings) {
+            if !opened.contains(&account.as_str()) {
+                opened.push(account);
+            }
+        }
+        for account in opened {
+            let _ = writeln!(out, "{} open {} {}", first.format("%Y-%m-%d"), account, report.currency);
+        }
+        let _ = writeln!(out);
+    }
+    for tx in transactions {
+        let postings = tx.postings;
+        let owed: Decimal = -postings.iter().map(|(_, a)| *a).sum::<Decimal>();
+        match flavor {
+            LedgerFlavor::Ledger => {
+                let _ = writeln!(out, "{} * {} | {}", tx.date.format("%Y-%m-%d"), report.client_name, tx.description);
+            }
+            LedgerFlavor::Beancount => {
+                let _ = writeln!(
+                    out,
+                    "{} * \"{}\" \"{}\"",
+                    tx.date.format("%Y-%m-%d"),
+                    quote(&report.client_name),
+                    quote(&tx.description)
+                );
+            }
+        }
+        let indent = if flavor == LedgerFlavor::Beancount { "  " } else { "    " };
+        for (account, amount) in std::iter::once((receivable.clone(), owed)).chain(postings) {
+            let _ = writeln!(out, "{}{:<48} {:>12.2} {}", indent, account, amount, report.currency);
+        }
+        let _ = writeln!(out);
+    }
+    Ok(out)
+}
+
+fn period_transaction(report: &ClientReport, accounts: &AccountsConfig) -> Transaction {
+    let client = &report.client_name;
+    let invoice = report.invoice();
+    let llm = round_money(report.llm_cost);
+    let expenses = round_money(report.expenses_total());
+    // The invoice rounds the subtotal once; income takes the rounding remainder
+    // so the postings add up to it
+    let mut postings = vec![
+        (accounts.income(client), -(invoice.subtotal - llm - expenses)),
+        (accounts.llm(client), -llm),
+        (accounts.expenses(client), -expenses),
+    ];
+    for line in &invoice.discounts {
+        postings.push((accounts.discounts(client), -line.amount));
+    }
+    for line in &invoice.taxes {
+        postings.push((format!("{}:{}", accounts.taxes(), account_component(&line.name)), -line.amount));
+    }
+    Transaction {
+        date: report.period_end,
+        description: tf(
+            "ledger.period",
+            &[
+                ("since", &format_date(report.period_start)),
+                ("until", &format_date(report.period_end)),
+            ],
+        ),
+        postings,
+    }
+}
+
+fn day_transactions(report: &ClientReport, accounts: &AccountsConfig) -> Vec<Transaction> {
+    let client = &report.client_name;
+    let mut by_date: BTreeMap<NaiveDate, Vec<(String, Decimal)>> = BTreeMap::new();
+    for day in &report.days {
+        by_date
+            .entry(day.date)
+            .or_default()
+            .push((accounts.income(client), -round_money(day.amount() - day.covered_amount())));
+        if !report.has_llm_usage() {
+            by_date
+                .entry(day.date)
+                .or_default()
+                .push((accounts.llm(client), -round_money(report.day_llm_cost(day))));
+        }
+    }
+    // Imported usage is posted on its own date, including days without commits
+    let mut usage: BTreeMap<NaiveDate, Decimal> = BTreeMap::new();
+    for entry in &report.llm_usage {
+        *usage.entry(usage_date(entry)).or_default() += entry.cost;
+    }
+    for (date, cost) in usage {
+        by_date.entry(date).or_default().push((accounts.llm(client), -round_money(cost)));
+    }
+    for expense in &report.expenses {
+        by_date
+            .entry(expense.date)
+            .or_default()
+            .push((accounts.expenses(client), -round_money(expense.amount)));
+    }
+
+    by_date
+        .into_iter()
+        .map(|(date, postings)| Transaction {
+            date,
+            description: tf("ledger.day", &[("date", &format_date(date))]),
+            postings,
+        })
+        .collect()
+}
+
+fn quote(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
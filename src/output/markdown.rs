@@ -0,0 +1,324 @@
+use std::fmt::Write;
+use crate::i18n::{
+    day_label, format_amount, format_date, format_money, format_number, format_signed_money, format_time, t, tf,
+};
+use crate::session::billing::DEFAULT_CATEGORY;
+use crate::session::types::ClientReport;
+use super::table::{day_sessions, format_duration};
+
+/// Render the report as a Markdown document for emails and wikis: header,
+/// per-day table, totals and amount lines, optionally followed by every session
+/// with its commits (linked when the repo has a commit URL template).
+pub fn serialize_markdown(report: &ClientReport, sessions: bool) -> String {
+    let mut md = String::new();
+    let currency = &report.currency;
+
+    let _ = writeln!(md, "# {}", tf("report.title", &[("client", &escape(&report.client_name))]));
+    let _ = writeln!(md);
+    let _ = writeln!(
+        md,
+        "**{}:** {} — {}",
+        t("report.period_label"),
+        format_date(report.period_start),
+        format_date(report.period_end)
+    );
+    let _ = writeln!(md);
+
+    let show_llm = report.shows_llm_cost();
+    let show_billed = report.days.iter().any(|d| d.billed_minutes != d.total_minutes);
+    let show_overflow = report.overflow_minutes > 0;
+
+    if !report.days.is_empty() {
+        let mut header = vec![t("col.date"), t("col.sessions"), t("col.hours")];
+        let mut align = vec![":--", "--:", "--:"];
+        if show_billed {
+            header.push(t("col.billed"));
+            align.push("--:");
+        }
+        if show_overflow {
+            header.push(t("col.overflow"));
+            align.push("--:");
+        }
+        header.extend([t("col.commits"), "+/-"]);
+        align.extend(["--:", "--:"]);
+        if show_llm {
+            header.push("LLM");
+            align.push("--:");
+        }
+        header.push(t("col.repos"));
+        align.push(":--");
+        let _ = writeln!(md, "| {} |", header.join(" | "));
+        let _ = writeln!(md, "|{}|", align.join("|"));
+
+        for day in &report.days {
+            let mut row = vec![
+                day_label(day.date),
+                day.sessions.len().to_string(),
+                format_duration(day.total_minutes),
+            ];
+            if show_billed {
+                row.push(format_duration(day.billed_minutes));
+            }
+            if show_overflow {
+                row.push(if day.overflow_minutes > 0 { format_duration(day.overflow_minutes) } else { String::new() });
+            }
+            row.push(day.total_commits.to_string());
+            row.push(format!("+{} -{}", day.total_lines_added, day.total_lines_deleted));
+            if show_llm {
+                row.push(format_money(report.day_llm_cost(day)));
+            }
+            row.push(escape(&day.repos.join(", ")));
+            let _ = writeln!(md, "| {} |", row.join(" | "));
+        }
+
+        let total_sessions: usize = report.days.iter().map(|d| d.sessions.len()).sum();
+        let mut total = vec![
+            format!("**{}**", t("common.total")),
+            format!("**{}**", total_sessions),
+            format!("**{}**", format_duration(report.total_minutes)),
+        ];
+        if show_billed {
+            total.push(format!("**{}**", format_duration(report.billed_minutes)));
+        }
+        if show_overflow {
+            total.push(format!("**{}**", format_duration(report.overflow_minutes)));
+        }
+        total.push(format!("**{}**", report.total_commits));
+        total.push(format!("**+{} -{}**", report.total_lines_added, report.total_lines_deleted));
+        if show_llm {
+            total.push(format!("**{}**", format_money(report.llm_cost)));
+        }
+        total.push(String::new());
+        let _ = writeln!(md, "| {} |", total.join(" | "));
+        let _ = writeln!(md);
+    }
+
+    if show_billed {
+        let _ = writeln!(
+            md,
+            "{}  ",
+            tf(
+                "report.logged_billed",
+                &[
+                    ("logged", &format_duration(report.total_minutes)),
+                    ("billed", &format_duration(report.billed_minutes)),
+                ]
+            )
+        );
+    }
+    if show_overflow {
+        let _ = writeln!(
+            md,
+            "{}  ",
+            tf("report.overflow", &[("duration", &format_duration(report.overflow_minutes))])
+        );
+    }
+    if show_billed || show_overflow {
+        let _ = writeln!(md);
+    }
+
+    if !report.expenses.is_empty() {
+        let _ = writeln!(md, "## {}", t("report.expenses"));
+        let _ = writeln!(md);
+        for e in &report.expenses {
+            let repo = e.repo.as_ref().map_or(String::new(), |r| format!(" ({})", escape(r)));
+            let _ = writeln!(
+                md,
+                "- {} — {}{}: {} {}",
+                format_date(e.date),
+                escape(&e.description),
+                repo,
+                format_money(e.amount),
+                currency
+            );
+        }
+        let _ = writeln!(md);
+    }
+
+    if report.has_rate() {
+        write_amounts(&mut md, report, show_llm);
+    } else {
+        let _ = writeln!(
+            md,
+            "**{}:** {} ({})",
+            t("common.total"),
+            format_duration(report.total_minutes),
+            t("report.no_rate")
+        );
+        let _ = writeln!(md);
+    }
+
+    if sessions {
+        write_sessions(&mut md, report);
+    }
+    md
+}
+
+/// Rate subtotals, amount line, retainer coverage, invoice lines and conversion
+fn write_amounts(md: &mut String, report: &ClientReport, show_llm: bool) {
+    let currency = &report.currency;
+    let _ = writeln!(md, "## {}", t("report.amount"));
+    let _ = writeln!(md);
+
+    let subtotals = report.rate_subtotals();
+    let hours_part = if subtotals.len() > 1 {
+        let show_category = subtotals.iter().any(|s| s.category != DEFAULT_CATEGORY);
+        for sub in &subtotals {
+            let label = if show_category { format!("{}: ", escape(&sub.category)) } else { String::new() };
+            let _ = writeln!(
+                md,
+                "- {}{}h × {}/h = {} {}",
+                label,
+                format_number(sub.billed_hours(), 2),
+                format_amount(sub.hourly_rate),
+                format_money(sub.amount),
+                currency
+            );
+        }
+        let _ = writeln!(md);
+        format!("{} {}", format_money(report.billable_amount()), currency)
+    } else {
+        let rate = subtotals.first().map_or(report.hourly_rate, |s| s.hourly_rate);
+        format!(
+            "{}h × {}/h = {} {}",
+            format_number(report.billed_hours(), 2),
+            format_amount(rate),
+            format_money(report.billable_amount()),
+            currency
+        )
+    };
+
+    let covered = report.covered_minutes > 0;
+    let invoice = report.invoice();
+    let total_label = if invoice.has_adjustments() { t("common.subtotal") } else { t("common.total") };
+    let mut extras = String::new();
+    if show_llm {
+        let label = if report.has_llm_usage() { t("report.llm_actual") } else { "LLM" };
+        extras += &format!(" + {}: {} {}", label, format_money(report.llm_cost), currency);
+    }
+    if !report.expenses.is_empty() {
+        extras += &format!(" + {}: {} {}", t("report.expenses"), format_money(report.expenses_total()), currency);
+    }
+
+    if covered {
+        let _ = writeln!(md, "**{}:** {}  ", t("report.amount"), hours_part);
+        let _ = writeln!(
+            md,
+            "{}  ",
+            tf(
+                "report.covered",
+                &[
+                    ("duration", &format_duration(report.covered_minutes)),
+                    ("amount", &format!("{} {}", format_money(report.covered_amount()), currency)),
+                ]
+            )
+        );
+        let _ = write!(
+            md,
+            "**{}:** {} = {} {}",
+            t("report.overage_label"),
+            format_duration(report.overage_minutes()),
+            format_money(report.overage_amount()),
+            currency
+        );
+    } else {
+        let _ = write!(md, "**{}:** {}", t("report.amount"), hours_part);
+    }
+    if !extras.is_empty() {
+        let _ = write!(md, "{} = **{}: {} {}**", extras, total_label, format_money(invoice.subtotal), currency);
+    }
+    let _ = writeln!(md);
+    let _ = writeln!(md);
+
+    if invoice.has_adjustments() {
+        let _ = writeln!(md, "| {} | {} |", t("report.concept"), t("report.amount"));
+        let _ = writeln!(md, "|:--|--:|");
+        let _ = writeln!(md, "| {} | {} {} |", t("common.subtotal"), format_money(invoice.subtotal), currency);
+        for line in invoice.discounts.iter().chain(&invoice.taxes) {
+            let label = match line.percent {
+                Some(percent) => format!("{} ({}%)", escape(&line.name), format_amount(percent)),
+                None => escape(&line.name),
+            };
+            let _ = writeln!(md, "| {} | {} {} |", label, format_signed_money(line.amount), currency);
+        }
+        let _ = writeln!(md, "| **{}** | **{} {}** |", t("report.total_due"), format_money(invoice.total()), currency);
+        let _ = writeln!(md);
+    }
+
+    if let Some(ref conv) = report.converted {
+        let rate = match conv.single_rate() {
+            Some(rate) => tf("report.rate", &[("rate", &format_amount(rate.round_dp(4)))]),
+            None => t("report.rate_daily").to_string(),
+        };
+        let _ = writeln!(
+            md,
+            "**{} ({}):** {} {} (original: {} {})",
+            tf("report.in_currency", &[("currency", &conv.currency)]),
+            rate,
+            format_money(conv.total_amount),
+            conv.currency,
+            format_money(invoice.total()),
+            currency
+        );
+        let _ = writeln!(md);
+    }
+}
+
+/// Every session of the period with its commits, as in `gtt verify`
+fn write_sessions(md: &mut String, report: &ClientReport) {
+    let _ = writeln!(md, "## {}", t("col.sessions"));
+    let _ = writeln!(md);
+    for day in &report.days {
+        let _ = writeln!(md, "### {}", day_sessions(day));
+        let _ = writeln!(md);
+        for (i, session) in day.sessions.iter().enumerate() {
+            let _ = writeln!(
+                md,
+                "- **{}:** {} → {} ({}, {} commits, +{} -{})",
+                tf("verify.session", &[("number", &(i + 1))]),
+                format_time(session.start),
+                format_time(session.end),
+                format_duration(session.duration_minutes),
+                session.commits.len(),
+                session.lines_added,
+                session.lines_deleted
+            );
+            if let Some(ref note) = session.note {
+                let _ = writeln!(md, "  - _manual:_ {}", escape(note));
+            }
+            for commit in &session.commits {
+                let short = &commit.hash[..commit.hash.len().min(7)];
+                let hash = match report.commit_url(commit) {
+                    Some(url) => format!("[`{}`]({})", short, url),
+                    None => format!("`{}`", short),
+                };
+                let volume = if commit.lines_added + commit.lines_deleted > 0 {
+                    format!(" (+{} -{})", commit.lines_added, commit.lines_deleted)
+                } else {
+                    String::new()
+                };
+                let _ = writeln!(
+                    md,
+                    "  - {} {} {}{}",
+                    format_time(commit.author_date),
+                    hash,
+                    escape(&commit.subject),
+                    volume
+                );
+            }
+        }
+        let _ = writeln!(md);
+    }
+}
+
+/// Escape characters that Markdown would otherwise interpret (including table pipes)
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -1,6 +1,29 @@
 pub mod chart;
 pub mod csv;
+pub mod html;
+pub mod ics;
 pub mod json_fmt;
+pub mod ledger;
+pub mod markdown;
+pub mod ndjson;
+pub mod ods;
+pub mod org;
+pub mod spreadsheet;
 pub mod table;
+pub mod time_entries;
+pub mod timewarrior;
+pub mod xlsx;
 
-pub use table::{format_duration, print_client_report, print_verify_report};
+pub use table::{
+    format_duration, print_budget_status, print_client_report, print_grand_total, print_receivables,
+    print_retainer_balance, print_verify_report,
+};
+
+/// Extra detail in JSON and CSV exports, below the per-day aggregates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detail {
+    /// Every session with its duration components
+    Sessions,
+    /// Every session and its commits
+    Commits,
+}
@@ -0,0 +1,71 @@
+use std::io::Write;
+use anyhow::Result;
+use serde::Serialize;
+use crate::session::types::ClientReport;
+use super::json_fmt::{json_commit, json_day, json_session};
+use super::Detail;
+
+/// One NDJSON line: the record kind and client, then the record's own fields
+/// (the same as in the JSON report)
+#[derive(Serialize)]
+struct Record<'a, T: Serialize> {
+    record: &'static str,
+    client: &'a str,
+    currency: &'a str,
+    /// Day of a session or commit (day records carry their own)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<&'a str>,
+    /// Start of the session a commit was counted in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_start: Option<&'a str>,
+    #[serde(flatten)]
+    data: T,
+}
+
+/// Write one JSON object per line for each day of the report, or each session
+/// or commit with `detail`. Every line names its client, so the output of
+/// several clients can be concatenated and still parsed line by line. The
+/// report is already built in memory: streaming is per client, not per record.
+pub fn write_ndjson<W: Write>(out: &mut W, report: &ClientReport, detail: Option<Detail>) -> Result<()> {
+    for day in &report.days {
+        let date = day.date.format("%Y-%m-%d").to_string();
+        match detail {
+            None => write_line(out, "day", report, None, None, json_day(report, day, None))?,
+            Some(Detail::Sessions) => {
+                for session in &day.sessions {
+                    write_line(out, "session", report, Some(&date), None, json_session(report, session, false))?;
+                }
+            }
+            Some(Detail::Commits) => {
+                for session in &day.sessions {
+                    let start = session.start.to_rfc3339();
+                    for commit in &session.commits {
+                        write_line(out, "commit", report, Some(&date), Some(&start), json_commit(report, commit))?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_line<W: Write, T: Serialize>(
+    out: &mut W,
+    record: &'static str,
+    report: &ClientReport,
+    date: Option<&str>,
+    session_start: Option<&str>,
+    data: T,
+) -> Result<()> {
+    let line = Record {
+        record,
+        client: &report.client_name,
+        currency: &report.currency,
+        date,
+        session_start,
+        data,
+    };
+    serde_json::to_writer(&mut *out, &line)?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use std::fmt::Write as _;
+use std::io::{Cursor, Write};
+use anyhow::Result;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+use crate::i18n::{format_date, format_time};
+use super::spreadsheet::{date_parts, Cell, DatePart, Style, Value, Workbook};
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+ <manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+ <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#;
+
+/// Number and cell styles for every `Style`; the date styles come from
+/// `date_parts`
+const STYLES: &str = r#"<office:automatic-styles>
+<number:number-style style:name="N_int"><number:number number:decimal-places="0" number:min-integer-digits="1"/></number:number-style>
+<number:number-style style:name="N_money"><number:number number:decimal-places="2" number:min-decimal-places="2" number:min-integer-digits="1" number:grouping="true"/></number:number-style>
+<number:time-style style:name="N_duration" number:truncate-on-overflow="false"><number:hours/><number:text>:</number:text><number:minutes number:style="long"/></number:time-style>
+<style:style style:name="ce_header" style:family="table-cell"><style:text-properties fo:font-weight="bold"/></style:style>
+<style:style style:name="ce_int" style:family="table-cell" style:data-style-name="N_int"/>
+<style:style style:name="ce_money" style:family="table-cell" style:data-style-name="N_money"/>
+<style:style style:name="ce_date" style:family="table-cell" style:data-style-name="N_date"/>
+<style:style style:name="ce_datetime" style:family="table-cell" style:data-style-name="N_datetime"/>
+<style:style style:name="ce_duration" style:family="table-cell" style:data-style-name="N_duration"/>
+"#;
+
+/// Write the workbook as an OpenDocument spreadsheet
+pub fn write_ods(book: &Workbook) -> Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    // The mimetype must be the first entry, uncompressed
+    zip.start_file("mimetype", SimpleFileOptions::default().compression_method(CompressionMethod::Stored))?;
+    zip.write_all(MIMETYPE.as_bytes())?;
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file("META-INF/manifest.xml", deflated)?;
+    zip.write_all(MANIFEST.as_bytes())?;
+    zip.start_file("content.xml", deflated)?;
+    zip.write_all(content_xml(book).as_bytes())?;
+    Ok(zip.finish()?.into_inner())
+}
+
+fn content_xml(book: &Workbook) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" "#,
+        r#"xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" "#,
+        r#"xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" "#,
+        r#"xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" "#,
+        r#"xmlns:number="urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0" "#,
+        r#"xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" "#,
+        r#"xmlns:of="urn:oasis:names:tc:opendocument:xmlns:of:1.2" office:version="1.2">"#,
+        "\n",
+    ));
+    xml += STYLES;
+    let _ = writeln!(xml, r#"<number:date-style style:name="N_date">{}</number:date-style>"#, date_style(&date_parts(false)));
+    let _ = writeln!(xml, r#"<number:date-style style:name="N_datetime">{}</number:date-style>"#, date_style(&date_parts(true)));
+    for (s, sheet) in book.sheets.iter().enumerate() {
+        for (c, width) in sheet.widths.iter().enumerate() {
+            // About 0.2cm per character
+            let _ = writeln!(
+                xml,
+                r#"<style:style style:name="co{s}_{c}" style:family="table-column"><style:table-column-properties style:column-width="{:.2}cm"/></style:style>"#,
+                width * 0.2
+            );
+        }
+    }
+    xml += "</office:automatic-styles>\n<office:body>\n<office:spreadsheet>\n";
+
+    for (s, sheet) in book.sheets.iter().enumerate() {
+        let _ = writeln!(xml, r#"<table:table table:name="{}">"#, escape(&sheet.name));
+        for c in 0..sheet.widths.len() {
+            let _ = writeln!(xml, r#"<table:table-column table:style-name="co{s}_{c}"/>"#);
+        }
+        for row in &sheet.rows {
+            xml += "<table:table-row>";
+            for cell in row {
+                xml += &cell_xml(cell);
+            }
+            xml += "</table:table-row>\n";
+        }
+        xml += "</table:table>\n";
+    }
+    xml += "</office:spreadsheet>\n</office:body>\n</office:document-content>\n";
+    xml
+}
+
+/// Elements of a `number:date-style` for date parts
+fn date_style(parts: &[DatePart]) -> String {
+    let long = |long: bool| if long { r#" number:style="long""# } else { "" };
+    parts
+        .iter()
+        .map(|part| match part {
+            DatePart::Day { padded } => format!("<number:day{}/>", long(*padded)),
+            DatePart::Month { padded } => format!("<number:month{}/>", long(*padded)),
+            DatePart::MonthName { long: l } => format!(r#"<number:month number:textual="true"{}/>"#, long(*l)),
+            DatePart::Year { long: l } => format!("<number:year{}/>", long(*l)),
+            DatePart::Weekday { long: l } => format!("<number:day-of-week{}/>", long(*l)),
+            DatePart::Hours { padded } => format!("<number:hours{}/>", long(*padded)),
+            DatePart::Minutes => r#"<number:minutes number:style="long"/>"#.to_string(),
+            DatePart::AmPm => "<number:am-pm/>".to_string(),
+            DatePart::Text(text) => format!("<number:text>{}</number:text>", escape(text)),
+        })
+        .collect()
+}
+
+fn style_name(style: Style) -> Option<&'static str> {
+    match style {
+        Style::Default => None,
+        Style::Header => Some("ce_header"),
+        Style::Integer => Some("ce_int"),
+        Style::Money => Some("ce_money"),
+        Style::Date => Some("ce_date"),
+        Style::DateTime => Some("ce_datetime"),
+        Style::Duration => Some("ce_duration"),
+    }
+}
+
+/// ISO 8601 duration of a number of minutes (`PT26H05M00S`)
+fn time_value(minutes: u32) -> String {
+    format!("PT{}H{:02}M00S", minutes / 60, minutes % 60)
+}
+
+fn cell_xml(cell: &Cell) -> String {
+    let style = style_name(cell.style).map_or(String::new(), |s| format!(r#" table:style-name="{}""#, s));
+    let (attrs, text) = match cell.value {
+        Value::Empty => return "<table:table-cell/>".to_string(),
+        Value::Text(ref text) => (r#"office:value-type="string""#.to_string(), text.clone()),
+        Value::Number(n) => (format!(r#"office:value-type="float" office:value="{}""#, n), number_text(n, cell.style)),
+        Value::Date(date) => (
+            format!(r#"office:value-type="date" office:date-value="{}""#, date.format("%Y-%m-%d")),
+            format_date(date),
+        ),
+        Value::DateTime(dt) => (
+            format!(r#"office:value-type="date" office:date-value="{}""#, dt.format("%Y-%m-%dT%H:%M:%S")),
+            format!("{} {}", format_date(dt.date()), format_time(dt.time())),
+        ),
+        Value::Duration(minutes) => (
+            format!(r#"office:value-type="time" office:time-value="{}""#, time_value(minutes)),
+            format!("{}:{:02}", minutes / 60, minutes % 60),
+        ),
+        Value::Formula(ref formula, result) => {
+            let formula = format!(r#"table:formula="of:={}""#, escape(&openformula(formula)));
+            if cell.style == Style::Duration {
+                let minutes = (result * 1440.0).round().max(0.0) as u32;
+                (
+                    format!(r#"{} office:value-type="time" office:time-value="{}""#, formula, time_value(minutes)),
+                    format!("{}:{:02}", minutes / 60, minutes % 60),
+                )
+            } else {
+                (
+                    format!(r#"{} office:value-type="float" office:value="{}""#, formula, result),
+                    number_text(result, cell.style),
+                )
+            }
+        }
+    };
+    format!(r#"<table:table-cell{} {}><text:p>{}</text:p></table:table-cell>"#, style, attrs, escape(&text))
+}
+
+fn number_text(n: f64, style: Style) -> String {
+    match style {
+        Style::Money => format!("{:.2}", n),
+        Style::Integer => format!("{:.0}", n),
+        _ => n.to_string(),
+    }
+}
+
+/// Translate an A1 formula to OpenFormula: references become `[.A1]`,
+/// `[.A1:.B2]` or `[$'Sheet'.A1]` and arguments are separated by `;`.
+pub fn openformula(formula: &str) -> String {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\'' {
+            // 'Sheet'!ref
+            let mut name = String::new();
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == '\'' && chars.get(i + 1) == Some(&'\'') {
+                    name.push('\'');
+                    i += 2;
+                } else if chars[i] == '\'' {
+                    i += 1;
+                    break;
+                } else {
+                    name.push(chars[i]);
+                    i += 1;
+                }
+            }
+            if chars.get(i) == Some(&'!') {
+                i += 1;
+            }
+            let (reference, next) = reference_at(&chars, i).unwrap_or_default();
+            i = next.max(i);
+            let _ = write!(out, "[$'{}'.{}]", name.replace('\'', "''"), reference);
+        } else if let Some((reference, next)) = reference_at(&chars, i) {
+            let _ = write!(out, "[.{}]", reference);
+            i = next;
+        } else if c.is_ascii_alphabetic() {
+            // Function name
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                out.push(chars[i]);
+                i += 1;
+            }
+        } else {
+            out.push(if c == ',' { ';' } else { c });
+            i += 1;
+        }
+    }
+    out
+}
+
+/// A cell reference or range starting at `start` (`A1`, `$B$5`, `C2:C9`),
+/// formatted for OpenFormula without brackets, and the index after it
+fn reference_at(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let (first, next) = cell_at(chars, start)?;
+    if chars.get(next) == Some(&':') {
+        if let Some((second, end)) = cell_at(chars, next + 1) {
+            return Some((format!("{}:.{}", first, second), end));
+        }
+    }
+    Some((first, next))
+}
+
+fn cell_at(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let mut i = start;
+    let mut cell = String::new();
+    if chars.get(i) == Some(&'$') {
+        cell.push('$');
+        i += 1;
+    }
+    let letters = i;
+    while i < chars.len() && chars[i].is_ascii_uppercase() {
+        cell.push(chars[i]);
+        i += 1;
+    }
+    if i == letters {
+        return None;
+    }
+    if chars.get(i) == Some(&'$') {
+        cell.push('$');
+        i += 1;
+    }
+    let digits = i;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        cell.push(chars[i]);
+        i += 1;
+    }
+    // Function names like LOG10( are not references
+    if i == digits || chars.get(i).is_some_and(|c| c.is_ascii_alphanumeric() || *c == '(') {
+        return None;
+    }
+    Some((cell, i))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,31 @@
+use std::fmt::Write;
+use chrono::Duration;
+use crate::import::GTT_TAG;
+use crate::session::types::ClientReport;
+
+/// Sessions as an org-mode outline: one heading per day and one per session
+/// with a LOGBOOK clock line. The client heading is tagged `:gtt:` so importing
+/// the file back into gtt skips it.
+pub fn serialize_org(report: &ClientReport) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "* {} :{}:", report.client_name, GTT_TAG);
+    for day in report.days.iter().filter(|d| !d.sessions.is_empty()) {
+        let _ = writeln!(out, "** {}", day.date.format("%Y-%m-%d %a"));
+        for session in &day.sessions {
+            let start = session.end - Duration::minutes(session.duration_minutes as i64);
+            let minutes = session.duration_minutes;
+            let _ = writeln!(out, "*** {}: {}", session.repos.join(", "), session.description().replace('\n', " "));
+            let _ = writeln!(out, "    :LOGBOOK:");
+            let _ = writeln!(
+                out,
+                "    CLOCK: [{}]--[{}] => {:2}:{:02}",
+                start.format("%Y-%m-%d %a %H:%M"),
+                session.end.format("%Y-%m-%d %a %H:%M"),
+                minutes / 60,
+                minutes % 60
+            );
+            let _ = writeln!(out, "    :END:");
+        }
+    }
+    out
+}
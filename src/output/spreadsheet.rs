@@ -0,0 +1,459 @@
+use chrono::format::{Fixed, Item, Numeric, Pad, StrftimeItems};
+use chrono::{NaiveDate, NaiveDateTime};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use crate::config::TimeFormat;
+use crate::i18n::{formats, t, tf, DEFAULT_DATE_FORMAT};
+use crate::session::types::ClientReport;
+
+/// A workbook independent of the file format, written by `output::xlsx` and
+/// `output::ods`
+#[derive(Debug, Clone)]
+pub struct Workbook {
+    pub sheets: Vec<Sheet>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub name: String,
+    /// Column widths in characters
+    pub widths: Vec<f64>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub value: Value,
+    pub style: Style,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Empty,
+    Text(String),
+    Number(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    /// A duration in minutes, stored as a fraction of a day
+    Duration(u32),
+    /// Formula in A1 notation without the leading `=`, referencing other sheets
+    /// as `'Name'!A1`, with its value when written (so readers that don't
+    /// recalculate on load still show it)
+    Formula(String, f64),
+}
+
+/// Number format of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Default,
+    /// Bold column headers and row labels
+    Header,
+    Integer,
+    Money,
+    Date,
+    DateTime,
+    /// Hours and minutes (`[h]:mm`); formula values are fractions of a day
+    Duration,
+}
+
+impl Cell {
+    fn new(value: Value, style: Style) -> Self {
+        Cell { value, style }
+    }
+
+    fn empty() -> Self {
+        Cell::new(Value::Empty, Style::Default)
+    }
+
+    fn text(text: impl Into<String>) -> Self {
+        Cell::new(Value::Text(text.into()), Style::Default)
+    }
+
+    fn header(text: impl Into<String>) -> Self {
+        Cell::new(Value::Text(text.into()), Style::Header)
+    }
+
+    fn int(value: impl Into<f64>) -> Self {
+        Cell::new(Value::Number(value.into()), Style::Integer)
+    }
+
+    fn money(value: Decimal) -> Self {
+        Cell::new(Value::Number(to_f64(value)), Style::Money)
+    }
+
+    fn formula(formula: String, result: f64, style: Style) -> Self {
+        Cell::new(Value::Formula(formula, result), style)
+    }
+
+    /// The value as a number, as a formula referencing the cell would see it
+    fn number(&self) -> f64 {
+        match self.value {
+            Value::Number(n) | Value::Formula(_, n) => n,
+            Value::Duration(minutes) => minutes as f64 / 1440.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Spreadsheet column letters for a zero-based index (0 → A, 26 → AA)
+pub fn column_name(index: usize) -> String {
+    let mut name = String::new();
+    let mut n = index + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        name.insert(0, (b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    name
+}
+
+/// One piece of a spreadsheet date or time format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatePart {
+    Day { padded: bool },
+    Month { padded: bool },
+    MonthName { long: bool },
+    Year { long: bool },
+    Weekday { long: bool },
+    Hours { padded: bool },
+    Minutes,
+    AmPm,
+    Text(String),
+}
+
+/// The `date_format` setting as spreadsheet date parts, followed by the time
+/// of day in `time_format` when `with_time`. A pattern with specifiers that
+/// spreadsheets can't show uses the default date format instead.
+pub fn date_parts(with_time: bool) -> Vec<DatePart> {
+    let formats = formats();
+    let mut parts = strftime_parts(&formats.date)
+        .or_else(|| strftime_parts(DEFAULT_DATE_FORMAT))
+        .unwrap_or_default();
+    if with_time {
+        parts.push(DatePart::Text(" ".to_string()));
+        match formats.time {
+            TimeFormat::H24 => parts.extend([
+                DatePart::Hours { padded: true },
+                DatePart::Text(":".to_string()),
+                DatePart::Minutes,
+            ]),
+            TimeFormat::H12 => parts.extend([
+                DatePart::Hours { padded: false },
+                DatePart::Text(":".to_string()),
+                DatePart::Minutes,
+                DatePart::Text(" ".to_string()),
+                DatePart::AmPm,
+            ]),
+        }
+    }
+    parts
+}
+
+fn strftime_parts(pattern: &str) -> Option<Vec<DatePart>> {
+    let mut parts = Vec::new();
+    for item in StrftimeItems::new(pattern) {
+        let part = match item {
+            Item::Literal(text) | Item::Space(text) => DatePart::Text(text.to_string()),
+            Item::OwnedLiteral(ref text) | Item::OwnedSpace(ref text) => DatePart::Text(text.to_string()),
+            Item::Numeric(Numeric::Day, pad) => DatePart::Day { padded: pad == Pad::Zero },
+            Item::Numeric(Numeric::Month, pad) => DatePart::Month { padded: pad == Pad::Zero },
+            Item::Numeric(Numeric::Year, _) => DatePart::Year { long: true },
+            Item::Numeric(Numeric::YearMod100, _) => DatePart::Year { long: false },
+            Item::Fixed(Fixed::ShortMonthName) => DatePart::MonthName { long: false },
+            Item::Fixed(Fixed::LongMonthName) => DatePart::MonthName { long: true },
+            Item::Fixed(Fixed::ShortWeekdayName) => DatePart::Weekday { long: false },
+            Item::Fixed(Fixed::LongWeekdayName) => DatePart::Weekday { long: true },
+            _ => return None,
+        };
+        // Keep neighbouring text together
+        match (parts.last_mut(), part) {
+            (Some(DatePart::Text(previous)), DatePart::Text(text)) => previous.push_str(&text),
+            (_, part) => parts.push(part),
+        }
+    }
+    Some(parts)
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// Sheet names: at most 31 characters, without `[]:*?/\`
+fn sheet_base(client: &str) -> String {
+    let clean: String = client
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .collect();
+    let clean = clean.trim().trim_matches('\'');
+    let base: String = clean.chars().take(18).collect();
+    if base.is_empty() { t("col.client").to_string() } else { base }
+}
+
+fn quote(sheet: &str) -> String {
+    format!("'{}'", sheet.replace('\'', "''"))
+}
+
+/// Three sheets per client: a summary with the invoice computed by formulas
+/// from the editable hourly-rate cell, one row per day and one per session.
+pub fn build_workbook(reports: &[ClientReport]) -> Workbook {
+    let mut sheets = Vec::new();
+    let mut used: Vec<String> = Vec::new();
+    for report in reports {
+        let mut base = sheet_base(&report.client_name);
+        let mut n = 2;
+        while used.contains(&base) {
+            base = format!("{} {}", sheet_base(&report.client_name), n);
+            n += 1;
+        }
+        used.push(base.clone());
+        let days_name = tf("sheet.days", &[("name", &base)]);
+        let (days, day_totals) = day_sheet(report, &days_name, &base);
+        sheets.push(summary_sheet(report, &base, &days_name, day_totals));
+        sheets.push(days);
+        sheets.push(session_sheet(report, &tf("sheet.sessions", &[("name", &base)])));
+    }
+    Workbook { sheets }
+}
+
+/// Row of the editable hourly rate on the summary sheet (1-based)
+const RATE_ROW: usize = 5;
+
+/// Totals row of the per-day sheet (1-based) and its values, for the summary
+struct DayTotals {
+    row: usize,
+    detected: f64,
+    billed: f64,
+    covered: f64,
+    amount: f64,
+}
+
+fn day_sheet(report: &ClientReport, name: &str, summary: &str) -> (Sheet, DayTotals) {
+    let mut rows = vec![[
+        "col.date",
+        "col.sessions",
+        "col.hours",
+        "sheet.billed_minutes",
+        "sheet.covered_minutes",
+        "report.rate_label",
+        "sheet.amount",
+        "sheet.llm",
+        "common.total",
+        "col.commits",
+        "col.repos",
+    ]
+    .into_iter()
+    .map(|key| Cell::header(t(key)))
+    .collect::<Vec<_>>()];
+
+    for (i, day) in report.days.iter().enumerate() {
+        let r = i + 2;
+        // Days billed entirely at the report's rate follow the summary's rate
+        // cell; days under another rate (history, rules) keep their average rate.
+        let uniform = day.categories.iter().all(|c| c.hourly_rate == report.hourly_rate)
+            && day.hourly_rate == report.hourly_rate;
+        let rate = if uniform {
+            Cell::formula(format!("{}!$B${}", quote(summary), RATE_ROW), to_f64(report.hourly_rate), Style::Money)
+        } else if day.billed_minutes > 0 {
+            Cell::new(Value::Number(to_f64(day.amount()) * 60.0 / day.billed_minutes as f64), Style::Money)
+        } else {
+            Cell::money(day.hourly_rate)
+        };
+        let billable = day.billed_minutes.saturating_sub(day.covered_minutes) as f64;
+        let amount = billable / 60.0 * rate.number();
+        let llm = to_f64(report.day_llm_cost(day));
+        rows.push(vec![
+            Cell::new(Value::Date(day.date), Style::Date),
+            Cell::int(day.sessions.len() as u32),
+            Cell::new(Value::Duration(day.total_minutes), Style::Duration),
+            Cell::int(day.billed_minutes),
+            Cell::int(day.covered_minutes),
+            rate,
+            Cell::formula(format!("(D{r}-E{r})/60*F{r}"), amount, Style::Money),
+            Cell::new(Value::Number(llm), Style::Money),
+            Cell::formula(format!("G{r}+H{r}"), amount + llm, Style::Money),
+            Cell::int(day.total_commits as u32),
+            Cell::text(day.repos.join(", ")),
+        ]);
+    }
+
+    let totals = totals_row(&rows, &[1, 2, 3, 4, 6, 7, 8, 9]);
+    let day_totals = DayTotals {
+        row: rows.len() + 1,
+        detected: totals[2].number(),
+        billed: totals[3].number(),
+        covered: totals[4].number(),
+        amount: totals[6].number(),
+    };
+    rows.push(totals);
+    let sheet = Sheet {
+        name: name.to_string(),
+        widths: vec![12.0, 10.0, 9.0, 15.0, 15.0, 10.0, 12.0, 10.0, 12.0, 9.0, 30.0],
+        rows,
+    };
+    (sheet, day_totals)
+}
+
+fn session_sheet(report: &ClientReport, name: &str) -> Sheet {
+    let mut rows = vec![[
+        "col.date",
+        "sheet.start",
+        "sheet.end",
+        "col.duration",
+        "sheet.base",
+        "sheet.intervals",
+        "sheet.volume",
+        "sheet.manual",
+        "col.commits",
+        "col.repos",
+        "col.description",
+    ]
+    .into_iter()
+    .map(|key| Cell::header(t(key)))
+    .collect::<Vec<_>>()];
+
+    for session in report.days.iter().flat_map(|d| &d.sessions) {
+        rows.push(vec![
+            Cell::new(Value::Date(session.date()), Style::Date),
+            Cell::new(Value::DateTime(session.start.naive_local()), Style::DateTime),
+            Cell::new(Value::DateTime(session.end.naive_local()), Style::DateTime),
+            Cell::new(Value::Duration(session.duration_minutes), Style::Duration),
+            Cell::int(session.parts.base),
+            Cell::int(session.parts.gap),
+            Cell::int(session.parts.volume),
+            Cell::int(session.parts.manual),
+            Cell::int(session.commits.len() as u32),
+            Cell::text(session.repos.join(", ")),
+            Cell::text(session.description()),
+        ]);
+    }
+
+    let totals = totals_row(&rows, &[3, 4, 5, 6, 7, 8]);
+    rows.push(totals);
+    Sheet {
+        name: name.to_string(),
+        widths: vec![12.0, 17.0, 17.0, 10.0, 8.0, 11.0, 9.0, 9.0, 9.0, 24.0, 50.0],
+        rows,
+    }
+}
+
+/// "Total" followed by a SUM over the data rows of each of `columns`
+fn totals_row(rows: &[Vec<Cell>], columns: &[usize]) -> Vec<Cell> {
+    let width = rows[0].len();
+    let last = rows.len();
+    let mut totals: Vec<Cell> = (0..width).map(|_| Cell::empty()).collect();
+    totals[0] = Cell::header(t("common.total"));
+    for &c in columns {
+        let sum: f64 = rows[1..].iter().map(|row| row[c].number()).sum();
+        let style = rows[1..].first().map_or(Style::Integer, |row| row[c].style);
+        let col = column_name(c);
+        totals[c] = if last > 1 {
+            Cell::formula(format!("SUM({col}2:{col}{last})"), sum, style)
+        } else {
+            Cell::new(Value::Number(0.0), style)
+        };
+    }
+    totals
+}
+
+fn summary_sheet(report: &ClientReport, name: &str, days: &str, totals: DayTotals) -> Sheet {
+    let days = quote(days);
+    let total = totals.row;
+    let invoice = report.invoice();
+    let mut rows = vec![
+        vec![Cell::header(t("col.client")), Cell::text(&report.client_name)],
+        vec![Cell::header(t("sheet.from")), Cell::new(Value::Date(report.period_start), Style::Date)],
+        vec![Cell::header(t("sheet.to")), Cell::new(Value::Date(report.period_end), Style::Date)],
+        vec![Cell::header(t("sheet.currency")), Cell::text(&report.currency)],
+        vec![Cell::header(t("sheet.hourly_rate")), Cell::money(report.hourly_rate)],
+        vec![
+            Cell::header(t("sheet.detected_hours")),
+            Cell::formula(format!("{days}!C{total}"), totals.detected, Style::Duration),
+        ],
+        vec![
+            Cell::header(t("report.billed_hours")),
+            Cell::formula(format!("{days}!D{total}/1440"), totals.billed / 1440.0, Style::Duration),
+        ],
+        vec![
+            Cell::header(t("sheet.covered_hours")),
+            Cell::formula(format!("{days}!E{total}/1440"), totals.covered / 1440.0, Style::Duration),
+        ],
+    ];
+    let amount_row = rows.len() + 1;
+    rows.push(vec![
+        Cell::header(t("sheet.hours_amount")),
+        Cell::formula(format!("{days}!G{total}"), totals.amount, Style::Money),
+    ]);
+    let llm_row = rows.len() + 1;
+    rows.push(vec![Cell::header(t("sheet.llm")), Cell::money(report.llm_cost)]);
+    let expenses_row = rows.len() + 1;
+    rows.push(vec![Cell::header(t("report.expenses")), Cell::money(report.expenses_total())]);
+    let subtotal_row = rows.len() + 1;
+    rows.push(vec![
+        Cell::header(t("common.subtotal")),
+        Cell::formula(
+            format!("ROUND(B{amount_row}+B{llm_row}+B{expenses_row},2)"),
+            to_f64(invoice.subtotal),
+            Style::Money,
+        ),
+    ]);
+
+    // Discounts: percentage of the subtotal plus a fixed amount, never below zero
+    let first_discount = rows.len() + 1;
+    for (rule, line) in report.discounts.iter().zip(&invoice.discounts) {
+        let row = rows.len() + 1;
+        let percent = rule.percent.map_or(0.0, to_f64);
+        let fixed = rule.amount.map_or(0.0, to_f64);
+        let base = if row == first_discount {
+            format!("B{subtotal_row}")
+        } else {
+            format!("B{subtotal_row}+SUM(B{first_discount}:B{})", row - 1)
+        };
+        rows.push(vec![
+            Cell::header(&rule.name),
+            Cell::formula(
+                format!("-MIN(ROUND(B{subtotal_row}*{percent}/100+{fixed},2),MAX({base},0))"),
+                to_f64(line.amount),
+                Style::Money,
+            ),
+        ]);
+    }
+    let base_row = rows.len() + 1;
+    let discounts = if base_row > first_discount {
+        format!("+SUM(B{first_discount}:B{})", base_row - 1)
+    } else {
+        String::new()
+    };
+    rows.push(vec![
+        Cell::header(t("sheet.taxable_base")),
+        Cell::formula(format!("B{subtotal_row}{discounts}"), to_f64(invoice.taxable_base()), Style::Money),
+    ]);
+
+    let first_tax = rows.len() + 1;
+    for (rule, line) in report.tax_rules.iter().zip(&invoice.taxes) {
+        let sign = if rule.withholding { "-" } else { "" };
+        rows.push(vec![
+            Cell::header(&rule.name),
+            Cell::formula(
+                format!("{sign}ROUND(B{base_row}*{}/100,2)", to_f64(rule.percent)),
+                to_f64(line.amount),
+                Style::Money,
+            ),
+        ]);
+    }
+    let total_row = rows.len() + 1;
+    let taxes = if total_row > first_tax {
+        format!("+SUM(B{first_tax}:B{})", total_row - 1)
+    } else {
+        String::new()
+    };
+    rows.push(vec![
+        Cell::header(t("report.total_due")),
+        Cell::formula(format!("B{base_row}{taxes}"), to_f64(invoice.total()), Style::Money),
+    ]);
+
+    Sheet {
+        name: name.to_string(),
+        widths: vec![20.0, 16.0],
+        rows,
+    }
+}
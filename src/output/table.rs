@@ -1,115 +1,292 @@
 use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Table};
 use colored::Colorize;
-use crate::session::types::ClientReport;
+use rust_decimal::Decimal;
+use chrono::Weekday;
+use crate::i18n::{
+    day_label, day_title, format_amount, format_date, format_day, format_money, format_month, format_number,
+    format_signed_money, format_time, t, tf, weekday_long,
+};
+use crate::session::billing::DEFAULT_CATEGORY;
+use crate::session::budget::BudgetStatus;
+use crate::session::currency::RateDate;
+use crate::session::invoice::Invoice;
+use crate::session::receivables::{AgingBucket, ClientReceivables};
+use crate::session::retainer::RetainerPeriod;
+use crate::session::grouping::{group_report, GroupBy, GroupKey};
+use crate::session::types::{ClientReport, DayReport};
 
-pub fn print_client_report(report: &ClientReport) {
+pub fn print_client_report(report: &ClientReport, group_by: GroupBy) {
     println!();
-    println!("{}", format!("Cliente: {}", report.client_name).bold());
-    println!(
-        "{}",
-        format!(
-            "Periodo: {} — {}",
-            report.period_start.format("%d/%m/%Y"),
-            report.period_end.format("%d/%m/%Y")
-        )
-        .dimmed()
-    );
+    println!("{}", tf("report.client", &[("client", &report.client_name)]).bold());
+    println!("{}", period(report).dimmed());
     println!();
 
-    let show_llm = report.llm_cost_per_kloc > 0.0;
+    let groups = group_report(report, group_by);
+    let show_llm = report.shows_llm_cost();
+    let show_billed = groups.iter().any(|g| g.billed_minutes != g.total_minutes);
+    let show_overflow = report.overflow_minutes > 0;
+    let show_days = matches!(group_by, GroupBy::Week | GroupBy::Month | GroupBy::Weekday);
+    let show_repos = group_by != GroupBy::Repo;
 
     let mut table = Table::new();
-    let mut header = vec![
-        Cell::new("Fecha").fg(Color::Cyan),
-        Cell::new("Sesiones").fg(Color::Cyan),
-        Cell::new("Horas").fg(Color::Cyan),
-        Cell::new("Commits").fg(Color::Cyan),
-        Cell::new("+/-").fg(Color::Cyan),
-    ];
+    let mut header = vec![Cell::new(t(group_column(group_by))).fg(Color::Cyan)];
+    if show_days {
+        header.push(Cell::new(t("col.days")).fg(Color::Cyan));
+    }
+    header.push(Cell::new(t("col.sessions")).fg(Color::Cyan));
+    header.push(Cell::new(t("col.hours")).fg(Color::Cyan));
+    if show_billed {
+        header.push(Cell::new(t("col.billed")).fg(Color::Cyan));
+    }
+    if show_overflow {
+        header.push(Cell::new(t("col.overflow")).fg(Color::Cyan));
+    }
+    header.push(Cell::new(t("col.commits")).fg(Color::Cyan));
+    header.push(Cell::new("+/-").fg(Color::Cyan));
     if show_llm {
         header.push(Cell::new("LLM").fg(Color::Cyan));
     }
-    header.push(Cell::new("Repos").fg(Color::Cyan));
+    if show_repos {
+        header.push(Cell::new(t("col.repos")).fg(Color::Cyan));
+    }
     table
         .set_content_arrangement(ContentArrangement::Dynamic)
         .set_header(header);
 
-    for day in &report.days {
-        let day_lines = (day.total_lines_added + day.total_lines_deleted) as f64;
-        let day_llm = (day_lines / 1000.0) * report.llm_cost_per_kloc;
-
-        let mut row = vec![
-            Cell::new(day.date.format("%a %d/%m").to_string()),
-            Cell::new(day.sessions.len().to_string()).set_alignment(CellAlignment::Right),
-            Cell::new(format_duration(day.total_minutes)).set_alignment(CellAlignment::Right),
-            Cell::new(day.total_commits.to_string()).set_alignment(CellAlignment::Right),
-            Cell::new(format!("+{} -{}", day.total_lines_added, day.total_lines_deleted))
+    for group in &groups {
+        let mut row = vec![Cell::new(group_label(&group.key))];
+        if show_days {
+            row.push(Cell::new(group.days.to_string()).set_alignment(CellAlignment::Right));
+        }
+        row.push(Cell::new(group.sessions.to_string()).set_alignment(CellAlignment::Right));
+        row.push(Cell::new(format_duration(group.total_minutes)).set_alignment(CellAlignment::Right));
+        if show_billed {
+            row.push(Cell::new(format_duration(group.billed_minutes)).set_alignment(CellAlignment::Right));
+        }
+        if show_overflow {
+            let overflow = if group.overflow_minutes > 0 {
+                format_duration(group.overflow_minutes)
+            } else {
+                String::new()
+            };
+            row.push(Cell::new(overflow).set_alignment(CellAlignment::Right).fg(Color::Red));
+        }
+        row.push(Cell::new(group.total_commits.to_string()).set_alignment(CellAlignment::Right));
+        row.push(
+            Cell::new(format!("+{} -{}", group.lines_added, group.lines_deleted))
                 .set_alignment(CellAlignment::Right),
-        ];
+        );
         if show_llm {
-            row.push(Cell::new(format!("{:.2}", day_llm)).set_alignment(CellAlignment::Right));
+            row.push(Cell::new(format_money(group.llm_cost)).set_alignment(CellAlignment::Right));
+        }
+        if show_repos {
+            row.push(Cell::new(group.repos.join(", ")));
         }
-        row.push(Cell::new(day.repos.join(", ")));
         table.add_row(row);
     }
 
     // Totals row
     let total_sessions: usize = report.days.iter().map(|d| d.sessions.len()).sum();
-    let mut total_row = vec![
-        Cell::new("Total").fg(Color::Yellow),
+    let mut total_row = vec![Cell::new(t("common.total")).fg(Color::Yellow)];
+    if show_days {
+        total_row.push(
+            Cell::new(report.days.len().to_string())
+                .set_alignment(CellAlignment::Right)
+                .fg(Color::Yellow),
+        );
+    }
+    total_row.push(
         Cell::new(total_sessions.to_string())
             .set_alignment(CellAlignment::Right)
             .fg(Color::Yellow),
+    );
+    total_row.push(
         Cell::new(format_duration(report.total_minutes))
             .set_alignment(CellAlignment::Right)
             .fg(Color::Yellow),
+    );
+    if show_billed {
+        total_row.push(
+            Cell::new(format_duration(report.billed_minutes))
+                .set_alignment(CellAlignment::Right)
+                .fg(Color::Yellow),
+        );
+    }
+    if show_overflow {
+        total_row.push(
+            Cell::new(format_duration(report.overflow_minutes))
+                .set_alignment(CellAlignment::Right)
+                .fg(Color::Red),
+        );
+    }
+    total_row.push(
         Cell::new(report.total_commits.to_string())
             .set_alignment(CellAlignment::Right)
             .fg(Color::Yellow),
+    );
+    total_row.push(
         Cell::new(format!("+{} -{}", report.total_lines_added, report.total_lines_deleted))
             .set_alignment(CellAlignment::Right)
             .fg(Color::Yellow),
-    ];
+    );
     if show_llm {
         total_row.push(
-            Cell::new(format!("{:.2}", report.llm_cost))
+            Cell::new(format_money(report.llm_cost))
                 .set_alignment(CellAlignment::Right)
                 .fg(Color::Yellow),
         );
     }
-    total_row.push(Cell::new("").fg(Color::Yellow));
+    if show_repos {
+        total_row.push(Cell::new("").fg(Color::Yellow));
+    }
     table.add_row(total_row);
 
     println!("{table}");
     println!();
 
-    if report.hourly_rate > 0.0 {
+    if show_billed {
+        println!(
+            "{}",
+            tf(
+                "report.logged_billed",
+                &[
+                    ("logged", &format_duration(report.total_minutes)),
+                    ("billed", &format_duration(report.billed_minutes)),
+                ]
+            )
+            .dimmed()
+        );
+    }
+
+    if show_overflow {
+        println!(
+            "{}",
+            tf("report.overflow", &[("duration", &format_duration(report.overflow_minutes))]).red()
+        );
+    }
+
+    if group_by != GroupBy::Repo {
+        print_repo_summary(report);
+    }
+
+    if !report.expenses.is_empty() {
+        print_expenses(report);
+    }
+
+    if report.has_rate() {
+        let subtotals = report.rate_subtotals();
+        let hours_part = if subtotals.len() > 1 {
+            // Mixed-rate period: one subtotal line per category and rate
+            let show_category = subtotals.iter().any(|s| s.category != DEFAULT_CATEGORY);
+            for sub in &subtotals {
+                let label = if show_category {
+                    format!("{}: ", sub.category)
+                } else {
+                    String::new()
+                };
+                println!(
+                    "{}",
+                    format!(
+                        "  {}{}h × {}/h = {} {}",
+                        label,
+                        format_number(sub.billed_hours(), 2),
+                        format_amount(sub.hourly_rate),
+                        format_money(sub.amount),
+                        report.currency
+                    )
+                    .green()
+                );
+            }
+            format!("{} {}", format_money(report.billable_amount()), report.currency)
+        } else {
+            let rate = subtotals.first().map_or(report.hourly_rate, |s| s.hourly_rate);
+            format!(
+                "{}h × {}/h = {} {}",
+                format_number(report.billed_hours(), 2),
+                format_amount(rate),
+                format_money(report.billable_amount()),
+                report.currency
+            )
+        };
+        let covered = report.covered_minutes > 0;
+        let invoice = report.invoice();
+        // With discounts or taxes the sum above is only the subtotal
+        let total_label = if invoice.has_adjustments() { t("common.subtotal") } else { t("common.total") };
+        // LLM cost and expenses are added to the hours into the subtotal
+        let mut extras = String::new();
         if show_llm {
+            let label = if report.has_llm_usage() { t("report.llm_actual") } else { "LLM" };
+            extras += &format!("  +  {}: {} {}", label, format_money(report.llm_cost), report.currency);
+        }
+        if !report.expenses.is_empty() {
+            extras += &format!(
+                "  +  {}: {} {}",
+                t("report.expenses"),
+                format_money(report.expenses_total()),
+                report.currency
+            );
+        }
+        let line = if !extras.is_empty() && !covered {
+            format!(
+                "{}: {}{}  =  {}: {} {}",
+                t("report.amount"), hours_part, extras, total_label, format_money(invoice.subtotal), report.currency,
+            )
+        } else {
+            format!("{}: {}", t("report.amount"), hours_part)
+        };
+        println!("{}", line.green().bold());
+
+        if covered {
             println!(
                 "{}",
-                format!(
-                    "Monto: {:.2}h × {}/h = {:.2} {}  +  LLM: {:.2} {}  =  Total: {:.2} {}",
-                    report.total_hours(),
-                    report.hourly_rate,
-                    report.billable_amount(),
-                    report.currency,
-                    report.llm_cost,
-                    report.currency,
-                    report.total_amount(),
-                    report.currency,
+                tf(
+                    "report.covered",
+                    &[
+                        ("duration", &format_duration(report.covered_minutes)),
+                        ("amount", &format!("{} {}", format_money(report.covered_amount()), report.currency)),
+                    ]
                 )
-                .green()
-                .bold()
+                .cyan()
             );
-        } else {
+            let overage = tf(
+                "report.overage",
+                &[
+                    ("duration", &format_duration(report.overage_minutes())),
+                    ("amount", &format!("{} {}", format_money(report.overage_amount()), report.currency)),
+                ],
+            );
+            let line = if !extras.is_empty() {
+                format!(
+                    "{}{}  =  {}: {} {}",
+                    overage, extras, total_label, format_money(invoice.subtotal), report.currency
+                )
+            } else {
+                overage
+            };
+            println!("{}", line.green().bold());
+        }
+        if invoice.has_adjustments() {
+            print_invoice(&invoice, &report.currency);
+        }
+        if let Some(ref conv) = report.converted {
+            let rate = match conv.single_rate() {
+                Some(rate) if conv.rate_date == RateDate::Invoice => tf(
+                    "report.rate_on",
+                    &[("rate", &format_amount(rate.round_dp(4))), ("date", &format_date(report.period_end))],
+                ),
+                Some(rate) => tf("report.rate", &[("rate", &format_amount(rate.round_dp(4)))]),
+                None => t("report.rate_daily").to_string(),
+            };
             println!(
                 "{}",
-                format!(
-                    "Monto: {:.2}h × {}/h = {:.2} {}",
-                    report.total_hours(),
-                    report.hourly_rate,
-                    report.billable_amount(),
-                    report.currency
+                tf(
+                    "report.converted",
+                    &[
+                        ("currency", &conv.currency),
+                        ("rate", &rate),
+                        ("total", &format!("{} {}", format_money(conv.total_amount), conv.currency)),
+                        ("original", &format!("{} {}", format_money(invoice.total()), report.currency)),
+                    ]
                 )
                 .green()
                 .bold()
@@ -118,48 +295,150 @@ pub fn print_client_report(report: &ClientReport) {
     } else {
         println!(
             "{}",
-            format!("Total: {} (sin tasa horaria configurada)", format_duration(report.total_minutes)).yellow()
+            format!("{}: {} ({})", t("common.total"), format_duration(report.total_minutes), t("report.no_rate")).yellow()
         );
     }
     super::chart::print_charts(report);
     println!();
 }
 
-pub fn print_verify_report(report: &ClientReport) {
+/// Itemized expenses of the period
+/// Header of the first column for `--group-by`
+fn group_column(group_by: GroupBy) -> &'static str {
+    match group_by {
+        GroupBy::Day => "col.date",
+        GroupBy::Week => "col.week",
+        GroupBy::Month => "col.month",
+        GroupBy::Weekday => "col.weekday",
+        GroupBy::Repo => "col.repo",
+    }
+}
+
+/// Row label of a group (`Lun 05/01`, `Semana 2 (05/01)`, `enero 2026`)
+fn group_label(key: &GroupKey) -> String {
+    match key {
+        GroupKey::Day(date) => day_label(*date),
+        GroupKey::Week { week, .. } => {
+            let start = key.start().map(format_day).unwrap_or_default();
+            tf("group.week", &[("week", week), ("start", &start)])
+        }
+        GroupKey::Month { year, month } => {
+            tf("group.month", &[("month", &t(&format!("month.long.{}", month))), ("year", year)])
+        }
+        GroupKey::Weekday(days) => Weekday::try_from(*days as u8).map_or_else(|_| key.id(), |w| weekday_long(w).to_string()),
+        GroupKey::Repo(name) if name.is_empty() => t("group.no_repo").to_string(),
+        GroupKey::Repo(name) => name.clone(),
+    }
+}
+
+/// Hours, commits and amount per repo, when the period spans more than one
+fn print_repo_summary(report: &ClientReport) {
+    let repos = group_report(report, GroupBy::Repo);
+    if repos.len() < 2 {
+        return;
+    }
+    // The amount is for the billed time, so show it whenever it differs
+    let show_billed = repos.iter().any(|g| g.billed_minutes != g.total_minutes);
+    println!("{}", format!("{}:", t("report.repos")).bold());
+    let mut table = Table::new();
+    let mut header = vec![Cell::new(t("col.repo")).fg(Color::Cyan), Cell::new(t("col.hours")).fg(Color::Cyan)];
+    if show_billed {
+        header.push(Cell::new(t("col.billed")).fg(Color::Cyan));
+    }
+    header.push(Cell::new(t("col.commits")).fg(Color::Cyan));
+    if report.has_rate() {
+        header.push(Cell::new(t("col.amount")).fg(Color::Cyan));
+    }
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header);
+    for group in &repos {
+        let mut row = vec![
+            Cell::new(group_label(&group.key)),
+            Cell::new(format_duration(group.total_minutes)).set_alignment(CellAlignment::Right),
+        ];
+        if show_billed {
+            row.push(Cell::new(format_duration(group.billed_minutes)).set_alignment(CellAlignment::Right));
+        }
+        row.push(Cell::new(group.total_commits.to_string()).set_alignment(CellAlignment::Right));
+        if report.has_rate() {
+            row.push(
+                Cell::new(format!("{} {}", format_money(group.total_amount()), report.currency))
+                    .set_alignment(CellAlignment::Right),
+            );
+        }
+        table.add_row(row);
+    }
+    println!("{table}");
+    println!();
+}
+
+fn print_expenses(report: &ClientReport) {
+    println!("{}", format!("{}:", t("report.expenses")).bold());
+    for e in &report.expenses {
+        let repo = e.repo.as_ref().map_or(String::new(), |r| format!(" [{}]", r));
+        println!(
+            "  {}  {}{}: {} {}",
+            format_date(e.date),
+            e.description,
+            repo.dimmed(),
+            format_money(e.amount),
+            report.currency
+        );
+    }
     println!();
-    println!("{}", format!("Verificar sesiones: {}", report.client_name).bold());
+}
+
+/// Invoice footer: subtotal, discount and tax lines, total
+fn print_invoice(invoice: &Invoice, currency: &str) {
+    println!("{}", format!("{}: {} {}", t("common.subtotal"), format_money(invoice.subtotal), currency).green());
+    for line in invoice.discounts.iter().chain(&invoice.taxes) {
+        let label = match line.percent {
+            Some(percent) => format!("{} ({}%)", line.name, format_amount(percent)),
+            None => line.name.clone(),
+        };
+        println!("{}", format!("  {}: {} {}", label, format_signed_money(line.amount), currency).green());
+    }
+    println!("{}", format!("{}: {} {}", t("report.total_due"), format_money(invoice.total()), currency).green().bold());
+}
+
+/// Sum of the converted totals of every client in the report
+pub fn print_grand_total(total: Decimal, currency: &str, clients: usize) {
     println!(
         "{}",
-        format!(
-            "Periodo: {} — {}",
-            report.period_start.format("%d/%m/%Y"),
-            report.period_end.format("%d/%m/%Y")
-        )
-        .dimmed()
+        tf("report.grand_total", &[("clients", &clients), ("total", &format!("{} {}", format_money(total), currency))])
+            .green()
+            .bold()
     );
     println!();
+}
+
+pub fn print_verify_report(report: &ClientReport) {
+    println!();
+    println!("{}", tf("verify.title", &[("client", &report.client_name)]).bold());
+    println!("{}", period(report).dimmed());
+    println!();
 
     for day in &report.days {
         println!(
             "{}",
-            format!("── {} ({} sesiones, {}) ──",
-                day.date.format("%A %d/%m/%Y"),
-                day.sessions.len(),
-                format_duration(day.total_minutes)
-            ).cyan().bold()
+            format!("── {} ──", day_sessions(day)).cyan().bold()
         );
 
         for (i, session) in day.sessions.iter().enumerate() {
             println!(
-                "  Sesión {}:  {} → {}  ({}, {} commits, +{} -{})",
-                i + 1,
-                session.start.format("%H:%M"),
-                session.end.format("%H:%M"),
+                "  {}:  {} → {}  ({}, {} commits, +{} -{})",
+                tf("verify.session", &[("number", &(i + 1))]),
+                format_time(session.start),
+                format_time(session.end),
                 format_duration(session.duration_minutes),
                 session.commits.len(),
                 session.lines_added,
                 session.lines_deleted
             );
+            if let Some(ref note) = session.note {
+                println!("    {} {}", "manual".dimmed(), note);
+            }
             for commit in &session.commits {
                 let volume = if commit.lines_added + commit.lines_deleted > 0 {
                     format!(" (+{} -{})", commit.lines_added, commit.lines_deleted)
@@ -168,7 +447,7 @@ pub fn print_verify_report(report: &ClientReport) {
                 };
                 println!(
                     "    {} {} {}{}",
-                    commit.author_date.format("%H:%M").to_string().dimmed(),
+                    format_time(commit.author_date).dimmed(),
                     &commit.hash[..7].yellow(),
                     commit.subject,
                     volume.dimmed()
@@ -179,6 +458,227 @@ pub fn print_verify_report(report: &ClientReport) {
     }
 }
 
+pub fn print_retainer_balance(client_name: &str, periods: &[RetainerPeriod]) {
+    println!();
+    println!("{}", tf("retainer.title", &[("client", &client_name)]).bold());
+    println!();
+
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new(t("retainer.month")).fg(Color::Cyan),
+            Cell::new(t("retainer.included")).fg(Color::Cyan),
+            Cell::new(t("retainer.rollover")).fg(Color::Cyan),
+            Cell::new(t("retainer.prepaid")).fg(Color::Cyan),
+            Cell::new(t("retainer.used")).fg(Color::Cyan),
+            Cell::new(t("retainer.remaining")).fg(Color::Cyan),
+            Cell::new(t("retainer.overage")).fg(Color::Cyan),
+        ]);
+
+    let hours = |minutes: u32| {
+        if minutes == 0 {
+            "—".to_string()
+        } else {
+            format_duration(minutes)
+        }
+    };
+
+    for p in periods {
+        let prepaid = if p.prepaid_added_minutes > 0 {
+            format!("+{} ({})", format_duration(p.prepaid_added_minutes), hours(p.prepaid_balance_minutes))
+        } else {
+            hours(p.prepaid_balance_minutes)
+        };
+        let overage = Cell::new(hours(p.overage_minutes)).set_alignment(CellAlignment::Right);
+        table.add_row(vec![
+            Cell::new(format_month(p.month)),
+            Cell::new(hours(p.included_minutes)).set_alignment(CellAlignment::Right),
+            Cell::new(hours(p.rollover_in_minutes)).set_alignment(CellAlignment::Right),
+            Cell::new(prepaid).set_alignment(CellAlignment::Right),
+            Cell::new(hours(p.used_minutes)).set_alignment(CellAlignment::Right),
+            Cell::new(hours(p.remaining_minutes())).set_alignment(CellAlignment::Right),
+            if p.overage_minutes > 0 { overage.fg(Color::Red) } else { overage },
+        ]);
+    }
+
+    println!("{table}");
+    if let Some(last) = periods.last() {
+        println!();
+        println!(
+            "{}",
+            tf(
+                "retainer.available",
+                &[
+                    ("now", &format_duration(last.remaining_minutes())),
+                    ("next", &format_duration(last.rollover_out_minutes + last.prepaid_balance_minutes)),
+                ]
+            )
+            .green()
+            .bold()
+        );
+    }
+    println!();
+}
+
+/// Outstanding invoices per client, with the pending amount in its aging bucket
+pub fn print_receivables(groups: &[ClientReceivables]) {
+    for group in groups {
+        println!();
+        println!(
+            "{}",
+            tf("receivables.title", &[("client", &group.client), ("currency", &group.currency)]).bold()
+        );
+        println!();
+
+        let mut header = vec![
+            Cell::new(t("col.invoice")).fg(Color::Cyan),
+            Cell::new(t("col.issued")).fg(Color::Cyan),
+            Cell::new(t("col.days")).fg(Color::Cyan),
+            Cell::new(t("col.amount")).fg(Color::Cyan),
+            Cell::new(t("col.paid")).fg(Color::Cyan),
+        ];
+        header.extend(AgingBucket::ALL.iter().map(|b| Cell::new(b.label()).fg(Color::Cyan)));
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(header);
+
+        for open in &group.invoices {
+            let invoice = &open.invoice;
+            let mut row = vec![
+                Cell::new(&invoice.number),
+                Cell::new(format_date(invoice.issued)),
+                Cell::new(open.age_days.to_string()).set_alignment(CellAlignment::Right),
+                Cell::new(format_money(invoice.amount)).set_alignment(CellAlignment::Right),
+                Cell::new(format_money(invoice.paid())).set_alignment(CellAlignment::Right),
+            ];
+            for bucket in AgingBucket::ALL {
+                let text = if bucket == open.bucket {
+                    format_money(invoice.outstanding())
+                } else {
+                    String::new()
+                };
+                let cell = Cell::new(text).set_alignment(CellAlignment::Right);
+                row.push(if bucket == AgingBucket::Days61Plus { cell.fg(Color::Red) } else { cell });
+            }
+            table.add_row(row);
+        }
+
+        let mut total_row = vec![
+            Cell::new(t("common.total")).fg(Color::Yellow),
+            Cell::new(""),
+            Cell::new(""),
+            Cell::new(""),
+            Cell::new(""),
+        ];
+        for amount in group.bucket_totals() {
+            total_row.push(
+                Cell::new(format_money(amount))
+                    .set_alignment(CellAlignment::Right)
+                    .fg(Color::Yellow),
+            );
+        }
+        table.add_row(total_row);
+        println!("{table}");
+        println!(
+            "{}",
+            format!("{}: {} {}", t("col.outstanding"), format_money(group.total()), group.currency)
+                .green()
+                .bold()
+        );
+    }
+    println!();
+}
+
+pub fn print_budget_status(client_name: &str, currency: &str, statuses: &[BudgetStatus]) {
+    println!();
+    println!("{}", tf("budget.title", &[("client", &client_name)]).bold());
+    println!();
+
+    for status in statuses {
+        let pct = status.fraction_used() * 100.0;
+        let title = format!(
+            "── {} ──",
+            tf(
+                "budget.heading",
+                &[("name", &status.name), ("since", &format_date(status.start)), ("used", &format!("{:.0}", pct))]
+            )
+        );
+        if status.fraction_used() >= 1.0 {
+            println!("{}", title.red().bold());
+        } else if status.over_threshold() {
+            println!("{}", title.yellow().bold());
+        } else {
+            println!("{}", title.cyan().bold());
+        }
+
+        if let (Some(limit), Some(remaining)) = (status.limit_minutes, status.remaining_minutes()) {
+            let remaining = if remaining >= 0 {
+                format_duration(remaining as u32)
+            } else {
+                format!("-{}", format_duration(remaining.unsigned_abs() as u32))
+            };
+            println!(
+                "  {}",
+                tf(
+                    "budget.hours",
+                    &[
+                        ("used", &format_duration(status.consumed_minutes)),
+                        ("limit", &format_duration(limit)),
+                        ("remaining", &remaining),
+                        ("pace", &format_duration(status.burn_minutes_per_day.round() as u32)),
+                    ]
+                )
+            );
+        }
+        if let (Some(limit), Some(remaining)) = (status.limit_amount, status.remaining_amount()) {
+            println!(
+                "  {}",
+                tf(
+                    "budget.amount",
+                    &[
+                        ("used", &format_money(status.consumed_amount)),
+                        ("limit", &format!("{} {}", format_money(limit), currency)),
+                        ("remaining", &format_money(remaining)),
+                        ("pace", &format_money(status.burn_amount_per_day)),
+                    ]
+                )
+            );
+        }
+        match status.projected_exhaustion {
+            Some(date) if status.fraction_used() >= 1.0 => {
+                println!("  {}", tf("budget.exhausted", &[("date", &format_date(date))]).red());
+            }
+            Some(date) => println!("  {}", tf("budget.runs_out", &[("date", &format_date(date))])),
+            None => println!("  {}", t("budget.no_projection").dimmed()),
+        }
+        println!();
+    }
+}
+
+/// "Periodo: 01/01/2026 — 31/01/2026"
+fn period(report: &ClientReport) -> String {
+    tf(
+        "report.period",
+        &[
+            ("since", &format_date(report.period_start)),
+            ("until", &format_date(report.period_end)),
+        ],
+    )
+}
+
+/// "Lunes 05/01/2026 (2 sesiones, 3h 15m)"
+pub(crate) fn day_sessions(day: &DayReport) -> String {
+    tf(
+        "report.day_sessions",
+        &[
+            ("day", &day_title(day.date)),
+            ("count", &day.sessions.len()),
+            ("duration", &format_duration(day.total_minutes)),
+        ],
+    )
+}
 
 pub fn format_duration(minutes: u32) -> String {
     let hours = minutes / 60;
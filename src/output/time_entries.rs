@@ -0,0 +1,132 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, FixedOffset};
+use crate::session::types::{ClientReport, Session};
+
+/// Value written in one column of a time-entry import file
+#[derive(Debug, Clone, Copy)]
+pub enum Field {
+    /// Client name, used as both client and project
+    Client,
+    Project,
+    /// Commit subjects of the session (or the manual entry's note)
+    Description,
+    /// Repos of the session
+    Tags,
+    /// Author email of the session's first commit
+    Email,
+    FirstName,
+    LastName,
+    /// Session start, formatted with the given chrono pattern
+    Start(&'static str),
+    /// Session start plus its duration, formatted with the given chrono pattern
+    End(&'static str),
+    /// Duration as HH:MM:SS
+    DurationClock,
+    /// Duration as decimal hours (2 decimals)
+    DurationHours,
+    Text(&'static str),
+}
+
+/// A time tracker's CSV import schema: header names mapped to fields
+#[derive(Debug, Clone, Copy)]
+pub struct ImportTarget {
+    pub name: &'static str,
+    pub columns: &'static [(&'static str, Field)],
+}
+
+/// Toggl Track detailed CSV
+pub const TOGGL: ImportTarget = ImportTarget {
+    name: "toggl",
+    columns: &[
+        ("Email", Field::Email),
+        ("Client", Field::Client),
+        ("Project", Field::Project),
+        ("Description", Field::Description),
+        ("Billable", Field::Text("Yes")),
+        ("Start date", Field::Start("%Y-%m-%d")),
+        ("Start time", Field::Start("%H:%M:%S")),
+        ("End date", Field::End("%Y-%m-%d")),
+        ("End time", Field::End("%H:%M:%S")),
+        ("Duration", Field::DurationClock),
+        ("Tags", Field::Tags),
+    ],
+};
+
+/// Clockify time-entry import
+pub const CLOCKIFY: ImportTarget = ImportTarget {
+    name: "clockify",
+    columns: &[
+        ("Project", Field::Project),
+        ("Client", Field::Client),
+        ("Description", Field::Description),
+        ("Email", Field::Email),
+        ("Tags", Field::Tags),
+        ("Billable", Field::Text("Yes")),
+        ("Start Date", Field::Start("%m/%d/%Y")),
+        ("Start Time", Field::Start("%H:%M")),
+        ("End Date", Field::End("%m/%d/%Y")),
+        ("End Time", Field::End("%H:%M")),
+        ("Duration (h)", Field::DurationClock),
+        ("Duration (decimal)", Field::DurationHours),
+    ],
+};
+
+/// Harvest timesheet import
+pub const HARVEST: ImportTarget = ImportTarget {
+    name: "harvest",
+    columns: &[
+        ("Date", Field::Start("%Y-%m-%d")),
+        ("Client", Field::Client),
+        ("Project", Field::Project),
+        ("Task", Field::Text("Development")),
+        ("Notes", Field::Description),
+        ("Hours", Field::DurationHours),
+        ("First name", Field::FirstName),
+        ("Last name", Field::LastName),
+    ],
+};
+
+pub const TARGETS: &[ImportTarget] = &[TOGGL, CLOCKIFY, HARVEST];
+
+pub fn import_target(name: &str) -> Option<&'static ImportTarget> {
+    TARGETS.iter().find(|t| t.name == name)
+}
+
+/// One row per session in the target's import schema. Entries end at the
+/// session's last commit and last its duration, as in the timeclock,
+/// Timewarrior and org exports.
+pub fn serialize_time_entries(report: &ClientReport, target: &ImportTarget) -> Result<String> {
+    let mut wtr = csv::Writer::from_writer(Vec::new());
+    wtr.write_record(target.columns.iter().map(|(header, _)| *header))?;
+    for session in report.days.iter().flat_map(|d| &d.sessions) {
+        let record: Vec<String> = target
+            .columns
+            .iter()
+            .map(|(_, field)| value(report, session, *field))
+            .collect();
+        wtr.write_record(&record)?;
+    }
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+fn value(report: &ClientReport, session: &Session, field: Field) -> String {
+    let author = session.commits.first();
+    let name = author.map_or("", |c| c.author_name.as_str());
+    let start = || -> DateTime<FixedOffset> { session.end - Duration::minutes(session.duration_minutes as i64) };
+    match field {
+        Field::Client | Field::Project => report.client_name.clone(),
+        Field::Description => session.description(),
+        Field::Tags => session.repos.join(", "),
+        Field::Email => author.map_or(String::new(), |c| c.author_email.clone()),
+        Field::FirstName => name.split_whitespace().next().unwrap_or("").to_string(),
+        Field::LastName => name.split_whitespace().skip(1).collect::<Vec<_>>().join(" "),
+        Field::Start(format) => start().format(format).to_string(),
+        Field::End(format) => session.end.format(format).to_string(),
+        Field::DurationClock => {
+            let minutes = session.duration_minutes;
+            format!("{:02}:{:02}:00", minutes / 60, minutes % 60)
+        }
+        Field::DurationHours => format!("{:.2}", session.duration_minutes as f64 / 60.0),
+        Field::Text(text) => text.to_string(),
+    }
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use crate::import::GTT_TAG;
+use crate::session::types::ClientReport;
+
+/// Sessions as `timew import` JSON. Intervals span the session's duration,
+/// ending at its last commit, and are tagged with the client, the repos and
+/// `gtt` so importing them back into gtt skips them.
+pub fn serialize_timewarrior(report: &ClientReport) -> Result<String> {
+    let intervals: Vec<serde_json::Value> = report
+        .days
+        .iter()
+        .flat_map(|d| &d.sessions)
+        .enumerate()
+        .map(|(i, session)| {
+            let start = session.end - Duration::minutes(session.duration_minutes as i64);
+            let mut tags = vec![report.client_name.clone()];
+            tags.extend(session.repos.iter().cloned());
+            tags.push(GTT_TAG.to_string());
+            json!({
+                "id": i + 1,
+                "start": start.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string(),
+                "end": session.end.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string(),
+                "tags": tags,
+                "annotation": session.description(),
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&intervals)?)
+}
@@ -0,0 +1,91 @@
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime};
+use rust_xlsxwriter::{Format, Formula, Workbook as XlsxWorkbook};
+use super::spreadsheet::{date_parts, DatePart, Style, Value, Workbook};
+
+/// Excel serial day number: days since 1899-12-30
+fn serial_date(date: NaiveDate) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30).unwrap_or_default();
+    (date - epoch).num_days() as f64
+}
+
+fn serial_datetime(dt: NaiveDateTime) -> f64 {
+    serial_date(dt.date()) + (dt - dt.date().and_hms_opt(0, 0, 0).unwrap_or(dt)).num_seconds() as f64 / 86400.0
+}
+
+fn format(style: Style) -> Format {
+    match style {
+        Style::Default => Format::new(),
+        Style::Header => Format::new().set_bold(),
+        Style::Integer => Format::new().set_num_format("0"),
+        Style::Money => Format::new().set_num_format("#,##0.00"),
+        Style::Date => Format::new().set_num_format(number_format(&date_parts(false))),
+        Style::DateTime => Format::new().set_num_format(number_format(&date_parts(true))),
+        Style::Duration => Format::new().set_num_format("[h]:mm"),
+    }
+}
+
+/// Excel number format of date parts (`dd"/"mm"/"yyyy`)
+fn number_format(parts: &[DatePart]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            DatePart::Day { padded: true } => "dd".to_string(),
+            DatePart::Day { padded: false } => "d".to_string(),
+            DatePart::Month { padded: true } => "mm".to_string(),
+            DatePart::Month { padded: false } => "m".to_string(),
+            DatePart::MonthName { long: true } => "mmmm".to_string(),
+            DatePart::MonthName { long: false } => "mmm".to_string(),
+            DatePart::Year { long: true } => "yyyy".to_string(),
+            DatePart::Year { long: false } => "yy".to_string(),
+            DatePart::Weekday { long: true } => "dddd".to_string(),
+            DatePart::Weekday { long: false } => "ddd".to_string(),
+            DatePart::Hours { padded: true } => "hh".to_string(),
+            DatePart::Hours { padded: false } => "h".to_string(),
+            DatePart::Minutes => "mm".to_string(),
+            DatePart::AmPm => "AM/PM".to_string(),
+            // Quoted literal; a quote closes it, is escaped and reopens it
+            DatePart::Text(text) => format!("\"{}\"", text.replace('"', "\"\\\"\"")),
+        })
+        .collect()
+}
+
+/// Write the workbook as an Office Open XML spreadsheet
+pub fn write_xlsx(book: &Workbook) -> Result<Vec<u8>> {
+    let mut xlsx = XlsxWorkbook::new();
+    for sheet in &book.sheets {
+        let ws = xlsx.add_worksheet();
+        ws.set_name(&sheet.name)?;
+        for (c, width) in sheet.widths.iter().enumerate() {
+            ws.set_column_width(c as u16, *width)?;
+        }
+        for (r, row) in sheet.rows.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let (r, c, fmt) = (r as u32, c as u16, format(cell.style));
+                match cell.value {
+                    Value::Empty => {}
+                    Value::Text(ref text) => {
+                        ws.write_string_with_format(r, c, text, &fmt)?;
+                    }
+                    Value::Number(n) => {
+                        ws.write_number_with_format(r, c, n, &fmt)?;
+                    }
+                    Value::Date(date) => {
+                        ws.write_number_with_format(r, c, serial_date(date), &fmt)?;
+                    }
+                    Value::DateTime(dt) => {
+                        ws.write_number_with_format(r, c, serial_datetime(dt), &fmt)?;
+                    }
+                    Value::Duration(minutes) => {
+                        ws.write_number_with_format(r, c, minutes as f64 / 1440.0, &fmt)?;
+                    }
+                    Value::Formula(ref formula, result) => {
+                        let formula = Formula::new(format!("={}", formula)).set_result(result.to_string());
+                        ws.write_formula_with_format(r, c, formula, &fmt)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(xlsx.save_to_buffer()?)
+}
@@ -1,8 +1,10 @@
 use std::collections::BTreeMap;
-use chrono::NaiveDate;
-use crate::config::Settings;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+use rust_decimal::Decimal;
+use crate::config::{RepoAttribution, Settings};
 use crate::git::Commit;
-use super::types::{DayReport, Session};
+use super::attribution::day_repo_minutes;
+use super::types::{DayReport, DurationParts, Session};
 
 /// Core session detection algorithm.
 /// Takes a flat list of commits (from one or multiple repos) and groups them into sessions.
@@ -24,7 +26,7 @@ pub fn analyze(mut commits: Vec<Commit>, settings: &Settings) -> Vec<Session> {
 
     let mut sessions: Vec<Session> = Vec::new();
     let mut current_commits: Vec<Commit> = vec![commits[0].clone()];
-    let mut current_minutes: u32 = settings.first_commit_minutes;
+    let mut current_gap: u32 = 0;
 
     for i in 1..commits.len() {
         let prev = &commits[i - 1];
@@ -38,29 +40,29 @@ pub fn analyze(mut commits: Vec<Commit>, settings: &Settings) -> Vec<Session> {
 
         if crosses_midnight || long_gap {
             // Finalize current session
-            let session = build_session(current_commits, current_minutes, settings);
+            let session = build_session(current_commits, current_gap, settings);
             sessions.push(session);
 
             // Start new session
             current_commits = vec![curr.clone()];
-            current_minutes = settings.first_commit_minutes;
+            current_gap = 0;
         } else {
             // Continue same session — add gap time as work time
-            current_minutes += gap_minutes;
+            current_gap += gap_minutes;
             current_commits.push(curr.clone());
         }
     }
 
     // Finalize last session
     if !current_commits.is_empty() {
-        let session = build_session(current_commits, current_minutes, settings);
+        let session = build_session(current_commits, current_gap, settings);
         sessions.push(session);
     }
 
     sessions
 }
 
-fn build_session(commits: Vec<Commit>, duration_minutes: u32, settings: &Settings) -> Session {
+fn build_session(commits: Vec<Commit>, gap_minutes: u32, settings: &Settings) -> Session {
     let start = commits.first().unwrap().author_date;
     let end = commits.last().unwrap().author_date;
 
@@ -85,14 +87,22 @@ fn build_session(commits: Vec<Commit>, duration_minutes: u32, settings: &Setting
         0
     };
 
+    let parts = DurationParts {
+        base: settings.first_commit_minutes,
+        gap: gap_minutes,
+        volume: volume_bonus,
+        manual: 0,
+    };
     Session {
         start,
         end,
-        duration_minutes: duration_minutes + volume_bonus,
+        duration_minutes: parts.base + parts.gap + parts.volume,
         commits,
         repos,
         lines_added,
         lines_deleted,
+        note: None,
+        parts,
     }
 }
 
@@ -117,13 +127,20 @@ pub fn group_by_day(sessions: Vec<Session>) -> Vec<DayReport> {
                 .collect();
             repos.sort();
             repos.dedup();
+            let repo_minutes = day_repo_minutes(&day_sessions, RepoAttribution::Commits);
 
             DayReport {
                 date,
                 sessions: day_sessions,
                 total_minutes,
+                billed_minutes: total_minutes,
+                overflow_minutes: 0,
+                hourly_rate: Decimal::ZERO,
+                categories: Vec::new(),
+                covered_minutes: 0,
                 total_commits,
                 repos,
+                repo_minutes,
                 total_lines_added,
                 total_lines_deleted,
             }
@@ -131,6 +148,57 @@ pub fn group_by_day(sessions: Vec<Session>) -> Vec<DayReport> {
         .collect()
 }
 
+/// Remove from manual sessions the time git sessions already bill, each git
+/// session spanning its duration up to its last commit. A manual session may
+/// split in two around a git session. Returns what is left of the manual
+/// sessions and the minutes removed.
+pub fn trim_manual_overlaps(git: &[Session], manual: Vec<Session>) -> (Vec<Session>, u32) {
+    let busy: Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> = git
+        .iter()
+        .map(|s| (s.end - Duration::minutes(s.duration_minutes as i64), s.end))
+        .collect();
+    let mut kept = Vec::new();
+    let mut trimmed = 0;
+    for session in manual {
+        let mut pieces = vec![(session.start, session.end)];
+        for &(busy_start, busy_end) in &busy {
+            pieces = pieces
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    if busy_end <= start || busy_start >= end {
+                        return vec![(start, end)];
+                    }
+                    let mut rest = Vec::new();
+                    if busy_start > start {
+                        rest.push((start, busy_start));
+                    }
+                    if busy_end < end {
+                        rest.push((busy_end, end));
+                    }
+                    rest
+                })
+                .collect();
+        }
+        let mut left = 0;
+        for (start, end) in pieces {
+            let minutes = (end - start).num_minutes().max(0) as u32;
+            if minutes == 0 {
+                continue;
+            }
+            left += minutes;
+            kept.push(Session {
+                start,
+                end,
+                duration_minutes: minutes,
+                parts: DurationParts { manual: minutes, ..Default::default() },
+                ..session.clone()
+            });
+        }
+        trimmed += session.duration_minutes.saturating_sub(left);
+    }
+    (kept, trimmed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,18 +216,15 @@ mod tests {
             repo_name: repo.to_string(),
             lines_added: 0,
             lines_deleted: 0,
+            files: vec![],
+            branch: None,
         }
     }
 
     fn default_settings() -> Settings {
         Settings {
-            session_gap_minutes: 120,
-            first_commit_minutes: 30,
-            exclude_weekends: false,
             bot_authors: vec![],
-            volume_adjustment: false,
-            volume_factor: 5.0,
-            volume_scale: 50.0,
+            ..Settings::default()
         }
     }
 
@@ -260,6 +325,7 @@ mod tests {
         let sessions = analyze(commits, &settings);
         assert_eq!(sessions.len(), 1);
         assert_eq!(sessions[0].duration_minutes, 30 + 120);
+        assert_eq!(sessions[0].parts, DurationParts { base: 30, gap: 120, volume: 0, manual: 0 });
     }
 
     #[test]
@@ -0,0 +1,61 @@
+use chrono::{DateTime, FixedOffset};
+use crate::config::RepoAttribution;
+use super::billing::split_minutes;
+use super::types::{DayReport, RepoMinutes, Session};
+
+/// Recompute each day's `repo_minutes` with `method`. `group_by_day` fills
+/// them by commit count.
+pub fn attribute_repos(days: &mut [DayReport], method: RepoAttribution) {
+    for day in days.iter_mut() {
+        day.repo_minutes = day_repo_minutes(&day.sessions, method);
+    }
+}
+
+/// Minutes of the sessions per repo, largest first
+pub fn day_repo_minutes(sessions: &[Session], method: RepoAttribution) -> Vec<RepoMinutes> {
+    let mut totals: Vec<RepoMinutes> = Vec::new();
+    for session in sessions {
+        for (repo, minutes) in session_repo_minutes(session, method) {
+            match totals.iter_mut().find(|r| r.repo == repo) {
+                Some(total) => total.minutes += minutes,
+                None => totals.push(RepoMinutes { repo, minutes }),
+            }
+        }
+    }
+    totals.sort_by(|a, b| b.minutes.cmp(&a.minutes).then_with(|| a.repo.cmp(&b.repo)));
+    totals
+}
+
+/// A session's minutes per repo, in order of first commit. Manual sessions
+/// split evenly between their repos, or go to `""` without one.
+pub fn session_repo_minutes(session: &Session, method: RepoAttribution) -> Vec<(String, u32)> {
+    let mut repos: Vec<(String, u64)> = Vec::new();
+    let mut previous: Option<DateTime<FixedOffset>> = None;
+    for commit in &session.commits {
+        let weight = match method {
+            RepoAttribution::Commits => 1,
+            RepoAttribution::Lines => (commit.lines_added + commit.lines_deleted) as u64,
+            // The first commit gets the minutes credited before it
+            RepoAttribution::Time => match previous {
+                None => session.parts.base as u64,
+                Some(at) => (commit.author_date - at).num_minutes().max(0) as u64,
+            },
+        };
+        previous = Some(commit.author_date);
+        match repos.iter_mut().find(|(name, _)| *name == commit.repo_name) {
+            Some((_, total)) => *total += weight,
+            None => repos.push((commit.repo_name.clone(), weight)),
+        }
+    }
+    // Commits without lines (or time) fall back to the commit count
+    if method != RepoAttribution::Commits && repos.iter().all(|(_, w)| *w == 0) {
+        return session_repo_minutes(session, RepoAttribution::Commits);
+    }
+    if repos.is_empty() {
+        repos = session.repos.iter().map(|r| (r.clone(), 1)).collect();
+    }
+    if repos.is_empty() {
+        return vec![(String::new(), session.duration_minutes)];
+    }
+    split_minutes(session.duration_minutes, &repos)
+}
@@ -0,0 +1,318 @@
+use chrono::Datelike;
+use crate::config::{ClientConfig, RateRule, RepoAttribution, RoundingConfig, RoundingMode, RoundingScope, Settings};
+use crate::git::Commit;
+use super::analyzer::group_by_day;
+use super::attribution::{attribute_repos, session_repo_minutes};
+use super::types::{DayCategory, DayReport, RepoMinutes, Session};
+
+/// Category name for time not matched by any rate rule
+pub const DEFAULT_CATEGORY: &str = "default";
+
+/// Round `minutes` to a multiple of `increment` using the given mode.
+/// An increment of 0 or 1 leaves the value untouched.
+pub fn round_minutes(minutes: u32, increment: u32, mode: RoundingMode) -> u32 {
+    if increment <= 1 {
+        return minutes;
+    }
+    let rem = minutes % increment;
+    if rem == 0 {
+        return minutes;
+    }
+    let down = minutes - rem;
+    match mode {
+        RoundingMode::Up => down + increment,
+        RoundingMode::Down => down,
+        RoundingMode::Nearest => {
+            if rem * 2 >= increment {
+                down + increment
+            } else {
+                down
+            }
+        }
+    }
+}
+
+/// Compute `billed_minutes` for every day according to the rounding policy.
+///
+/// Order of operations:
+/// 1. `minimum_per_session` raises every session to the minimum
+/// 2. The increment is applied at the configured scope (session, day or period)
+/// 3. `minimum_per_day` raises every active day to the minimum
+///
+/// With `scope = "period"` the difference between the rounded period total and the
+/// sum of the days is booked on the last days, so days always add up to the total.
+/// Rounding down never takes a day below `minimum_per_day`; when every day is at
+/// the minimum the period total stays above the rounded value.
+pub fn apply_rounding(days: &mut [DayReport], rounding: &RoundingConfig) {
+    let increment = rounding.increment_minutes;
+
+    for day in days.iter_mut() {
+        let session_minutes = day.sessions.iter().map(|s| {
+            let minutes = s.duration_minutes.max(rounding.minimum_per_session);
+            if rounding.scope == RoundingScope::Session {
+                round_minutes(minutes, increment, rounding.mode)
+                    .max(rounding.minimum_per_session)
+            } else {
+                minutes
+            }
+        });
+        let mut billed: u32 = session_minutes.sum();
+        if rounding.scope == RoundingScope::Day {
+            billed = round_minutes(billed, increment, rounding.mode);
+        }
+        day.billed_minutes = billed.max(rounding.minimum_per_day);
+    }
+
+    if rounding.scope == RoundingScope::Period {
+        let total: u32 = days.iter().map(|d| d.billed_minutes).sum();
+        let rounded = round_minutes(total, increment, rounding.mode);
+        adjust_tail(days, rounded as i64 - total as i64, rounding.minimum_per_day);
+    }
+}
+
+/// Set each day's hourly rate from the client's rate history and split its
+/// billed minutes into rate categories.
+///
+/// Each session's minutes are first split between its repos by `method`, then
+/// each repo's share among the `rate_rules` matching its commits there,
+/// proportionally to commit count; unmatched commits fall in the default category,
+/// billed at the day's rate. The day's billed minutes are then split in the same
+/// proportions, so rounding and caps carry over to every category, and each
+/// category's minutes between its repos.
+pub fn apply_rates(days: &mut [DayReport], client: &ClientConfig, method: RepoAttribution) {
+    let rules = &client.rate_rules;
+    for day in days.iter_mut() {
+        day.hourly_rate = client.rate_on(day.date);
+
+        let mut raw: Vec<(Option<usize>, u64)> = Vec::new();
+        let mut raw_repos: Vec<((Option<usize>, String), u64)> = Vec::new();
+        for session in &day.sessions {
+            for (repo, repo_minutes) in session_repo_minutes(session, method) {
+                for (rule, minutes) in split_minutes(repo_minutes, &rule_weights(session, rules, &repo)) {
+                    add_weight(&mut raw, rule, minutes as u64);
+                    add_weight(&mut raw_repos, (rule, repo.clone()), minutes as u64);
+                }
+            }
+        }
+
+        let mut billed = split_minutes(day.billed_minutes, &raw);
+        if billed.is_empty() {
+            billed = vec![(None, day.billed_minutes)];
+        }
+        day.categories = billed
+            .into_iter()
+            .filter(|(_, minutes)| *minutes > 0)
+            .map(|(rule, minutes)| {
+                let repo_weights: Vec<(String, u64)> = raw_repos
+                    .iter()
+                    .filter(|((r, _), _)| *r == rule)
+                    .map(|((_, repo), w)| (repo.clone(), *w))
+                    .collect();
+                let repos = split_minutes(minutes, &repo_weights)
+                    .into_iter()
+                    .filter(|(_, minutes)| *minutes > 0)
+                    .map(|(repo, minutes)| RepoMinutes { repo, minutes })
+                    .collect();
+                let (name, hourly_rate) = match rule {
+                    Some(i) => (rules[i].name.clone(), rules[i].hourly_rate),
+                    None => (DEFAULT_CATEGORY.to_string(), day.hourly_rate),
+                };
+                DayCategory { name, hourly_rate, billed_minutes: minutes, repos }
+            })
+            .collect();
+    }
+}
+
+/// Commit count per matching rule index (`None` = no rule matched) among the
+/// session's commits in `repo`
+fn rule_weights(session: &Session, rules: &[RateRule], repo: &str) -> Vec<(Option<usize>, u64)> {
+    let mut weights = Vec::new();
+    for commit in session.commits.iter().filter(|c| c.repo_name == repo) {
+        let rule = rules.iter().position(|r| rule_matches(r, commit));
+        add_weight(&mut weights, rule, 1);
+    }
+    if weights.is_empty() {
+        weights.push((None, 1));
+    }
+    weights
+}
+
+fn add_weight<K: PartialEq>(weights: &mut Vec<(K, u64)>, key: K, weight: u64) {
+    match weights.iter_mut().find(|(k, _)| *k == key) {
+        Some(entry) => entry.1 += weight,
+        None => weights.push((key, weight)),
+    }
+}
+
+/// Whether every matcher set on the rule matches the commit.
+pub fn rule_matches(rule: &RateRule, commit: &Commit) -> bool {
+    if let Some(ref repo) = rule.repo {
+        if *repo != commit.repo_name {
+            return false;
+        }
+    }
+    if let Some(ref pattern) = rule.branch {
+        match commit.branch {
+            Some(ref branch) if glob_match(pattern, branch) => {}
+            _ => return false,
+        }
+    }
+    if let Some(ref pattern) = rule.path {
+        let matched = commit.files.iter().any(|f| {
+            if pattern.contains('*') {
+                glob_match(pattern, f)
+            } else {
+                f.starts_with(pattern.as_str())
+            }
+        });
+        if !matched {
+            return false;
+        }
+    }
+    if !rule.commit_types.is_empty() {
+        match commit.conventional_type() {
+            Some(kind) if rule.commit_types.iter().any(|t| t == kind) => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Minimal glob: `*` matches any run of characters, everything else is literal.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !text.starts_with(first) || text.len() < first.len() + last.len() || !text.ends_with(last) {
+        return false;
+    }
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Split `total` minutes proportionally to `weights` using the largest-remainder
+/// method, so the parts always add up to `total`. Returns an empty list when all
+/// weights are zero.
+pub fn split_minutes<K: Clone>(total: u32, weights: &[(K, u64)]) -> Vec<(K, u32)> {
+    let sum: u64 = weights.iter().map(|(_, w)| *w).sum();
+    if sum == 0 {
+        return Vec::new();
+    }
+    let mut parts: Vec<(K, u32, u64)> = weights
+        .iter()
+        .map(|(k, w)| {
+            let exact = total as u64 * w;
+            (k.clone(), (exact / sum) as u32, exact % sum)
+        })
+        .collect();
+    let assigned: u32 = parts.iter().map(|p| p.1).sum();
+    let mut order: Vec<usize> = (0..parts.len()).collect();
+    order.sort_by(|a, b| parts[*b].2.cmp(&parts[*a].2));
+    for idx in order.into_iter().take((total - assigned) as usize) {
+        parts[idx].1 += 1;
+    }
+    parts.into_iter().map(|(k, m, _)| (k, m)).collect()
+}
+
+/// Sessions → days → repo attribution → rounding → caps → rates, with the
+/// sessions billed as one period
+pub fn bill_sessions(sessions: Vec<Session>, client: &ClientConfig, settings: &Settings) -> Vec<DayReport> {
+    let mut days = group_by_day(sessions);
+    attribute_repos(&mut days, settings.repo_attribution);
+    if let Some(ref rounding) = client.rounding {
+        apply_rounding(&mut days, rounding);
+    }
+    apply_caps(&mut days, client);
+    apply_rates(&mut days, client, settings.repo_attribution);
+    days
+}
+
+/// `bill_sessions` one calendar month at a time, so period caps and
+/// period-scope rounding apply to each month. `sessions` must be sorted.
+pub fn bill_by_month(sessions: Vec<Session>, client: &ClientConfig, settings: &Settings) -> Vec<DayReport> {
+    let mut days = Vec::new();
+    let mut month: Vec<Session> = Vec::new();
+    for session in sessions {
+        let key = |s: &Session| (s.date().year(), s.date().month());
+        if month.last().is_some_and(|last| key(last) != key(&session)) {
+            days.extend(bill_sessions(std::mem::take(&mut month), client, settings));
+        }
+        month.push(session);
+    }
+    days.extend(bill_sessions(month, client, settings));
+    days
+}
+
+/// Apply the client's daily, weekly and period caps to the billed minutes.
+/// Must run after rounding. Whatever exceeds a cap moves from `billed_minutes`
+/// to `overflow_minutes` so it stays visible in reports instead of being dropped.
+///
+/// Days are processed in chronological order, so for weekly and period caps
+/// the overflow lands on the days that crossed the limit.
+pub fn apply_caps(days: &mut [DayReport], client: &ClientConfig) {
+    if let Some(cap) = client.max_hours_per_day.map(hours_to_minutes) {
+        for day in days.iter_mut() {
+            trim_day(day, day.billed_minutes.saturating_sub(cap));
+        }
+    }
+
+    if let Some(cap) = client.max_hours_per_week.map(hours_to_minutes) {
+        let mut current_week = None;
+        let mut used = 0u32;
+        for day in days.iter_mut() {
+            let iw = day.date.iso_week();
+            let week = (iw.year(), iw.week());
+            if current_week != Some(week) {
+                current_week = Some(week);
+                used = 0;
+            }
+            trim_day(day, (used + day.billed_minutes).saturating_sub(cap));
+            used += day.billed_minutes;
+        }
+    }
+
+    if let Some(cap) = client.max_hours_per_period.map(hours_to_minutes) {
+        let mut used = 0u32;
+        for day in days.iter_mut() {
+            trim_day(day, (used + day.billed_minutes).saturating_sub(cap));
+            used += day.billed_minutes;
+        }
+    }
+}
+
+pub(crate) fn hours_to_minutes(hours: f64) -> u32 {
+    (hours.max(0.0) * 60.0).round() as u32
+}
+
+fn trim_day(day: &mut DayReport, excess: u32) {
+    let excess = excess.min(day.billed_minutes);
+    day.billed_minutes -= excess;
+    day.overflow_minutes += excess;
+}
+
+/// Add (or remove) `delta` billed minutes starting from the last day backwards.
+/// Minutes are only removed down to `floor`.
+fn adjust_tail(days: &mut [DayReport], mut delta: i64, floor: u32) {
+    if delta > 0 {
+        if let Some(last) = days.last_mut() {
+            last.billed_minutes += delta as u32;
+        }
+        return;
+    }
+    for day in days.iter_mut().rev() {
+        if delta == 0 {
+            break;
+        }
+        let take = (-delta).min(day.billed_minutes.saturating_sub(floor) as i64);
+        day.billed_minutes -= take as u32;
+        delta += take;
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{Duration, NaiveDate};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use crate::config::BudgetConfig;
+use super::billing::hours_to_minutes;
+use super::types::DayReport;
+
+/// Number of calendar days used to compute the recent burn rate
+pub const BURN_WINDOW_DAYS: i64 = 28;
+
+/// Consumption of a budget up to a given day
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetStatus {
+    pub name: String,
+    pub start: NaiveDate,
+    pub limit_minutes: Option<u32>,
+    pub limit_amount: Option<Decimal>,
+    pub consumed_minutes: u32,
+    pub consumed_amount: Decimal,
+    /// Billed minutes per calendar day over the recent window
+    pub burn_minutes_per_day: f64,
+    /// Amount per calendar day over the recent window
+    pub burn_amount_per_day: Decimal,
+    /// Day the budget runs out at the current burn rate (or ran out, if already exceeded)
+    pub projected_exhaustion: Option<NaiveDate>,
+    pub alert_threshold: f64,
+}
+
+impl BudgetStatus {
+    pub fn remaining_minutes(&self) -> Option<i64> {
+        self.limit_minutes.map(|l| l as i64 - self.consumed_minutes as i64)
+    }
+
+    pub fn remaining_amount(&self) -> Option<Decimal> {
+        self.limit_amount.map(|l| l - self.consumed_amount)
+    }
+
+    /// Consumed fraction of the budget; the larger of hours and money when both are set
+    pub fn fraction_used(&self) -> f64 {
+        let by_hours = self
+            .limit_minutes
+            .filter(|l| *l > 0)
+            .map(|l| self.consumed_minutes as f64 / l as f64);
+        let by_amount = self
+            .limit_amount
+            .filter(|l| *l > Decimal::ZERO)
+            .and_then(|l| (self.consumed_amount / l).to_f64());
+        by_hours.into_iter().chain(by_amount).fold(0.0, f64::max)
+    }
+
+    pub fn over_threshold(&self) -> bool {
+        self.fraction_used() >= self.alert_threshold
+    }
+}
+
+/// Compute how much of `budget` the billed `days` consume up to `today`.
+///
+/// Only days from `budget.start` onwards count. The burn rate averages the last
+/// `BURN_WINDOW_DAYS` calendar days (or fewer if the budget is younger), and the
+/// exhaustion date projects the remaining budget at that rate.
+pub fn budget_status(budget: &BudgetConfig, days: &[DayReport], today: NaiveDate) -> BudgetStatus {
+    let limit_minutes = budget.hours.map(hours_to_minutes);
+    let limit_amount = budget.amount;
+
+    let counted: Vec<&DayReport> = days
+        .iter()
+        .filter(|d| d.date >= budget.start && d.date <= today)
+        .collect();
+
+    // First day at which the cumulative consumption reached a limit
+    let mut exhausted_on = None;
+    let (mut minutes, mut amount) = (0u32, Decimal::ZERO);
+    for day in &counted {
+        minutes += day.billed_minutes;
+        amount += day.amount();
+        let hit_hours = limit_minutes.is_some_and(|l| minutes >= l);
+        let hit_amount = limit_amount.is_some_and(|l| amount >= l);
+        if exhausted_on.is_none() && (hit_hours || hit_amount) {
+            exhausted_on = Some(day.date);
+        }
+    }
+
+    let window_start = (today - Duration::days(BURN_WINDOW_DAYS - 1)).max(budget.start);
+    let window_days = ((today - window_start).num_days() + 1).max(1);
+    let recent = counted.iter().filter(|d| d.date >= window_start);
+    let (recent_minutes, recent_amount) = recent.fold((0u32, Decimal::ZERO), |(m, a), d| {
+        (m + d.billed_minutes, a + d.amount())
+    });
+
+    let mut status = BudgetStatus {
+        name: budget.name.clone(),
+        start: budget.start,
+        limit_minutes,
+        limit_amount,
+        consumed_minutes: minutes,
+        consumed_amount: amount,
+        burn_minutes_per_day: recent_minutes as f64 / window_days as f64,
+        burn_amount_per_day: recent_amount / Decimal::from(window_days),
+        projected_exhaustion: exhausted_on,
+        alert_threshold: budget.alert_threshold,
+    };
+
+    if status.projected_exhaustion.is_none() {
+        let days_by_hours = status
+            .remaining_minutes()
+            .filter(|_| status.burn_minutes_per_day > 0.0)
+            .map(|r| r as f64 / status.burn_minutes_per_day);
+        let days_by_amount = status
+            .remaining_amount()
+            .filter(|_| status.burn_amount_per_day > Decimal::ZERO)
+            .and_then(|r| (r / status.burn_amount_per_day).to_f64());
+        status.projected_exhaustion = days_by_hours
+            .into_iter()
+            .chain(days_by_amount)
+            .reduce(f64::min)
+            .map(|d| today + Duration::days(d.ceil() as i64));
+    }
+
+    status
+}
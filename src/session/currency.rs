@@ -0,0 +1,188 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use crate::errors::GttError;
+use crate::i18n::tf;
+use super::invoice::round_money;
+use super::llm::usage_date;
+use super::types::ClientReport;
+
+/// Which date's exchange rate converts a report's amounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateDate {
+    /// One rate for the whole report, taken on the invoice date (period end)
+    Invoice,
+    /// Each day converted at that day's rate
+    Day,
+}
+
+/// One row of the exchange-rate table: 1 `from` = `rate` `to` from `date` on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRate {
+    pub date: NaiveDate,
+    pub from: String,
+    pub to: String,
+    pub rate: Decimal,
+}
+
+/// Offline exchange-rate table, loaded from a local CSV file
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeRates {
+    pub rates: Vec<ExchangeRate>,
+}
+
+impl ExchangeRates {
+    /// Parse a CSV with the header `date,from,to,rate` (dates as YYYY-MM-DD).
+    pub fn parse(data: &str) -> Result<Self, GttError> {
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let mut rates = Vec::new();
+        for (i, record) in reader.records().enumerate() {
+            let line = i + 2;
+            let record = record.map_err(|e| GttError::ExchangeRateParse(tf("rates.line", &[("line", &line), ("error", &e)])))?;
+            if record.len() < 4 {
+                return Err(GttError::ExchangeRateParse(tf("rates.columns", &[("line", &line)])));
+            }
+            let date = NaiveDate::parse_from_str(record[0].trim(), "%Y-%m-%d")
+                .map_err(|_| GttError::ExchangeRateParse(tf("rates.bad_date", &[("line", &line), ("value", &&record[0])])))?;
+            let rate: Decimal = record[3]
+                .trim()
+                .parse()
+                .ok()
+                .filter(|r: &Decimal| *r > Decimal::ZERO)
+                .ok_or_else(|| GttError::ExchangeRateParse(tf("rates.bad_rate", &[("line", &line), ("value", &&record[3])])))?;
+            rates.push(ExchangeRate {
+                date,
+                from: record[1].trim().to_uppercase(),
+                to: record[2].trim().to_uppercase(),
+                rate,
+            });
+        }
+        Ok(Self { rates })
+    }
+
+    /// Rate converting 1 `from` into `to` on `date`: the latest entry for the pair
+    /// dated on or before `date`. The inverse pair is used when only that one is
+    /// listed; the same currency always converts at 1.
+    pub fn rate_on(&self, from: &str, to: &str, date: NaiveDate) -> Option<Decimal> {
+        let (from, to) = (from.to_uppercase(), to.to_uppercase());
+        if from == to {
+            return Some(Decimal::ONE);
+        }
+        self.rates
+            .iter()
+            .filter(|r| r.date <= date)
+            .filter_map(|r| {
+                if r.from == from && r.to == to {
+                    Some((r.date, r.rate))
+                } else if r.from == to && r.to == from {
+                    Some((r.date, Decimal::ONE / r.rate))
+                } else {
+                    None
+                }
+            })
+            .max_by_key(|(d, _)| *d)
+            .map(|(_, rate)| rate)
+    }
+
+    /// Like `rate_on`, but a missing rate is an error
+    pub fn require(&self, from: &str, to: &str, date: NaiveDate) -> Result<Decimal, GttError> {
+        self.rate_on(from, to, date).ok_or_else(|| {
+            GttError::ExchangeRateMissing(tf("rates.missing", &[("from", &from), ("to", &to), ("date", &date)]))
+        })
+    }
+}
+
+/// A report's amounts converted into another currency
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    pub currency: String,
+    pub rate_date: RateDate,
+    /// Rate used for each day, in the same order as `ClientReport::days`
+    pub day_rates: Vec<Decimal>,
+    pub billable_amount: Decimal,
+    pub covered_amount: Decimal,
+    pub llm_cost: Decimal,
+    pub expenses: Decimal,
+    /// Invoice total after discounts and taxes
+    pub total_amount: Decimal,
+}
+
+impl Conversion {
+    pub fn overage_amount(&self) -> Decimal {
+        self.billable_amount - self.covered_amount
+    }
+
+    /// Converted subtotal, before discounts and taxes
+    pub fn subtotal(&self) -> Decimal {
+        self.overage_amount() + self.llm_cost + self.expenses
+    }
+
+    /// The single rate used for the report, when every day shares it
+    pub fn single_rate(&self) -> Option<Decimal> {
+        let first = *self.day_rates.first()?;
+        self.day_rates.iter().all(|r| *r == first).then_some(first)
+    }
+}
+
+/// Convert the report's amounts into `currency`.
+///
+/// With `RateDate::Invoice` every amount uses the rate on the period end; with
+/// `RateDate::Day` each day's amount, LLM usage and expenses use the rate on their own day.
+/// Discounts and taxes keep their share of the total, so the converted total is
+/// the invoice total scaled by the converted-to-original subtotal ratio.
+pub fn convert_report(
+    report: &ClientReport,
+    rates: &ExchangeRates,
+    currency: &str,
+    rate_date: RateDate,
+) -> Result<Conversion, GttError> {
+    let currency = currency.to_uppercase();
+    let invoice_rate = match rate_date {
+        RateDate::Invoice => Some(rates.require(&report.currency, &currency, report.period_end)?),
+        RateDate::Day => None,
+    };
+
+    let mut conversion = Conversion {
+        currency,
+        rate_date,
+        day_rates: Vec::with_capacity(report.days.len()),
+        billable_amount: Decimal::ZERO,
+        covered_amount: Decimal::ZERO,
+        llm_cost: Decimal::ZERO,
+        expenses: Decimal::ZERO,
+        total_amount: Decimal::ZERO,
+    };
+    for day in &report.days {
+        let rate = match invoice_rate {
+            Some(rate) => rate,
+            None => rates.require(&report.currency, &conversion.currency, day.date)?,
+        };
+        conversion.day_rates.push(rate);
+        conversion.billable_amount += day.amount() * rate;
+        conversion.covered_amount += day.covered_amount() * rate;
+        if !report.has_llm_usage() {
+            conversion.llm_cost += report.day_llm_cost(day) * rate;
+        }
+    }
+    for usage in &report.llm_usage {
+        let rate = match invoice_rate {
+            Some(rate) => rate,
+            None => rates.require(&report.currency, &conversion.currency, usage_date(usage))?,
+        };
+        conversion.llm_cost += usage.cost * rate;
+    }
+    for expense in &report.expenses {
+        let rate = match invoice_rate {
+            Some(rate) => rate,
+            None => rates.require(&report.currency, &conversion.currency, expense.date)?,
+        };
+        conversion.expenses += expense.amount * rate;
+    }
+
+    let subtotal = report.subtotal();
+    conversion.total_amount = if subtotal.is_zero() {
+        Decimal::ZERO
+    } else {
+        round_money(report.total_amount() * conversion.subtotal() / subtotal)
+    };
+    Ok(conversion)
+}
@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+use chrono::{Datelike, NaiveDate, Weekday};
+use rust_decimal::Decimal;
+use super::billing::split_minutes;
+use super::types::{llm_cost, ClientReport, DayReport};
+
+/// Dimension the rows of a report are grouped by (`--group-by`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    #[default]
+    Day,
+    /// ISO week
+    Week,
+    Month,
+    /// Repository, splitting multi-repo sessions by `repo_attribution`
+    Repo,
+    /// Day of the week, Monday to Sunday
+    Weekday,
+}
+
+impl GroupBy {
+    pub const NAMES: &'static str = "day, week, month, repo, weekday";
+
+    pub fn parse(value: &str) -> Option<GroupBy> {
+        match value {
+            "day" => Some(GroupBy::Day),
+            "week" => Some(GroupBy::Week),
+            "month" => Some(GroupBy::Month),
+            "repo" => Some(GroupBy::Repo),
+            "weekday" => Some(GroupBy::Weekday),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GroupBy::Day => "day",
+            GroupBy::Week => "week",
+            GroupBy::Month => "month",
+            GroupBy::Repo => "repo",
+            GroupBy::Weekday => "weekday",
+        }
+    }
+}
+
+/// Key of one group. Time keys sort chronologically, weekdays from Monday.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GroupKey {
+    Day(NaiveDate),
+    Week { year: i32, week: u32 },
+    Month { year: i32, month: u32 },
+    /// Days from Monday (0) to Sunday (6)
+    Weekday(u32),
+    /// Repository name; empty for manual time without a repo
+    Repo(String),
+}
+
+impl GroupKey {
+    /// Group of a whole day; repos split days instead
+    fn for_date(by: GroupBy, date: NaiveDate) -> Option<GroupKey> {
+        match by {
+            GroupBy::Day => Some(GroupKey::Day(date)),
+            GroupBy::Week => {
+                let iw = date.iso_week();
+                Some(GroupKey::Week { year: iw.year(), week: iw.week() })
+            }
+            GroupBy::Month => Some(GroupKey::Month { year: date.year(), month: date.month() }),
+            GroupBy::Weekday => Some(GroupKey::Weekday(date.weekday().num_days_from_monday())),
+            GroupBy::Repo => None,
+        }
+    }
+
+    /// Stable identifier for machine-readable output: `2026-01-05`, `2026-W02`,
+    /// `2026-01`, `monday` or the repo name
+    pub fn id(&self) -> String {
+        match self {
+            GroupKey::Day(date) => date.format("%Y-%m-%d").to_string(),
+            GroupKey::Week { year, week } => format!("{}-W{:02}", year, week),
+            GroupKey::Month { year, month } => format!("{}-{:02}", year, month),
+            GroupKey::Weekday(days) => weekday_id(*days).to_string(),
+            GroupKey::Repo(name) => name.clone(),
+        }
+    }
+
+    /// First day of a day, week or month group
+    pub fn start(&self) -> Option<NaiveDate> {
+        match *self {
+            GroupKey::Day(date) => Some(date),
+            GroupKey::Week { year, week } => NaiveDate::from_isoywd_opt(year, week, Weekday::Mon),
+            GroupKey::Month { year, month } => NaiveDate::from_ymd_opt(year, month, 1),
+            GroupKey::Weekday(_) | GroupKey::Repo(_) => None,
+        }
+    }
+}
+
+fn weekday_id(days_from_monday: u32) -> &'static str {
+    ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"][days_from_monday as usize % 7]
+}
+
+/// Aggregated figures of the days (or parts of days) in one group
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub key: GroupKey,
+    /// Days with activity
+    pub days: usize,
+    pub sessions: usize,
+    pub total_minutes: u32,
+    pub billed_minutes: u32,
+    pub overflow_minutes: u32,
+    pub covered_minutes: u32,
+    pub total_commits: usize,
+    pub lines_added: u32,
+    pub lines_deleted: u32,
+    pub repos: Vec<String>,
+    /// Billed time at the days' rates, before retainer coverage
+    pub amount: Decimal,
+    pub covered_amount: Decimal,
+    pub llm_cost: Decimal,
+    /// Total amount in the `--in-currency` currency, at each day's rate
+    pub converted_total: Option<Decimal>,
+}
+
+impl Group {
+    pub fn total_hours(&self) -> f64 {
+        self.total_minutes as f64 / 60.0
+    }
+
+    pub fn billed_hours(&self) -> f64 {
+        self.billed_minutes as f64 / 60.0
+    }
+
+    /// Billable overage plus LLM cost, as `ClientReport::day_total_amount`
+    pub fn total_amount(&self) -> Decimal {
+        self.amount - self.covered_amount + self.llm_cost
+    }
+
+    fn merge(&mut self, other: Group) {
+        self.days += other.days;
+        self.sessions += other.sessions;
+        self.total_minutes += other.total_minutes;
+        self.billed_minutes += other.billed_minutes;
+        self.overflow_minutes += other.overflow_minutes;
+        self.covered_minutes += other.covered_minutes;
+        self.total_commits += other.total_commits;
+        self.lines_added += other.lines_added;
+        self.lines_deleted += other.lines_deleted;
+        self.amount += other.amount;
+        self.covered_amount += other.covered_amount;
+        self.llm_cost += other.llm_cost;
+        self.converted_total = match (self.converted_total, other.converted_total) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        for repo in other.repos {
+            if !self.repos.contains(&repo) {
+                self.repos.push(repo);
+            }
+        }
+        self.repos.sort();
+    }
+}
+
+/// Aggregate the report's days by `by`. Every figure of a day goes to its
+/// group; grouping by repo splits each day between its repos instead (see
+/// `repo_parts`). Repo groups are sorted by time, largest first.
+pub fn group_report(report: &ClientReport, by: GroupBy) -> Vec<Group> {
+    let mut groups: BTreeMap<GroupKey, Group> = BTreeMap::new();
+    for (i, day) in report.days.iter().enumerate() {
+        let rate = report.converted.as_ref().map(|conv| conv.day_rates[i]);
+        let parts = match GroupKey::for_date(by, day.date) {
+            Some(key) => vec![whole_day(report, day, key)],
+            None => repo_parts(report, day),
+        };
+        for mut part in parts {
+            part.converted_total = rate.map(|rate| part.total_amount() * rate);
+            match groups.get_mut(&part.key) {
+                Some(group) => group.merge(part),
+                None => {
+                    groups.insert(part.key.clone(), part);
+                }
+            }
+        }
+    }
+    let mut groups: Vec<Group> = groups.into_values().collect();
+    if by == GroupBy::Repo {
+        groups.sort_by(|a, b| b.total_minutes.cmp(&a.total_minutes).then_with(|| a.key.cmp(&b.key)));
+    }
+    groups
+}
+
+fn whole_day(report: &ClientReport, day: &DayReport, key: GroupKey) -> Group {
+    Group {
+        key,
+        days: 1,
+        sessions: day.sessions.len(),
+        total_minutes: day.total_minutes,
+        billed_minutes: day.billed_minutes,
+        overflow_minutes: day.overflow_minutes,
+        covered_minutes: day.covered_minutes,
+        total_commits: day.total_commits,
+        lines_added: day.total_lines_added,
+        lines_deleted: day.total_lines_deleted,
+        repos: day.repos.clone(),
+        amount: day.amount(),
+        covered_amount: day.covered_amount(),
+        llm_cost: report.day_llm_cost(day),
+        converted_total: None,
+    }
+}
+
+/// One part of the day per repo, from the day's attributed `repo_minutes`.
+/// Billed minutes and amounts come from the repo's minutes in each rate
+/// category, covered minutes follow the billed ones and overflow follows each
+/// repo's share of the time. Commits and lines are counted exactly.
+fn repo_parts(report: &ClientReport, day: &DayReport) -> Vec<Group> {
+    // Without any minutes (a zero-length day) the repos share evenly
+    let even = day.repo_minutes.iter().all(|r| r.minutes == 0);
+    let weights: Vec<(usize, u64)> =
+        day.repo_minutes.iter().enumerate().map(|(i, r)| (i, if even { 1 } else { r.minutes as u64 })).collect();
+    let split = |total: u32| split_minutes(total, &weights).into_iter().map(|(_, m)| m).collect::<Vec<u32>>();
+    let billed: Vec<u32> = if day.categories.is_empty() {
+        split(day.billed_minutes)
+    } else {
+        day.repo_minutes.iter().map(|r| day.categories.iter().map(|c| c.repo_minutes(&r.repo)).sum()).collect()
+    };
+    let overflow = split(day.overflow_minutes);
+    let billed_weights: Vec<(usize, u64)> = billed.iter().enumerate().map(|(i, m)| (i, *m as u64)).collect();
+    let mut covered = vec![0; billed.len()];
+    for (i, minutes) in split_minutes(day.covered_minutes, &billed_weights) {
+        covered[i] = minutes;
+    }
+    let day_llm = report.day_llm_cost(day);
+
+    day.repo_minutes
+        .iter()
+        .enumerate()
+        .map(|(i, share)| {
+            let repo = &share.repo;
+            let fraction = if day.total_minutes > 0 {
+                Decimal::from(share.minutes) / Decimal::from(day.total_minutes)
+            } else {
+                Decimal::ONE / Decimal::from(day.repo_minutes.len())
+            };
+            let sessions = day
+                .sessions
+                .iter()
+                .filter(|s| if repo.is_empty() { s.repos.is_empty() } else { s.repos.contains(repo) })
+                .count();
+            let commits = day.sessions.iter().flat_map(|s| &s.commits).filter(|c| &c.repo_name == repo);
+            let (count, added, deleted) =
+                commits.fold((0, 0, 0), |(n, a, d), c| (n + 1, a + c.lines_added, d + c.lines_deleted));
+            let llm = if report.has_llm_usage() {
+                day_llm * fraction
+            } else {
+                llm_cost(added + deleted, report.llm_cost_per_kloc)
+            };
+            let amount: Decimal = day.categories.iter().map(|c| c.repo_amount(repo)).sum();
+            let covered_amount = if billed[i] > 0 {
+                amount * Decimal::from(covered[i]) / Decimal::from(billed[i])
+            } else {
+                Decimal::ZERO
+            };
+            Group {
+                key: GroupKey::Repo(repo.clone()),
+                days: 1,
+                sessions,
+                total_minutes: share.minutes,
+                billed_minutes: billed[i],
+                overflow_minutes: overflow[i],
+                covered_minutes: covered[i],
+                total_commits: count,
+                lines_added: added,
+                lines_deleted: deleted,
+                repos: if repo.is_empty() { Vec::new() } else { vec![repo.clone()] },
+                amount,
+                covered_amount,
+                llm_cost: llm,
+                converted_total: None,
+            }
+        })
+        .collect()
+}
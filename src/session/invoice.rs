@@ -0,0 +1,90 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use crate::config::{DiscountRule, TaxRule};
+
+/// One discount or tax line of an invoice. Amounts are signed: discounts and
+/// withholding taxes are negative, added taxes positive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceLine {
+    pub name: String,
+    /// Percentage the line was computed from, if any
+    pub percent: Option<Decimal>,
+    pub amount: Decimal,
+}
+
+/// Invoice breakdown: subtotal → discounts → taxes → total
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invoice {
+    pub subtotal: Decimal,
+    pub discounts: Vec<InvoiceLine>,
+    pub taxes: Vec<InvoiceLine>,
+}
+
+impl Invoice {
+    pub fn discount_total(&self) -> Decimal {
+        self.discounts.iter().map(|l| l.amount).sum()
+    }
+
+    /// Subtotal after discounts, the base every tax is computed on
+    pub fn taxable_base(&self) -> Decimal {
+        self.subtotal + self.discount_total()
+    }
+
+    pub fn tax_total(&self) -> Decimal {
+        self.taxes.iter().map(|l| l.amount).sum()
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.taxable_base() + self.tax_total()
+    }
+
+    /// Whether the invoice has anything beyond the subtotal
+    pub fn has_adjustments(&self) -> bool {
+        !self.discounts.is_empty() || !self.taxes.is_empty()
+    }
+}
+
+/// Round to cents, halves away from zero (as on paper invoices).
+pub fn round_money(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Apply `discounts` and then `taxes` to `subtotal`.
+///
+/// Percentage discounts are taken from the subtotal (they do not compound) and
+/// discounts never take the base below zero. Every tax is computed on the
+/// discounted base; withholding taxes are subtracted. Each line is rounded to cents.
+pub fn compute_invoice(subtotal: Decimal, discounts: &[DiscountRule], taxes: &[TaxRule]) -> Invoice {
+    let subtotal = round_money(subtotal);
+    let hundred = Decimal::ONE_HUNDRED;
+
+    let mut base = subtotal;
+    let mut discount_lines = Vec::new();
+    for rule in discounts {
+        let by_percent = rule.percent.map_or(Decimal::ZERO, |p| subtotal * p / hundred);
+        let amount = round_money(by_percent + rule.amount.unwrap_or_default()).min(base.max(Decimal::ZERO));
+        base -= amount;
+        discount_lines.push(InvoiceLine {
+            name: rule.name.clone(),
+            percent: rule.percent,
+            amount: -amount,
+        });
+    }
+
+    let tax_lines = taxes
+        .iter()
+        .map(|rule| {
+            let amount = round_money(base * rule.percent / hundred);
+            InvoiceLine {
+                name: rule.name.clone(),
+                percent: Some(rule.percent),
+                amount: if rule.withholding { -amount } else { amount },
+            }
+        })
+        .collect();
+
+    Invoice {
+        subtotal,
+        discounts: discount_lines,
+        taxes: tax_lines,
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{Duration, Local, NaiveDate};
+use crate::store::LlmUsageEntry;
+use super::types::DayReport;
+
+/// Local calendar date of a usage entry
+pub fn usage_date(entry: &LlmUsageEntry) -> NaiveDate {
+    entry.timestamp.with_timezone(&Local).date_naive()
+}
+
+/// Select the imported usage that belongs to a client's report.
+///
+/// Tagged entries belong to the client when the tag is the client's name or one
+/// of its `llm_tags` (case-insensitive) and the entry falls within the period.
+/// Untagged entries are matched by time: they belong to the client when they fall
+/// inside one of its sessions (from `end - duration` to the last commit). An
+/// untagged entry inside overlapping sessions of two clients counts for both, so
+/// tag usage when working for several clients at once.
+pub fn attribute_usage(
+    entries: &[LlmUsageEntry],
+    client_name: &str,
+    tags: &[String],
+    days: &[DayReport],
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Vec<LlmUsageEntry> {
+    let matches_tag = |tag: &str| tag.eq_ignore_ascii_case(client_name) || tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+    entries
+        .iter()
+        .filter(|entry| match entry.tag {
+            Some(ref tag) => matches_tag(tag) && (since..=until).contains(&usage_date(entry)),
+            None => days.iter().flat_map(|d| &d.sessions).any(|s| {
+                let start = s.end - Duration::minutes(s.duration_minutes as i64);
+                start <= entry.timestamp && entry.timestamp <= s.end
+            }),
+        })
+        .cloned()
+        .collect()
+}
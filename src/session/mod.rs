@@ -1,6 +1,27 @@
 pub mod analyzer;
+pub mod attribution;
+pub mod billing;
+pub mod budget;
+pub mod currency;
+pub mod grouping;
+pub mod invoice;
+pub mod llm;
+pub mod receivables;
+pub mod retainer;
 pub mod types;
 
-pub use analyzer::{analyze, group_by_day};
 #[allow(unused_imports)]
-pub use types::{ClientReport, DayReport, Session};
+pub use analyzer::{analyze, group_by_day, trim_manual_overlaps};
+#[allow(unused_imports)]
+pub use attribution::attribute_repos;
+#[allow(unused_imports)]
+pub use billing::{apply_caps, apply_rates, apply_rounding, bill_by_month, bill_sessions};
+pub use currency::{convert_report, ExchangeRates, RateDate};
+#[allow(unused_imports)]
+pub use grouping::{group_report, Group, GroupBy, GroupKey};
+#[allow(unused_imports)]
+pub use invoice::{compute_invoice, Invoice, InvoiceLine};
+pub use llm::attribute_usage;
+pub use retainer::{apply_retainer, RetainerPeriod};
+#[allow(unused_imports)]
+pub use types::{ClientReport, DayCategory, DayReport, RateSubtotal, RepoMinutes, Session};
@@ -0,0 +1,103 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use crate::store::InvoiceRecord;
+
+/// Age of an unpaid invoice, counted in days since it was issued
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgingBucket {
+    /// 0 to 30 days
+    Current,
+    /// 31 to 60 days
+    Days31To60,
+    /// 61 days or more
+    Days61Plus,
+}
+
+impl AgingBucket {
+    pub const ALL: [AgingBucket; 3] = [AgingBucket::Current, AgingBucket::Days31To60, AgingBucket::Days61Plus];
+
+    pub fn for_age(days: i64) -> Self {
+        match days {
+            ..=30 => AgingBucket::Current,
+            31..=60 => AgingBucket::Days31To60,
+            _ => AgingBucket::Days61Plus,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgingBucket::Current => "0-30",
+            AgingBucket::Days31To60 => "31-60",
+            AgingBucket::Days61Plus => "61-90+",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            AgingBucket::Current => 0,
+            AgingBucket::Days31To60 => 1,
+            AgingBucket::Days61Plus => 2,
+        }
+    }
+}
+
+/// An invoice with money still owed
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenInvoice {
+    pub invoice: InvoiceRecord,
+    pub age_days: i64,
+    pub bucket: AgingBucket,
+}
+
+/// Outstanding invoices of one client in one currency
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientReceivables {
+    pub client: String,
+    pub currency: String,
+    /// Oldest first
+    pub invoices: Vec<OpenInvoice>,
+}
+
+impl ClientReceivables {
+    /// Outstanding amount in each bucket, in `AgingBucket::ALL` order
+    pub fn bucket_totals(&self) -> [Decimal; 3] {
+        let mut totals = [Decimal::ZERO; 3];
+        for open in &self.invoices {
+            totals[open.bucket.index()] += open.invoice.outstanding();
+        }
+        totals
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.invoices.iter().map(|o| o.invoice.outstanding()).sum()
+    }
+}
+
+/// Group the unpaid invoices by client and currency and age them as of `today`.
+pub fn receivables(invoices: &[InvoiceRecord], today: NaiveDate) -> Vec<ClientReceivables> {
+    let mut result: Vec<ClientReceivables> = Vec::new();
+    for invoice in invoices.iter().filter(|i| !i.is_paid()) {
+        let age_days = (today - invoice.issued).num_days().max(0);
+        let open = OpenInvoice {
+            invoice: invoice.clone(),
+            age_days,
+            bucket: AgingBucket::for_age(age_days),
+        };
+        match result
+            .iter_mut()
+            .find(|r| r.client == invoice.client && r.currency == invoice.currency)
+        {
+            Some(group) => group.invoices.push(open),
+            None => result.push(ClientReceivables {
+                client: invoice.client.clone(),
+                currency: invoice.currency.clone(),
+                invoices: vec![open],
+            }),
+        }
+    }
+    for group in result.iter_mut() {
+        group.invoices.sort_by_key(|o| (o.invoice.issued, o.invoice.number.clone()));
+    }
+    result.sort_by(|a, b| (&a.client, &a.currency).cmp(&(&b.client, &b.currency)));
+    result
+}
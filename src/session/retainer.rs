@@ -0,0 +1,117 @@
+use chrono::{Datelike, NaiveDate};
+use crate::config::RetainerConfig;
+use super::billing::hours_to_minutes;
+use super::types::DayReport;
+
+/// Retainer usage for one calendar month
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetainerPeriod {
+    /// First day of the month
+    pub month: NaiveDate,
+    /// Hours included by the retainer for this month
+    pub included_minutes: u32,
+    /// Unused hours carried over from the previous month
+    pub rollover_in_minutes: u32,
+    /// Prepaid hours bought during this month
+    pub prepaid_added_minutes: u32,
+    /// Billed minutes in the month
+    pub used_minutes: u32,
+    /// Billed minutes paid for by the retainer or prepaid hours
+    pub covered_minutes: u32,
+    /// Billed minutes beyond the retainer and prepaid hours
+    pub overage_minutes: u32,
+    /// Monthly hours left unused at the end of the month
+    pub unused_minutes: u32,
+    /// Unused hours carried into the next month
+    pub rollover_out_minutes: u32,
+    /// Prepaid hours left at the end of the month
+    pub prepaid_balance_minutes: u32,
+}
+
+impl RetainerPeriod {
+    /// Hours still available at the end of the month (monthly + prepaid)
+    pub fn remaining_minutes(&self) -> u32 {
+        self.unused_minutes + self.prepaid_balance_minutes
+    }
+}
+
+/// Walk the retainer month by month from `retainer.start` to `until`, consuming
+/// each day's billed minutes in chronological order: first the monthly hours
+/// (plus rollover), then prepaid blocks. Sets `covered_minutes` on every day and
+/// returns the per-month balance.
+///
+/// `days` must be sorted by date, as returned by `group_by_day`.
+pub fn apply_retainer(days: &mut [DayReport], retainer: &RetainerConfig, until: NaiveDate) -> Vec<RetainerPeriod> {
+    let included = hours_to_minutes(retainer.hours_per_month);
+    let max_rollover = retainer.max_rollover_hours.map(hours_to_minutes);
+
+    let mut periods = Vec::new();
+    let mut rollover_in = 0u32;
+    let mut prepaid_balance = 0u32;
+    let mut month = first_of_month(retainer.start);
+
+    while month <= until {
+        let next = next_month(month);
+        let prepaid_added: u32 = retainer
+            .prepaid
+            .iter()
+            .filter(|b| b.date >= month && b.date < next)
+            .map(|b| hours_to_minutes(b.hours))
+            .sum();
+        prepaid_balance += prepaid_added;
+
+        let mut monthly_left = included + rollover_in;
+        let mut used = 0u32;
+        let mut covered = 0u32;
+
+        for day in days.iter_mut() {
+            if day.date < month.max(retainer.start) || day.date >= next {
+                continue;
+            }
+            let from_monthly = day.billed_minutes.min(monthly_left);
+            monthly_left -= from_monthly;
+            let from_prepaid = (day.billed_minutes - from_monthly).min(prepaid_balance);
+            prepaid_balance -= from_prepaid;
+
+            day.covered_minutes = from_monthly + from_prepaid;
+            used += day.billed_minutes;
+            covered += day.covered_minutes;
+        }
+
+        let rollover_out = if retainer.rollover {
+            max_rollover.map_or(monthly_left, |max| monthly_left.min(max))
+        } else {
+            0
+        };
+
+        periods.push(RetainerPeriod {
+            month,
+            included_minutes: included,
+            rollover_in_minutes: rollover_in,
+            prepaid_added_minutes: prepaid_added,
+            used_minutes: used,
+            covered_minutes: covered,
+            overage_minutes: used - covered,
+            unused_minutes: monthly_left,
+            rollover_out_minutes: rollover_out,
+            prepaid_balance_minutes: prepaid_balance,
+        });
+
+        rollover_in = rollover_out;
+        month = next;
+    }
+
+    periods
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap()
+}
+
+fn next_month(month: NaiveDate) -> NaiveDate {
+    if month.month() == 12 {
+        NaiveDate::from_ymd_opt(month.year() + 1, 1, 1).unwrap()
+    } else {
+        NaiveDate::from_ymd_opt(month.year(), month.month() + 1, 1).unwrap()
+    }
+}
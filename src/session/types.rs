@@ -1,5 +1,12 @@
 use chrono::{DateTime, FixedOffset, NaiveDate};
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use crate::config::{DiscountRule, TaxRule};
 use crate::git::Commit;
+use crate::store::{Expense, LlmUsageEntry};
+use super::currency::Conversion;
+use super::invoice::{compute_invoice, Invoice};
+use super::llm::usage_date;
 
 #[derive(Debug, Clone)]
 pub struct Session {
@@ -10,6 +17,23 @@ pub struct Session {
     pub repos: Vec<String>,
     pub lines_added: u32,
     pub lines_deleted: u32,
+    /// Description of a manually entered session (git sessions have none)
+    pub note: Option<String>,
+    /// How `duration_minutes` was built
+    pub parts: DurationParts,
+}
+
+/// Components of a session's duration, which add up to `duration_minutes`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DurationParts {
+    /// `first_commit_minutes` credited before the first commit
+    pub base: u32,
+    /// Minutes between consecutive commits
+    pub gap: u32,
+    /// Volume adjustment for the lines changed
+    pub volume: u32,
+    /// Time entered manually (`gtt time import`)
+    pub manual: u32,
 }
 
 impl Session {
@@ -21,6 +45,14 @@ impl Session {
     pub fn date(&self) -> NaiveDate {
         self.start.date_naive()
     }
+
+    /// The manual entry's note, or the commit subjects
+    pub fn description(&self) -> String {
+        match self.note {
+            Some(ref note) => note.clone(),
+            None => self.commits.iter().map(|c| c.subject.as_str()).collect::<Vec<_>>().join("; "),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -28,16 +60,97 @@ pub struct DayReport {
     pub date: NaiveDate,
     pub sessions: Vec<Session>,
     pub total_minutes: u32,
+    /// Minutes billed after applying the client's rounding policy
+    pub billed_minutes: u32,
+    /// Minutes removed from billing by the client's hour caps (unbilled overflow)
+    pub overflow_minutes: u32,
+    /// Hourly rate in effect on this date
+    pub hourly_rate: Decimal,
+    /// Billed minutes split by rate category (filled by `apply_rates`)
+    pub categories: Vec<DayCategory>,
+    /// Billed minutes covered by a retainer or prepaid hours
+    pub covered_minutes: u32,
     pub total_commits: usize,
     pub repos: Vec<String>,
+    /// Session minutes split between the repos (see `attribute_repos`)
+    pub repo_minutes: Vec<RepoMinutes>,
     pub total_lines_added: u32,
     pub total_lines_deleted: u32,
 }
 
+/// Minutes of a day attributed to one repo; `""` for manual time without a repo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoMinutes {
+    pub repo: String,
+    pub minutes: u32,
+}
+
 impl DayReport {
     pub fn total_hours(&self) -> f64 {
         self.total_minutes as f64 / 60.0
     }
+
+    pub fn billed_hours(&self) -> f64 {
+        self.billed_minutes as f64 / 60.0
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.categories.iter().map(|c| c.amount()).sum()
+    }
+
+    /// Value of the minutes covered by a retainer, at the day's average billed rate
+    pub fn covered_amount(&self) -> Decimal {
+        if self.billed_minutes == 0 {
+            return Decimal::ZERO;
+        }
+        self.amount() * Decimal::from(self.covered_minutes) / Decimal::from(self.billed_minutes)
+    }
+}
+
+/// Billed minutes of one rate category within a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayCategory {
+    pub name: String,
+    pub hourly_rate: Decimal,
+    pub billed_minutes: u32,
+    /// The billed minutes split between the repos of the category's commits
+    pub repos: Vec<RepoMinutes>,
+}
+
+impl DayCategory {
+    pub fn amount(&self) -> Decimal {
+        minutes_amount(self.billed_minutes, self.hourly_rate)
+    }
+
+    /// Billed minutes of the category in `repo`
+    pub fn repo_minutes(&self, repo: &str) -> u32 {
+        self.repos.iter().filter(|r| r.repo == repo).map(|r| r.minutes).sum()
+    }
+
+    /// Value of the category's minutes in `repo`
+    pub fn repo_amount(&self, repo: &str) -> Decimal {
+        minutes_amount(self.repo_minutes(repo), self.hourly_rate)
+    }
+}
+
+/// Value of `minutes` at an hourly `rate`, without rounding
+pub fn minutes_amount(minutes: u32, rate: Decimal) -> Decimal {
+    Decimal::from(minutes) * rate / Decimal::from(60)
+}
+
+/// Billed time and amount for one rate category at one hourly rate
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateSubtotal {
+    pub category: String,
+    pub hourly_rate: Decimal,
+    pub billed_minutes: u32,
+    pub amount: Decimal,
+}
+
+impl RateSubtotal {
+    pub fn billed_hours(&self) -> f64 {
+        self.billed_minutes as f64 / 60.0
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -47,15 +160,34 @@ pub struct ClientReport {
     pub period_end: NaiveDate,
     pub days: Vec<DayReport>,
     pub total_minutes: u32,
+    /// Minutes billed after rounding (always the sum of the days' billed minutes)
+    pub billed_minutes: u32,
+    /// Minutes over the client's hour caps, not billed
+    pub overflow_minutes: u32,
+    /// Billed minutes covered by a retainer or prepaid hours
+    pub covered_minutes: u32,
     pub total_commits: usize,
-    pub hourly_rate: f64,
+    /// Rate in effect at the end of the period (days may carry older rates)
+    pub hourly_rate: Decimal,
     pub currency: String,
     pub total_lines_added: u32,
     pub total_lines_deleted: u32,
-    /// Cost per 1000 lines changed to offset LLM usage (0.0 = disabled)
-    pub llm_cost_per_kloc: f64,
-    /// Total LLM cost for the period
-    pub llm_cost: f64,
+    /// Cost per 1000 lines changed to offset LLM usage (0 = disabled)
+    pub llm_cost_per_kloc: Decimal,
+    /// Imported LLM usage attributed to the period, in the client's currency
+    pub llm_usage: Vec<LlmUsageEntry>,
+    /// Total LLM cost for the period: imported usage when any, else the per-kloc estimate
+    pub llm_cost: Decimal,
+    /// Reimbursable expenses dated within the period
+    pub expenses: Vec<Expense>,
+    /// Discounts applied to the invoice subtotal
+    pub discounts: Vec<DiscountRule>,
+    /// Taxes applied to the discounted subtotal
+    pub tax_rules: Vec<TaxRule>,
+    /// Amounts converted into another currency (`--in-currency`)
+    pub converted: Option<Conversion>,
+    /// Commit URL template per repo name, for linking commits in documents
+    pub commit_urls: HashMap<String, String>,
 }
 
 impl ClientReport {
@@ -63,11 +195,116 @@ impl ClientReport {
         self.total_minutes as f64 / 60.0
     }
 
-    pub fn billable_amount(&self) -> f64 {
-        self.total_hours() * self.hourly_rate
+    pub fn billed_hours(&self) -> f64 {
+        self.billed_minutes as f64 / 60.0
+    }
+
+    /// Sum of the per-day amounts, each at the rate in effect on that day
+    pub fn billable_amount(&self) -> Decimal {
+        self.days.iter().map(|d| d.amount()).sum()
+    }
+
+    /// Value of the billed time covered by a retainer or prepaid hours
+    pub fn covered_amount(&self) -> Decimal {
+        self.days.iter().map(|d| d.covered_amount()).sum()
+    }
+
+    /// Billed minutes not covered by a retainer
+    pub fn overage_minutes(&self) -> u32 {
+        self.billed_minutes - self.covered_minutes
+    }
+
+    /// Value of the billed time not covered by a retainer
+    pub fn overage_amount(&self) -> Decimal {
+        self.billable_amount() - self.covered_amount()
+    }
+
+    /// Whether any rate applies to the period
+    pub fn has_rate(&self) -> bool {
+        self.hourly_rate > Decimal::ZERO || self.days.iter().any(|d| d.hourly_rate > Decimal::ZERO)
+    }
+
+    /// One line per (rate category, hourly rate), in chronological order of first use
+    pub fn rate_subtotals(&self) -> Vec<RateSubtotal> {
+        let mut subtotals: Vec<RateSubtotal> = Vec::new();
+        for cat in self.days.iter().flat_map(|d| &d.categories) {
+            match subtotals
+                .iter_mut()
+                .find(|s| s.category == cat.name && s.hourly_rate == cat.hourly_rate)
+            {
+                Some(sub) => {
+                    sub.billed_minutes += cat.billed_minutes;
+                    sub.amount += cat.amount();
+                }
+                None => subtotals.push(RateSubtotal {
+                    category: cat.name.clone(),
+                    hourly_rate: cat.hourly_rate,
+                    billed_minutes: cat.billed_minutes,
+                    amount: cat.amount(),
+                }),
+            }
+        }
+        subtotals
+    }
+
+    /// Whether the LLM cost comes from imported usage rather than the per-kloc estimate
+    pub fn has_llm_usage(&self) -> bool {
+        !self.llm_usage.is_empty()
+    }
+
+    /// Whether the report carries an LLM cost at all
+    pub fn shows_llm_cost(&self) -> bool {
+        self.has_llm_usage() || self.llm_cost_per_kloc > Decimal::ZERO
     }
 
-    pub fn total_amount(&self) -> f64 {
-        self.billable_amount() + self.llm_cost
+    /// LLM cost attributed to one day of the report. With imported usage this is
+    /// the usage dated that day, so tagged usage on days without commits is only
+    /// part of the report total.
+    pub fn day_llm_cost(&self, day: &DayReport) -> Decimal {
+        if self.has_llm_usage() {
+            return self
+                .llm_usage
+                .iter()
+                .filter(|u| usage_date(u) == day.date)
+                .map(|u| u.cost)
+                .sum();
+        }
+        llm_cost(day.total_lines_added + day.total_lines_deleted, self.llm_cost_per_kloc)
     }
+
+    /// Amount to invoice for one day, before discounts and taxes: billable overage plus LLM cost
+    pub fn day_total_amount(&self, day: &DayReport) -> Decimal {
+        day.amount() - day.covered_amount() + self.day_llm_cost(day)
+    }
+
+    pub fn expenses_total(&self) -> Decimal {
+        self.expenses.iter().map(|e| e.amount).sum()
+    }
+
+    /// Invoice subtotal: billable overage, LLM cost and expenses, before discounts and taxes
+    pub fn subtotal(&self) -> Decimal {
+        self.overage_amount() + self.llm_cost + self.expenses_total()
+    }
+
+    /// Subtotal, discount lines, tax lines and total
+    pub fn invoice(&self) -> Invoice {
+        compute_invoice(self.subtotal(), &self.discounts, &self.tax_rules)
+    }
+
+    /// Amount to invoice, after discounts and taxes
+    pub fn total_amount(&self) -> Decimal {
+        self.invoice().total()
+    }
+
+    /// Link to a commit, when its repo has a commit URL template
+    pub fn commit_url(&self, commit: &Commit) -> Option<String> {
+        self.commit_urls
+            .get(&commit.repo_name)
+            .map(|template| template.replace("{hash}", &commit.hash))
+    }
+}
+
+/// LLM cost of `lines` changed at `per_kloc` per 1000 lines
+pub fn llm_cost(lines: u32, per_kloc: Decimal) -> Decimal {
+    Decimal::from(lines) * per_kloc / Decimal::from(1000)
 }
@@ -0,0 +1,103 @@
+use std::path::Path;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::errors::GttError;
+use crate::i18n::tf;
+use super::data_path;
+
+const EXPENSES_FILE: &str = "expenses.toml";
+
+/// A reimbursable cost passed through to the client (hosting, domains, paid APIs)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Expense {
+    pub id: u32,
+    pub client: String,
+    pub date: NaiveDate,
+    /// Amount in the client's currency
+    pub amount: Decimal,
+    pub description: String,
+    /// Repo name the expense belongs to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+}
+
+/// Expenses recorded with `gtt expense add`, stored in `expenses.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExpenseStore {
+    #[serde(default, rename = "expense")]
+    pub expenses: Vec<Expense>,
+}
+
+impl ExpenseStore {
+    /// Load the store from the default location; missing file = empty store.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&data_path(EXPENSES_FILE)?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| tf("common.read_failed", &[("path", &path.display())]))?;
+        let store = toml::from_str(&content).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        Ok(store)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&data_path(EXPENSES_FILE)?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| tf("common.create_dir_failed", &[("path", &parent.display())]))?;
+        }
+        let content = toml::to_string_pretty(self).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        std::fs::write(path, content)
+            .with_context(|| tf("common.write_failed", &[("path", &path.display())]))?;
+        Ok(())
+    }
+
+    /// Add an expense with the next free id and return it
+    pub fn add(
+        &mut self,
+        client: &str,
+        date: NaiveDate,
+        amount: Decimal,
+        description: &str,
+        repo: Option<String>,
+    ) -> &Expense {
+        let id = self.expenses.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+        self.expenses.push(Expense {
+            id,
+            client: client.to_string(),
+            date,
+            amount,
+            description: description.to_string(),
+            repo,
+        });
+        self.expenses.last().unwrap()
+    }
+
+    /// Remove the expense with `id`; returns whether it existed
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.expenses.len();
+        self.expenses.retain(|e| e.id != id);
+        self.expenses.len() != before
+    }
+
+    /// The client's expenses dated within `since..=until`, oldest first
+    pub fn for_client(&self, client: &str, since: NaiveDate, until: NaiveDate) -> Vec<Expense> {
+        let mut found: Vec<Expense> = self
+            .expenses
+            .iter()
+            .filter(|e| e.client == client && e.date >= since && e.date <= until)
+            .cloned()
+            .collect();
+        found.sort_by_key(|e| (e.date, e.id));
+        found
+    }
+}
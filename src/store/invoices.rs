@@ -0,0 +1,131 @@
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::errors::GttError;
+use crate::i18n::{t, tf};
+use super::data_path;
+
+const INVOICES_FILE: &str = "invoices.toml";
+
+/// Payment received against an invoice
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Payment {
+    pub date: NaiveDate,
+    pub amount: Decimal,
+}
+
+/// An issued invoice, with the report total it was created from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceRecord {
+    pub number: String,
+    pub client: String,
+    pub issued: NaiveDate,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    /// Invoice total after discounts and taxes
+    pub amount: Decimal,
+    pub currency: String,
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+}
+
+impl InvoiceRecord {
+    pub fn paid(&self) -> Decimal {
+        self.payments.iter().map(|p| p.amount).sum()
+    }
+
+    pub fn outstanding(&self) -> Decimal {
+        (self.amount - self.paid()).max(Decimal::ZERO)
+    }
+
+    pub fn is_paid(&self) -> bool {
+        self.outstanding().is_zero()
+    }
+}
+
+/// Invoices registered with `gtt invoice create`, stored in `invoices.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InvoiceStore {
+    #[serde(default, rename = "invoice")]
+    pub invoices: Vec<InvoiceRecord>,
+}
+
+impl InvoiceStore {
+    /// Load the store from the default location; missing file = empty store.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&data_path(INVOICES_FILE)?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| tf("common.read_failed", &[("path", &path.display())]))?;
+        let store = toml::from_str(&content).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        Ok(store)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&data_path(INVOICES_FILE)?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| tf("common.create_dir_failed", &[("path", &parent.display())]))?;
+        }
+        let content = toml::to_string_pretty(self).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        std::fs::write(path, content)
+            .with_context(|| tf("common.write_failed", &[("path", &path.display())]))?;
+        Ok(())
+    }
+
+    pub fn get(&self, number: &str) -> Option<&InvoiceRecord> {
+        self.invoices.iter().find(|i| i.number == number)
+    }
+
+    /// Next sequential number for the year, as `YYYY-NNN`
+    pub fn next_number(&self, issued: NaiveDate) -> String {
+        let prefix = format!("{}-", issued.year());
+        let last = self
+            .invoices
+            .iter()
+            .filter_map(|i| i.number.strip_prefix(&prefix)?.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0);
+        format!("{}{:03}", prefix, last + 1)
+    }
+
+    pub fn add(&mut self, invoice: InvoiceRecord) -> Result<()> {
+        if self.get(&invoice.number).is_some() {
+            bail!(tf("invoice.exists", &[("number", &invoice.number)]));
+        }
+        self.invoices.push(invoice);
+        Ok(())
+    }
+
+    /// Record a payment; it may be partial but never more than what is outstanding.
+    pub fn record_payment(&mut self, number: &str, amount: Decimal, date: NaiveDate) -> Result<&InvoiceRecord> {
+        let Some(invoice) = self.invoices.iter_mut().find(|i| i.number == number) else {
+            bail!(tf("invoice.not_found", &[("number", &number)]));
+        };
+        if amount <= Decimal::ZERO {
+            bail!(t("common.amount_not_positive"));
+        }
+        if amount > invoice.outstanding() {
+            bail!(tf(
+                "invoice.overpaid",
+                &[
+                    ("amount", &format!("{} {}", amount, invoice.currency)),
+                    ("number", &number),
+                    ("outstanding", &format!("{} {}", invoice.outstanding(), invoice.currency)),
+                ]
+            ));
+        }
+        invoice.payments.push(Payment { date, amount });
+        Ok(invoice)
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::errors::GttError;
+use crate::i18n::tf;
+use super::data_path;
+
+const LLM_USAGE_FILE: &str = "llm_usage.toml";
+
+/// One billed usage record from an LLM provider export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LlmUsageEntry {
+    pub timestamp: DateTime<FixedOffset>,
+    pub cost: Decimal,
+    #[serde(default = "default_usage_currency")]
+    pub currency: String,
+    /// Parser or provider the entry came from (e.g. "openai")
+    pub provider: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Client name or one of its `llm_tags`; tagged entries skip time matching
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+fn default_usage_currency() -> String {
+    "USD".to_string()
+}
+
+/// Usage imported with `gtt llm import`, stored in `llm_usage.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LlmUsageStore {
+    #[serde(default, rename = "usage")]
+    pub entries: Vec<LlmUsageEntry>,
+}
+
+impl LlmUsageStore {
+    /// Load the store from the default location; missing file = empty store.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&data_path(LLM_USAGE_FILE)?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| tf("common.read_failed", &[("path", &path.display())]))?;
+        let store = toml::from_str(&content).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        Ok(store)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&data_path(LLM_USAGE_FILE)?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| tf("common.create_dir_failed", &[("path", &parent.display())]))?;
+        }
+        let content = toml::to_string_pretty(self).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        std::fs::write(path, content)
+            .with_context(|| tf("common.write_failed", &[("path", &path.display())]))?;
+        Ok(())
+    }
+
+    /// Add entries not already present (re-importing the same export is a no-op).
+    /// Returns how many were added.
+    pub fn import(&mut self, entries: Vec<LlmUsageEntry>) -> usize {
+        let before = self.entries.len();
+        for entry in entries {
+            if !self.entries.contains(&entry) {
+                self.entries.push(entry);
+            }
+        }
+        self.entries.len() - before
+    }
+}
@@ -0,0 +1,135 @@
+use std::path::Path;
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::errors::GttError;
+use crate::i18n::tf;
+use crate::session::types::{DurationParts, Session};
+use super::data_path;
+
+const MANUAL_TIME_FILE: &str = "manual_time.toml";
+
+/// Time worked outside git (calls, planning, research), merged into reports as a session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualEntry {
+    pub id: u32,
+    pub client: String,
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+    pub description: String,
+    /// Repo name the time belongs to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// Where the entry was imported from (e.g. "timewarrior", "org")
+    pub source: String,
+}
+
+impl ManualEntry {
+    pub fn duration_minutes(&self) -> u32 {
+        (self.end - self.start).num_minutes().max(0) as u32
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.start.date_naive()
+    }
+
+    /// The entry as a session without commits
+    pub fn to_session(&self) -> Session {
+        Session {
+            start: self.start,
+            end: self.end,
+            duration_minutes: self.duration_minutes(),
+            commits: Vec::new(),
+            repos: self.repo.iter().cloned().collect(),
+            lines_added: 0,
+            lines_deleted: 0,
+            note: Some(self.description.clone()),
+            parts: DurationParts { manual: self.duration_minutes(), ..Default::default() },
+        }
+    }
+}
+
+/// Manual time imported with `gtt time import`, stored in `manual_time.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManualTimeStore {
+    #[serde(default, rename = "entry")]
+    pub entries: Vec<ManualEntry>,
+}
+
+impl ManualTimeStore {
+    /// Load the store from the default location; missing file = empty store.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&data_path(MANUAL_TIME_FILE)?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| tf("common.read_failed", &[("path", &path.display())]))?;
+        let store = toml::from_str(&content).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        Ok(store)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&data_path(MANUAL_TIME_FILE)?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| tf("common.create_dir_failed", &[("path", &parent.display())]))?;
+        }
+        let content = toml::to_string_pretty(self).map_err(|e| GttError::StoreParse(e.to_string()))?;
+        std::fs::write(path, content)
+            .with_context(|| tf("common.write_failed", &[("path", &path.display())]))?;
+        Ok(())
+    }
+
+    /// Add an entry with the next free id unless the client already has one with
+    /// the same start and end. Returns whether it was added.
+    pub fn add(
+        &mut self,
+        client: &str,
+        start: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+        description: &str,
+        repo: Option<String>,
+        source: &str,
+    ) -> bool {
+        if self.entries.iter().any(|e| e.client == client && e.start == start && e.end == end) {
+            return false;
+        }
+        let id = self.entries.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+        self.entries.push(ManualEntry {
+            id,
+            client: client.to_string(),
+            start,
+            end,
+            description: description.to_string(),
+            repo,
+            source: source.to_string(),
+        });
+        true
+    }
+
+    /// Remove the entry with `id`; returns whether it existed
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.id != id);
+        self.entries.len() != before
+    }
+
+    /// Entries of `client` starting within `since..=until`, in chronological order
+    pub fn for_client(&self, client: &str, since: NaiveDate, until: NaiveDate) -> Vec<ManualEntry> {
+        let mut found: Vec<ManualEntry> = self
+            .entries
+            .iter()
+            .filter(|e| e.client == client && e.date() >= since && e.date() <= until)
+            .cloned()
+            .collect();
+        found.sort_by_key(|e| e.start);
+        found
+    }
+}
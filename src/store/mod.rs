@@ -0,0 +1,22 @@
+pub mod expenses;
+pub mod invoices;
+pub mod llm_usage;
+pub mod manual_time;
+
+use std::path::PathBuf;
+use anyhow::Result;
+use crate::config::config_path;
+
+#[allow(unused_imports)]
+pub use expenses::{Expense, ExpenseStore};
+#[allow(unused_imports)]
+pub use invoices::{InvoiceRecord, InvoiceStore, Payment};
+#[allow(unused_imports)]
+pub use llm_usage::{LlmUsageEntry, LlmUsageStore};
+#[allow(unused_imports)]
+pub use manual_time::{ManualEntry, ManualTimeStore};
+
+/// Path of a local data file kept next to `config.toml`
+pub fn data_path(file_name: &str) -> Result<PathBuf> {
+    Ok(config_path()?.with_file_name(file_name))
+}
@@ -0,0 +1,789 @@
+use chrono::{DateTime, NaiveDate};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use gtt::config::{BudgetConfig, ClientConfig, DiscountRule, PrepaidBlock, RateEntry, RateRule, RepoAttribution, RetainerConfig, RoundingConfig, RoundingMode, RoundingScope, TaxRule};
+use gtt::git::Commit;
+use gtt::session::budget::budget_status;
+use gtt::session::attribution::{attribute_repos, session_repo_minutes};
+use gtt::session::grouping::{group_report, GroupBy, GroupKey};
+use gtt::session::invoice::{compute_invoice, round_money};
+use gtt::store::{ExpenseStore, LlmUsageEntry};
+use gtt::session::billing::{round_minutes, rule_matches, split_minutes, DEFAULT_CATEGORY};
+use gtt::session::{
+    analyze, apply_caps, apply_rates, apply_retainer, apply_rounding, attribute_usage, bill_by_month, bill_sessions,
+    convert_report, group_by_day, DayReport, ExchangeRates, RateDate,
+};
+
+mod common;
+use common::{default_settings, make_commit, make_commit_with_volume, make_report};
+
+fn rounding(increment: u32, mode: RoundingMode, scope: RoundingScope) -> RoundingConfig {
+    RoundingConfig {
+        increment_minutes: increment,
+        mode,
+        scope,
+        minimum_per_day: 0,
+        minimum_per_session: 0,
+    }
+}
+
+/// Two sessions on 2026-01-05 (40m and 35m) and one on 2026-01-06 (30m)
+fn sample_days() -> Vec<DayReport> {
+    let commits = vec![
+        make_commit("a1", "2026-01-05T09:00:00+00:00", "proj"),
+        make_commit("a2", "2026-01-05T09:10:00+00:00", "proj"),
+        make_commit("a3", "2026-01-05T14:00:00+00:00", "proj"),
+        make_commit("a4", "2026-01-05T14:05:00+00:00", "proj"),
+        make_commit("a5", "2026-01-06T10:00:00+00:00", "proj"),
+    ];
+    group_by_day(analyze(commits, &default_settings()))
+}
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_round_minutes_modes() {
+    assert_eq!(round_minutes(31, 15, RoundingMode::Up), 45);
+    assert_eq!(round_minutes(31, 15, RoundingMode::Down), 30);
+    assert_eq!(round_minutes(37, 15, RoundingMode::Nearest), 30);
+    assert_eq!(round_minutes(38, 15, RoundingMode::Nearest), 45);
+    assert_eq!(round_minutes(45, 15, RoundingMode::Up), 45);
+}
+
+#[test]
+fn test_round_minutes_no_increment() {
+    assert_eq!(round_minutes(31, 0, RoundingMode::Up), 31);
+    assert_eq!(round_minutes(31, 1, RoundingMode::Up), 31);
+}
+
+#[test]
+fn test_billed_defaults_to_raw_minutes() {
+    let days = sample_days();
+    assert_eq!(days[0].total_minutes, 75);
+    assert_eq!(days[0].billed_minutes, 75);
+}
+
+#[test]
+fn test_session_scope_rounds_each_session() {
+    let mut days = sample_days();
+    apply_rounding(&mut days, &rounding(15, RoundingMode::Up, RoundingScope::Session));
+    // 40 → 45, 35 → 45
+    assert_eq!(days[0].billed_minutes, 90);
+    assert_eq!(days[0].total_minutes, 75);
+    assert_eq!(days[1].billed_minutes, 30);
+}
+
+#[test]
+fn test_day_scope_rounds_day_total() {
+    let mut days = sample_days();
+    apply_rounding(&mut days, &rounding(15, RoundingMode::Up, RoundingScope::Day));
+    // 75 is already a multiple of 15
+    assert_eq!(days[0].billed_minutes, 75);
+    assert_eq!(days[1].billed_minutes, 30);
+}
+
+#[test]
+fn test_period_scope_books_difference_on_last_day() {
+    let mut days = sample_days();
+    apply_rounding(&mut days, &rounding(60, RoundingMode::Up, RoundingScope::Period));
+    // 75 + 30 = 105 → 120
+    assert_eq!(days[0].billed_minutes, 75);
+    assert_eq!(days[1].billed_minutes, 45);
+    let total: u32 = days.iter().map(|d| d.billed_minutes).sum();
+    assert_eq!(total, 120);
+}
+
+#[test]
+fn test_period_scope_round_down_spans_days() {
+    let mut days = sample_days();
+    apply_rounding(&mut days, &rounding(100, RoundingMode::Down, RoundingScope::Period));
+    // 105 → 100: the 5 minutes come off the last day
+    assert_eq!(days[1].billed_minutes, 25);
+    let total: u32 = days.iter().map(|d| d.billed_minutes).sum();
+    assert_eq!(total, 100);
+}
+
+#[test]
+fn test_period_round_down_keeps_minimum_per_day() {
+    let mut days = sample_days();
+    let cfg = RoundingConfig {
+        minimum_per_day: 60,
+        ..rounding(60, RoundingMode::Down, RoundingScope::Period)
+    };
+    apply_rounding(&mut days, &cfg);
+    // 75 + 60 = 135 → 120: the last day is at the minimum, so the first one gives
+    assert_eq!(days[0].billed_minutes, 60);
+    assert_eq!(days[1].billed_minutes, 60);
+
+    let mut days = sample_days();
+    let cfg = RoundingConfig {
+        minimum_per_day: 60,
+        ..rounding(100, RoundingMode::Down, RoundingScope::Period)
+    };
+    apply_rounding(&mut days, &cfg);
+    // 135 → 100 would need a day below 60: stop at the minimum
+    assert_eq!(days[0].billed_minutes, 60);
+    assert_eq!(days[1].billed_minutes, 60);
+}
+
+#[test]
+fn test_minimums_per_day_and_session() {
+    let mut days = sample_days();
+    let cfg = RoundingConfig {
+        minimum_per_session: 45,
+        minimum_per_day: 60,
+        ..rounding(15, RoundingMode::Up, RoundingScope::Day)
+    };
+    apply_rounding(&mut days, &cfg);
+    // 45 + 45 = 90 on the first day, 45 → raised to 60 on the second
+    assert_eq!(days[0].billed_minutes, 90);
+    assert_eq!(days[1].billed_minutes, 60);
+}
+
+// --- Caps ---
+
+#[test]
+fn test_daily_cap_moves_excess_to_overflow() {
+    let mut days = sample_days();
+    let client = ClientConfig {
+        max_hours_per_day: Some(1.0),
+        ..Default::default()
+    };
+    apply_caps(&mut days, &client);
+    assert_eq!(days[0].billed_minutes, 60);
+    assert_eq!(days[0].overflow_minutes, 15);
+    assert_eq!(days[0].total_minutes, 75);
+    assert_eq!(days[1].billed_minutes, 30);
+    assert_eq!(days[1].overflow_minutes, 0);
+}
+
+#[test]
+fn test_weekly_cap_overflows_on_day_crossing_limit() {
+    let mut days = sample_days();
+    let client = ClientConfig {
+        max_hours_per_week: Some(1.5),
+        ..Default::default()
+    };
+    apply_caps(&mut days, &client);
+    // 75 fits in 90, the next day only has 15 left
+    assert_eq!(days[0].billed_minutes, 75);
+    assert_eq!(days[1].billed_minutes, 15);
+    assert_eq!(days[1].overflow_minutes, 15);
+}
+
+#[test]
+fn test_weekly_cap_resets_on_new_iso_week() {
+    let commits = vec![
+        make_commit("a1", "2026-01-09T10:00:00+00:00", "proj"), // Friday
+        make_commit("a2", "2026-01-12T10:00:00+00:00", "proj"), // next Monday
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    let client = ClientConfig {
+        max_hours_per_week: Some(0.5),
+        ..Default::default()
+    };
+    apply_caps(&mut days, &client);
+    assert_eq!(days[0].billed_minutes, 30);
+    assert_eq!(days[1].billed_minutes, 30);
+    assert!(days.iter().all(|d| d.overflow_minutes == 0));
+}
+
+#[test]
+fn test_period_cap_applies_after_rounding() {
+    let mut days = sample_days();
+    apply_rounding(&mut days, &rounding(60, RoundingMode::Up, RoundingScope::Day));
+    let client = ClientConfig {
+        max_hours_per_period: Some(2.5),
+        ..Default::default()
+    };
+    apply_caps(&mut days, &client);
+    // Rounded: 120 + 60 = 180, capped at 150
+    assert_eq!(days[0].billed_minutes, 120);
+    assert_eq!(days[1].billed_minutes, 30);
+    assert_eq!(days[1].overflow_minutes, 30);
+}
+
+// --- Rate history ---
+
+fn client_with_rate_history() -> ClientConfig {
+    ClientConfig {
+        hourly_rate: dec!(80.0),
+        rates: vec![
+            RateEntry { from: date("2026-01-06"), hourly_rate: dec!(100.0) },
+            RateEntry { from: date("2026-02-01"), hourly_rate: dec!(120.0) },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_rate_on_uses_latest_effective_entry() {
+    let client = client_with_rate_history();
+    assert_eq!(client.rate_on(date("2026-01-05")), dec!(80.0));
+    assert_eq!(client.rate_on(date("2026-01-06")), dec!(100.0));
+    assert_eq!(client.rate_on(date("2026-01-31")), dec!(100.0));
+    assert_eq!(client.rate_on(date("2026-03-15")), dec!(120.0));
+}
+
+#[test]
+fn test_rate_on_without_history_uses_hourly_rate() {
+    let client = ClientConfig {
+        hourly_rate: dec!(75.0),
+        ..Default::default()
+    };
+    assert_eq!(client.rate_on(date("2026-01-05")), dec!(75.0));
+}
+
+#[test]
+fn test_mixed_rate_period_has_subtotal_per_rate() {
+    let mut days = sample_days();
+    apply_rates(&mut days, &client_with_rate_history(), RepoAttribution::Commits);
+    let report = make_report(days);
+
+    // 75 min at 80/h + 30 min at 100/h
+    assert_eq!(report.billable_amount(), dec!(100.0) + dec!(50.0));
+    let subtotals = report.rate_subtotals();
+    assert_eq!(subtotals.len(), 2);
+    assert_eq!(subtotals[0].hourly_rate, dec!(80.0));
+    assert_eq!(subtotals[0].billed_minutes, 75);
+    assert_eq!(subtotals[1].hourly_rate, dec!(100.0));
+    assert_eq!(subtotals[1].amount, dec!(50.0));
+}
+
+// --- Rate rules ---
+
+fn typed_commit(hash: &str, date_str: &str, subject: &str) -> Commit {
+    Commit {
+        subject: subject.to_string(),
+        ..make_commit(hash, date_str, "proj")
+    }
+}
+
+#[test]
+fn test_split_minutes_adds_up_to_total() {
+    let parts = split_minutes(100, &[("a", 1), ("b", 1), ("c", 1)]);
+    assert_eq!(parts.iter().map(|p| p.1).sum::<u32>(), 100);
+    assert_eq!(parts[0].1, 34);
+    assert_eq!(split_minutes(100, &[("a", 2), ("b", 1), ("c", 1)]), vec![("a", 50), ("b", 25), ("c", 25)]);
+    // Equal remainders go to the earlier entries
+    assert_eq!(split_minutes(10, &[("a", 1), ("b", 1), ("c", 1)]), vec![("a", 4), ("b", 3), ("c", 3)]);
+    assert!(split_minutes(100, &[("a", 0)]).is_empty());
+    assert!(split_minutes::<&str>(5, &[]).is_empty());
+}
+
+#[test]
+fn test_rule_matchers() {
+    let mut commit = typed_commit("a1", "2026-01-05T09:00:00+00:00", "fix(auth): token expiry");
+    commit.files = vec!["src/auth/token.rs".to_string()];
+    commit.branch = Some("hotfix/token".to_string());
+
+    let by_type = RateRule { commit_types: vec!["fix".to_string()], ..Default::default() };
+    let by_path = RateRule { path: Some("src/auth/".to_string()), ..Default::default() };
+    let by_glob = RateRule { path: Some("*.rs".to_string()), ..Default::default() };
+    let by_branch = RateRule { branch: Some("hotfix/*".to_string()), ..Default::default() };
+    let wrong_repo = RateRule {
+        repo: Some("other".to_string()),
+        commit_types: vec!["fix".to_string()],
+        ..Default::default()
+    };
+
+    assert!(rule_matches(&by_type, &commit));
+    assert!(rule_matches(&by_path, &commit));
+    assert!(rule_matches(&by_glob, &commit));
+    assert!(rule_matches(&by_branch, &commit));
+    assert!(!rule_matches(&wrong_repo, &commit));
+}
+
+#[test]
+fn test_session_minutes_split_by_rule() {
+    let commits = vec![
+        typed_commit("a1", "2026-01-05T09:00:00+00:00", "feat: dashboard"),
+        typed_commit("a2", "2026-01-05T09:30:00+00:00", "fix: dashboard crash"),
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    let client = ClientConfig {
+        hourly_rate: dec!(100.0),
+        rate_rules: vec![RateRule {
+            name: "maintenance".to_string(),
+            hourly_rate: dec!(60.0),
+            commit_types: vec!["fix".to_string(), "chore".to_string()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    apply_rates(&mut days, &client, RepoAttribution::Commits);
+    let report = make_report(days);
+
+    // 60 minutes, one commit per category → 30 min each
+    let lines = report.rate_subtotals();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].category, DEFAULT_CATEGORY);
+    assert_eq!(lines[0].billed_minutes, 30);
+    assert_eq!(lines[1].category, "maintenance");
+    assert_eq!(lines[1].billed_minutes, 30);
+    assert_eq!(report.billable_amount(), dec!(50.0) + dec!(30.0));
+}
+
+// --- Retainer ---
+
+fn retainer(hours_per_month: f64, rollover: bool) -> RetainerConfig {
+    RetainerConfig {
+        start: date("2026-01-01"),
+        hours_per_month,
+        rollover,
+        max_rollover_hours: None,
+        prepaid: vec![],
+    }
+}
+
+#[test]
+fn test_retainer_history_caps_each_month_separately() {
+    // 120 minutes on a day in January and another in February, capped at 90 per period
+    let sessions = analyze(
+        vec![
+            make_commit("a1", "2026-01-05T10:00:00+00:00", "proj"),
+            make_commit("a2", "2026-01-05T11:30:00+00:00", "proj"),
+            make_commit("a3", "2026-02-02T10:00:00+00:00", "proj"),
+            make_commit("a4", "2026-02-02T11:30:00+00:00", "proj"),
+        ],
+        &default_settings(),
+    );
+    let client = ClientConfig { hourly_rate: dec!(60), max_hours_per_period: Some(1.5), ..Default::default() };
+
+    // As one period, February would be capped away entirely
+    let whole = bill_sessions(sessions.clone(), &client, &default_settings());
+    assert_eq!(whole.iter().map(|d| d.billed_minutes).collect::<Vec<_>>(), vec![90, 0]);
+
+    let mut days = bill_by_month(sessions, &client, &default_settings());
+    assert_eq!(days.iter().map(|d| (d.billed_minutes, d.overflow_minutes)).collect::<Vec<_>>(), vec![(90, 30), (90, 30)]);
+    let periods = apply_retainer(&mut days, &retainer(1.5, false), date("2026-02-28"));
+    assert_eq!(periods.iter().map(|p| (p.used_minutes, p.covered_minutes)).collect::<Vec<_>>(), vec![(90, 90), (90, 90)]);
+}
+
+#[test]
+fn test_retainer_covers_days_chronologically() {
+    let mut days = sample_days();
+    let periods = apply_retainer(&mut days, &retainer(1.5, false), date("2026-01-31"));
+
+    // 90 included: 75 on the first day, the remaining 15 on the second
+    assert_eq!(days[0].covered_minutes, 75);
+    assert_eq!(days[1].covered_minutes, 15);
+    assert_eq!(periods.len(), 1);
+    assert_eq!(periods[0].used_minutes, 105);
+    assert_eq!(periods[0].overage_minutes, 15);
+    assert_eq!(periods[0].remaining_minutes(), 0);
+}
+
+#[test]
+fn test_retainer_rollover_and_prepaid() {
+    let commits = vec![
+        make_commit("a1", "2026-01-05T10:00:00+00:00", "proj"), // 30 min in January
+        make_commit("a2", "2026-02-02T10:00:00+00:00", "proj"),
+        make_commit("a3", "2026-02-02T11:30:00+00:00", "proj"), // 120 min in February
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    let cfg = RetainerConfig {
+        max_rollover_hours: Some(0.25),
+        prepaid: vec![PrepaidBlock { date: date("2026-02-01"), hours: 1.0 }],
+        ..retainer(1.0, true)
+    };
+    let periods = apply_retainer(&mut days, &cfg, date("2026-02-28"));
+
+    assert_eq!(periods.len(), 2);
+    // January: 30 of 60 unused, rollover capped at 15
+    assert_eq!(periods[0].unused_minutes, 30);
+    assert_eq!(periods[0].rollover_out_minutes, 15);
+    // February: 60 + 15 monthly, 45 from the prepaid block
+    assert_eq!(periods[1].rollover_in_minutes, 15);
+    assert_eq!(periods[1].covered_minutes, 120);
+    assert_eq!(periods[1].overage_minutes, 0);
+    assert_eq!(periods[1].prepaid_balance_minutes, 15);
+}
+
+#[test]
+fn test_report_splits_covered_and_overage() {
+    let mut days = sample_days();
+    apply_retainer(&mut days, &retainer(1.5, false), date("2026-01-31"));
+    apply_rates(
+        &mut days,
+        &ClientConfig {
+            hourly_rate: dec!(60.0),
+            ..Default::default()
+        },
+        RepoAttribution::Commits,
+    );
+    let report = make_report(days);
+    assert_eq!(report.covered_minutes, 90);
+    assert_eq!(report.overage_minutes(), 15);
+    assert_eq!(report.covered_amount(), dec!(90.0));
+    assert_eq!(report.overage_amount(), dec!(15.0));
+    assert_eq!(report.total_amount(), dec!(15.0));
+}
+
+// --- Budgets ---
+
+fn budget(hours: Option<f64>, amount: Option<Decimal>) -> BudgetConfig {
+    BudgetConfig {
+        name: "Redesign".to_string(),
+        start: date("2026-01-01"),
+        hours,
+        amount,
+        repos: vec![],
+        alert_threshold: 0.8,
+    }
+}
+
+fn rated_days(rate: Decimal) -> Vec<DayReport> {
+    let mut days = sample_days();
+    apply_rates(&mut days, &ClientConfig { hourly_rate: rate, ..Default::default() }, RepoAttribution::Commits);
+    days
+}
+
+#[test]
+fn test_budget_consumption_and_projection() {
+    let days = rated_days(dec!(60.0));
+    // 105 minutes consumed out of 4h; 105 minutes over a 7-day window = 15 min/day
+    let status = budget_status(&budget(Some(4.0), None), &days, date("2026-01-07"));
+    assert_eq!(status.consumed_minutes, 105);
+    assert_eq!(status.remaining_minutes(), Some(135));
+    assert_eq!(status.burn_minutes_per_day, 15.0);
+    // 135 / 15 = 9 days from today
+    assert_eq!(status.projected_exhaustion, Some(date("2026-01-16")));
+    assert!(!status.over_threshold());
+}
+
+#[test]
+fn test_budget_uses_largest_fraction_and_reports_exhaustion_day() {
+    let days = rated_days(dec!(60.0));
+    // Money: 105 spent of 100 → exhausted on the second day
+    let status = budget_status(&budget(Some(10.0), Some(dec!(100))), &days, date("2026-01-31"));
+    assert!(status.fraction_used() > 1.0);
+    assert!(status.over_threshold());
+    assert_eq!(status.projected_exhaustion, Some(date("2026-01-06")));
+}
+
+#[test]
+fn test_budget_ignores_days_before_start() {
+    let days = rated_days(dec!(60.0));
+    let cfg = BudgetConfig {
+        start: date("2026-01-06"),
+        ..budget(Some(1.0), None)
+    };
+    let status = budget_status(&cfg, &days, date("2026-01-06"));
+    assert_eq!(status.consumed_minutes, 30);
+    assert_eq!(status.fraction_used(), 0.5);
+}
+
+const RATES_CSV: &str = "date,from,to,rate\n\
+2026-01-01,USD,EUR,0.90\n\
+2026-01-06,USD,EUR,0.80\n\
+2026-01-01,GBP,USD,1.25\n";
+
+#[test]
+fn test_exchange_rate_lookup_uses_latest_rate_and_inverse_pairs() {
+    let rates = ExchangeRates::parse(RATES_CSV).unwrap();
+    assert_eq!(rates.rate_on("USD", "EUR", date("2026-01-05")), Some(dec!(0.90)));
+    assert_eq!(rates.rate_on("usd", "eur", date("2026-01-31")), Some(dec!(0.80)));
+    assert_eq!(rates.rate_on("USD", "GBP", date("2026-01-05")), Some(dec!(0.8)));
+    assert_eq!(rates.rate_on("EUR", "EUR", date("2020-01-01")), Some(dec!(1.0)));
+    assert_eq!(rates.rate_on("USD", "EUR", date("2025-12-31")), None);
+    assert!(ExchangeRates::parse("date,from,to,rate\n2026-01-01,USD,EUR,abc\n").is_err());
+}
+
+#[test]
+fn test_convert_report_at_invoice_or_daily_rate() {
+    let rates = ExchangeRates::parse(RATES_CSV).unwrap();
+    // 75 minutes on 2026-01-05 and 30 minutes on 2026-01-06 at 60/h = 75 + 30 USD
+    let report = make_report(rated_days(dec!(60.0)));
+
+    let at_invoice = convert_report(&report, &rates, "eur", RateDate::Invoice).unwrap();
+    assert_eq!(at_invoice.currency, "EUR");
+    assert_eq!(at_invoice.single_rate(), Some(dec!(0.80)));
+    assert_eq!(at_invoice.total_amount, dec!(84.00));
+
+    let daily = convert_report(&report, &rates, "EUR", RateDate::Day).unwrap();
+    assert_eq!(daily.day_rates, vec![dec!(0.90), dec!(0.80)]);
+    assert_eq!(daily.single_rate(), None);
+    assert_eq!(daily.total_amount, dec!(75) * dec!(0.90) + dec!(30) * dec!(0.80));
+
+    assert!(convert_report(&report, &rates, "JPY", RateDate::Invoice).is_err());
+}
+
+fn discount(name: &str, percent: Option<Decimal>, amount: Option<Decimal>) -> DiscountRule {
+    DiscountRule { name: name.to_string(), percent, amount }
+}
+
+fn tax(name: &str, percent: Decimal, withholding: bool) -> TaxRule {
+    TaxRule { name: name.to_string(), percent, withholding }
+}
+
+#[test]
+fn test_invoice_applies_discounts_then_taxes() {
+    let invoice = compute_invoice(
+        dec!(1000),
+        &[discount("Loyalty", Some(dec!(10)), None), discount("Voucher", None, Some(dec!(50)))],
+        &[tax("VAT", dec!(21), false), tax("Withholding", dec!(15), true)],
+    );
+    assert_eq!(invoice.discount_total(), dec!(-150));
+    assert_eq!(invoice.taxable_base(), dec!(850));
+    assert_eq!(invoice.taxes[0].amount, dec!(178.50));
+    assert_eq!(invoice.taxes[1].amount, dec!(-127.50));
+    assert_eq!(invoice.total(), dec!(901.00));
+}
+
+#[test]
+fn test_invoice_rounds_each_line_to_cents_and_caps_discounts() {
+    // 0.1 + 0.2 is exact in decimal; 21% of 0.30 = 0.063 → 0.06
+    let invoice = compute_invoice(dec!(0.1) + dec!(0.2), &[], &[tax("VAT", dec!(21), false)]);
+    assert_eq!(invoice.subtotal, dec!(0.30));
+    assert_eq!(invoice.total(), dec!(0.36));
+
+    // Half a cent rounds away from zero
+    let invoice = compute_invoice(dec!(10.005), &[], &[]);
+    assert_eq!(invoice.total(), dec!(10.01));
+
+    // Discounts never take the base below zero
+    let invoice = compute_invoice(dec!(40), &[discount("Credit", None, Some(dec!(100)))], &[]);
+    assert_eq!(invoice.total(), Decimal::ZERO);
+}
+
+#[test]
+fn test_report_total_includes_discounts_and_taxes() {
+    let mut report = make_report(rated_days(dec!(60)));
+    report.discounts = vec![discount("Loyalty", Some(dec!(10)), None)];
+    report.tax_rules = vec![tax("VAT", dec!(21), false)];
+    // 105 minutes at 60/h = 105; -10.50; +21% of 94.50 = 19.85 (19.845)
+    assert_eq!(report.subtotal(), dec!(105));
+    assert_eq!(report.total_amount(), dec!(114.35));
+}
+
+#[test]
+fn test_expenses_are_part_of_the_subtotal() {
+    let mut store = ExpenseStore::default();
+    store.add("Client", date("2026-01-05"), dec!(12.50), "Hosting", None);
+    let mut report = make_report(rated_days(dec!(60)));
+    report.expenses = store.expenses;
+    report.tax_rules = vec![tax("VAT", dec!(10), false)];
+    // 105 hours amount + 12.50 expenses = 117.50; +10% = 129.25
+    assert_eq!(report.expenses_total(), dec!(12.50));
+    assert_eq!(report.subtotal(), dec!(117.50));
+    assert_eq!(report.total_amount(), dec!(129.25));
+}
+
+fn usage(timestamp: &str, cost: Decimal, tag: Option<&str>) -> LlmUsageEntry {
+    LlmUsageEntry {
+        timestamp: DateTime::parse_from_rfc3339(timestamp).unwrap(),
+        cost,
+        currency: "USD".to_string(),
+        provider: "csv".to_string(),
+        model: None,
+        tag: tag.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_llm_usage_attributed_by_tag_or_session_window() {
+    let days = sample_days();
+    let entries = vec![
+        usage("2026-01-05T08:45:00+00:00", dec!(1.00), None), // inside 08:30-09:10 (base minutes included)
+        usage("2026-01-05T11:00:00+00:00", dec!(2.00), None), // between sessions
+        usage("2026-01-06T09:45:00+00:00", dec!(0.50), None), // inside 09:30-10:00
+        usage("2026-01-07T12:00:00+00:00", dec!(4.00), Some("acme")), // tagged, no commits that day
+        usage("2026-01-05T09:05:00+00:00", dec!(8.00), Some("Other")), // tagged for another client
+        usage("2026-02-01T12:00:00+00:00", dec!(16.00), Some("client")), // outside the period
+    ];
+    let found = attribute_usage(&entries, "Client", &["ACME".to_string()], &days, date("2026-01-01"), date("2026-01-31"));
+    let costs: Vec<Decimal> = found.iter().map(|u| u.cost).collect();
+    assert_eq!(costs, vec![dec!(1.00), dec!(0.50), dec!(4.00)]);
+}
+
+#[test]
+fn test_report_uses_real_llm_cost_when_available() {
+    let mut report = make_report(rated_days(dec!(60)));
+    report.llm_cost_per_kloc = dec!(10);
+    assert!(!report.has_llm_usage());
+
+    report.llm_usage = vec![
+        usage("2026-01-05T12:00:00+00:00", dec!(1.25), None),
+        usage("2026-01-06T12:00:00+00:00", dec!(0.75), None),
+    ];
+    report.llm_cost = dec!(2.00);
+    assert!(report.has_llm_usage());
+    assert_eq!(report.day_llm_cost(&report.days[0]), dec!(1.25));
+    assert_eq!(report.day_llm_cost(&report.days[1]), dec!(0.75));
+    assert_eq!(report.subtotal(), dec!(107));
+}
+
+#[test]
+fn test_repo_attribution_methods() {
+    // 90 minutes: 30 credited before the first commit plus 60 between commits
+    let commits = vec![
+        Commit { lines_added: 10, ..make_commit("a1", "2026-01-05T09:00:00+00:00", "web") },
+        Commit { lines_added: 10, ..make_commit("a2", "2026-01-05T09:20:00+00:00", "web") },
+        Commit { lines_added: 10, ..make_commit("a3", "2026-01-05T09:40:00+00:00", "web") },
+        Commit { lines_added: 50, lines_deleted: 10, ..make_commit("a4", "2026-01-05T10:00:00+00:00", "api") },
+    ];
+    let session = &analyze(commits, &default_settings())[0];
+    let split = |method| session_repo_minutes(session, method);
+    assert_eq!(split(RepoAttribution::Commits), vec![("web".to_string(), 68), ("api".to_string(), 22)]);
+    assert_eq!(split(RepoAttribution::Lines), vec![("web".to_string(), 30), ("api".to_string(), 60)]);
+    assert_eq!(split(RepoAttribution::Time), vec![("web".to_string(), 70), ("api".to_string(), 20)]);
+
+    // Without lines, the lines method falls back to the commit count
+    let commits = vec![
+        make_commit("b1", "2026-01-06T09:00:00+00:00", "web"),
+        make_commit("b2", "2026-01-06T09:30:00+00:00", "api"),
+    ];
+    let session = &analyze(commits, &default_settings())[0];
+    assert_eq!(
+        session_repo_minutes(session, RepoAttribution::Lines),
+        vec![("web".to_string(), 30), ("api".to_string(), 30)]
+    );
+}
+
+#[test]
+fn test_day_repo_minutes_follow_the_method() {
+    let commits = vec![
+        make_commit("a1", "2026-01-05T09:00:00+00:00", "web"),
+        make_commit("a2", "2026-01-05T09:50:00+00:00", "api"),
+        make_commit("a3", "2026-01-05T15:00:00+00:00", "api"),
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    let minutes = |day: &DayReport| day.repo_minutes.iter().map(|r| (r.repo.clone(), r.minutes)).collect::<Vec<_>>();
+    // 80 and 30 minute sessions; by commits the first splits evenly
+    assert_eq!(minutes(&days[0]), vec![("api".to_string(), 70), ("web".to_string(), 40)]);
+    assert_eq!(days[0].repo_minutes.iter().map(|r| r.minutes).sum::<u32>(), days[0].total_minutes);
+
+    attribute_repos(&mut days, RepoAttribution::Time);
+    assert_eq!(minutes(&days[0]), vec![("api".to_string(), 80), ("web".to_string(), 30)]);
+}
+
+#[test]
+fn test_group_report_by_week_month_and_weekday() {
+    let commits = vec![
+        make_commit("a1", "2026-01-05T09:00:00+00:00", "proj"),
+        make_commit("a2", "2026-01-06T09:00:00+00:00", "proj"),
+        make_commit("a3", "2026-01-12T09:00:00+00:00", "proj"),
+        make_commit("a4", "2026-02-02T09:00:00+00:00", "proj"),
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    apply_rates(&mut days, &ClientConfig { hourly_rate: dec!(60), ..Default::default() }, RepoAttribution::Commits);
+    let report = make_report(days);
+
+    let weeks = group_report(&report, GroupBy::Week);
+    let ids: Vec<String> = weeks.iter().map(|g| g.key.id()).collect();
+    assert_eq!(ids, vec!["2026-W02", "2026-W03", "2026-W06"]);
+    assert_eq!(weeks[0].days, 2);
+    assert_eq!(weeks[0].total_minutes, 60);
+    assert_eq!(weeks[0].amount, dec!(60));
+    assert_eq!(weeks[0].key.start(), Some(date("2026-01-05")));
+
+    let months = group_report(&report, GroupBy::Month);
+    assert_eq!(months.iter().map(|g| g.key.id()).collect::<Vec<_>>(), vec!["2026-01", "2026-02"]);
+    assert_eq!(months[0].days, 3);
+    assert_eq!(months.iter().map(|g| g.total_amount()).sum::<Decimal>(), dec!(120));
+
+    // Mondays 5, 12 and 2 Feb, then Tuesday
+    let weekdays = group_report(&report, GroupBy::Weekday);
+    assert_eq!(weekdays.iter().map(|g| g.key.id()).collect::<Vec<_>>(), vec!["monday", "tuesday"]);
+    assert_eq!(weekdays[0].days, 3);
+
+    let days = group_report(&report, GroupBy::Day);
+    assert_eq!(days.len(), report.days.len());
+    assert_eq!(days[0].key, GroupKey::Day(date("2026-01-05")));
+}
+
+#[test]
+fn test_group_report_by_repo_splits_sessions_by_commits() {
+    // One 90-minute session with three commits in web and one in api
+    let commits = vec![
+        make_commit("a1", "2026-01-05T09:00:00+00:00", "web"),
+        make_commit("a2", "2026-01-05T09:20:00+00:00", "web"),
+        make_commit("a3", "2026-01-05T09:40:00+00:00", "web"),
+        make_commit("a4", "2026-01-05T10:00:00+00:00", "api"),
+        make_commit("a5", "2026-01-06T10:00:00+00:00", "api"),
+    ];
+    let sessions = analyze(commits, &default_settings());
+    assert_eq!(
+        session_repo_minutes(&sessions[0], RepoAttribution::Commits),
+        vec![("web".to_string(), 68), ("api".to_string(), 22)]
+    );
+
+    let mut days = group_by_day(sessions);
+    apply_rates(&mut days, &ClientConfig { hourly_rate: dec!(60), ..Default::default() }, RepoAttribution::Commits);
+    let report = make_report(days);
+    let repos = group_report(&report, GroupBy::Repo);
+    assert_eq!(repos.len(), 2);
+    assert_eq!(repos[0].key, GroupKey::Repo("web".to_string()));
+    assert_eq!((repos[0].total_minutes, repos[0].total_commits, repos[0].days), (68, 3, 1));
+    assert_eq!((repos[1].total_minutes, repos[1].total_commits, repos[1].days), (52, 2, 2));
+    assert_eq!(round_money(repos[0].amount), dec!(68.00));
+    assert_eq!(round_money(repos[1].amount), dec!(52.00));
+    assert_eq!(repos.iter().map(|g| g.billed_minutes).sum::<u32>(), report.billed_minutes);
+}
+
+#[test]
+fn test_group_report_by_repo_amounts_follow_the_attribution() {
+    // One 60-minute session: 1000 lines in a, 10 in b (a rule bills b at 200/h)
+    let commits = vec![
+        make_commit_with_volume("a1", "2026-01-05T09:00:00+00:00", "a", 900, 100),
+        make_commit_with_volume("b1", "2026-01-05T09:30:00+00:00", "b", 10, 0),
+    ];
+    let client = ClientConfig {
+        hourly_rate: dec!(100),
+        rate_rules: vec![RateRule {
+            name: "b".to_string(),
+            hourly_rate: dec!(200),
+            repo: Some("b".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    for method in [RepoAttribution::Lines, RepoAttribution::Time] {
+        let mut days = group_by_day(analyze(commits.clone(), &default_settings()));
+        attribute_repos(&mut days, method);
+        apply_rates(&mut days, &client, method);
+        let report = make_report(days);
+        let repos = group_report(&report, GroupBy::Repo);
+        assert_eq!(repos.iter().map(|g| g.billed_minutes).sum::<u32>(), 60);
+        for (group, rate) in repos.iter().zip([dec!(100), dec!(200)]) {
+            assert_eq!(group.amount, Decimal::from(group.billed_minutes) * rate / Decimal::from(60));
+        }
+        if method == RepoAttribution::Lines {
+            assert_eq!((repos[0].billed_minutes, repos[1].billed_minutes), (59, 1));
+        }
+    }
+}
+
+#[test]
+fn test_group_report_by_repo_bills_each_repo_at_its_rate() {
+    // 68 minutes of web at the default 60/h; 22 + 30 minutes of api at 120/h
+    let commits = vec![
+        make_commit("a1", "2026-01-05T09:00:00+00:00", "web"),
+        make_commit("a2", "2026-01-05T09:20:00+00:00", "web"),
+        make_commit("a3", "2026-01-05T09:40:00+00:00", "web"),
+        make_commit("a4", "2026-01-05T10:00:00+00:00", "api"),
+        make_commit("a5", "2026-01-06T10:00:00+00:00", "api"),
+    ];
+    let mut days = group_by_day(analyze(commits, &default_settings()));
+    let client = ClientConfig {
+        hourly_rate: dec!(60),
+        rate_rules: vec![RateRule {
+            name: "api".to_string(),
+            hourly_rate: dec!(120),
+            repo: Some("api".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    apply_rates(&mut days, &client, RepoAttribution::Commits);
+    let report = make_report(days);
+    let repos = group_report(&report, GroupBy::Repo);
+    assert_eq!(repos[0].key, GroupKey::Repo("web".to_string()));
+    assert_eq!(round_money(repos[0].amount), dec!(68.00));
+    assert_eq!(repos[1].key, GroupKey::Repo("api".to_string()));
+    assert_eq!(round_money(repos[1].amount), dec!(104.00));
+    assert_eq!(repos.iter().map(|g| g.amount).sum::<Decimal>(), report.days.iter().map(|d| d.amount()).sum::<Decimal>());
+}
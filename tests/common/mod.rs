@@ -0,0 +1,66 @@
+//! Fixtures shared by the integration tests. Each test crate uses only some
+//! of them.
+#![allow(dead_code)]
+
+use chrono::DateTime;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use gtt::config::Settings;
+use gtt::git::Commit;
+use gtt::session::{ClientReport, DayReport};
+
+pub fn make_commit(hash: &str, date_str: &str, repo: &str) -> Commit {
+    make_commit_with_volume(hash, date_str, repo, 0, 0)
+}
+
+pub fn make_commit_with_volume(hash: &str, date_str: &str, repo: &str, added: u32, deleted: u32) -> Commit {
+    Commit {
+        hash: hash.to_string(),
+        author_date: DateTime::parse_from_rfc3339(date_str).unwrap(),
+        author_email: "dev@example.com".to_string(),
+        author_name: "Dev".to_string(),
+        subject: format!("commit {}", hash),
+        repo_path: PathBuf::from(format!("/repos/{}", repo)),
+        repo_name: repo.to_string(),
+        lines_added: added,
+        lines_deleted: deleted,
+        files: vec![],
+        branch: None,
+    }
+}
+
+/// Default settings without the bot filter
+pub fn default_settings() -> Settings {
+    Settings {
+        bot_authors: vec![],
+        ..Settings::default()
+    }
+}
+
+/// A USD report for "Client" spanning `days`, with the totals summed from them
+pub fn make_report(days: Vec<DayReport>) -> ClientReport {
+    ClientReport {
+        client_name: "Client".to_string(),
+        period_start: days.first().unwrap().date,
+        period_end: days.last().unwrap().date,
+        total_minutes: days.iter().map(|d| d.total_minutes).sum(),
+        billed_minutes: days.iter().map(|d| d.billed_minutes).sum(),
+        overflow_minutes: days.iter().map(|d| d.overflow_minutes).sum(),
+        covered_minutes: days.iter().map(|d| d.covered_minutes).sum(),
+        total_commits: days.iter().map(|d| d.total_commits).sum(),
+        hourly_rate: days.last().unwrap().hourly_rate,
+        currency: "USD".to_string(),
+        total_lines_added: days.iter().map(|d| d.total_lines_added).sum(),
+        total_lines_deleted: days.iter().map(|d| d.total_lines_deleted).sum(),
+        llm_cost_per_kloc: Decimal::ZERO,
+        llm_usage: Vec::new(),
+        llm_cost: Decimal::ZERO,
+        expenses: vec![],
+        discounts: vec![],
+        tax_rules: vec![],
+        converted: None,
+        commit_urls: HashMap::new(),
+        days,
+    }
+}
@@ -1,5 +1,5 @@
 use std::path::PathBuf;
-use gtt::git::{parse_git_log, parse_numstat, merge_numstat};
+use gtt::git::{merge_files, merge_numstat, parse_branch_map, parse_git_log, parse_numstat, parse_numstat_files};
 
 fn repo() -> PathBuf {
     PathBuf::from("/home/user/project")
@@ -151,3 +151,41 @@ fn test_merge_numstat_no_match_keeps_zero() {
     assert_eq!(commits[0].lines_added, 0);
     assert_eq!(commits[0].lines_deleted, 0);
 }
+
+// --- file paths, branches and commit types ---
+
+#[test]
+fn test_parse_numstat_files_includes_binary() {
+    let raw = "abc123\n\n-\t-\timage.png\n5\t2\tsrc/main.rs\n";
+    let map = parse_numstat_files(raw);
+    assert_eq!(map.get("abc123"), Some(&vec!["image.png".to_string(), "src/main.rs".to_string()]));
+}
+
+#[test]
+fn test_merge_files_matches_by_hash() {
+    let raw_log = "abc123\x002026-01-05T10:30:00+00:00\x00dev@ex.com\x00Dev\x00Fix\x00END\n";
+    let mut commits = parse_git_log(raw_log, &repo(), &[]).unwrap();
+    merge_files(&mut commits, &parse_numstat_files("abc123\n\n1\t0\tdocs/README.md\n"));
+    assert_eq!(commits[0].files, vec!["docs/README.md".to_string()]);
+}
+
+#[test]
+fn test_parse_branch_map_strips_ref_prefix() {
+    let raw = "abc1\x00refs/heads/main\nabc2\x00refs/heads/hotfix/login\n";
+    let map = parse_branch_map(raw);
+    assert_eq!(map.get("abc1").map(String::as_str), Some("main"));
+    assert_eq!(map.get("abc2").map(String::as_str), Some("hotfix/login"));
+}
+
+#[test]
+fn test_conventional_commit_type() {
+    let raw = concat!(
+        "a1\x002026-01-05T10:00:00+00:00\x00dev@ex.com\x00Dev\x00fix: typo\x00END\n",
+        "a2\x002026-01-05T11:00:00+00:00\x00dev@ex.com\x00Dev\x00feat(api)!: new endpoint\x00END\n",
+        "a3\x002026-01-05T12:00:00+00:00\x00dev@ex.com\x00Dev\x00Update readme: wording\x00END\n",
+    );
+    let commits = parse_git_log(raw, &repo(), &[]).unwrap();
+    assert_eq!(commits[0].conventional_type(), Some("fix"));
+    assert_eq!(commits[1].conventional_type(), Some("feat"));
+    assert_eq!(commits[2].conventional_type(), None);
+}
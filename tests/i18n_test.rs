@@ -0,0 +1,154 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use chrono::{NaiveDate, NaiveTime};
+use rust_decimal_macros::dec;
+use gtt::config::{Settings, TimeFormat};
+use gtt::errors::GttError;
+use gtt::i18n::{
+    day_label, day_title, format_amount, format_date, format_day, format_money, format_month, format_number,
+    format_signed_money, format_time, set_formats, set_locale, t, tf, Formats, Locale,
+};
+
+fn keys(locale: Locale) -> BTreeSet<&'static str> {
+    locale.messages().iter().map(|(k, _)| *k).collect()
+}
+
+fn placeholders(message: &str) -> BTreeSet<String> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_catalogs_have_the_same_keys_and_placeholders() {
+    for locale in Locale::ALL {
+        assert_eq!(keys(locale).len(), locale.messages().len(), "duplicate keys in {}", locale.code());
+        assert_eq!(keys(locale), keys(Locale::En), "keys of {} differ from en", locale.code());
+    }
+    for (key, message) in Locale::Es.messages() {
+        let english = Locale::En.messages().iter().find(|(k, _)| k == key).unwrap().1;
+        assert_eq!(placeholders(message), placeholders(english), "placeholders of '{}'", key);
+    }
+}
+
+/// Every key used with `t("…")` or `tf("…"` in the sources exists
+#[test]
+fn test_used_keys_exist() {
+    fn visit(dir: &Path, found: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, found);
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                let source = fs::read_to_string(&path).unwrap();
+                for call in ["t(\"", "tf(\""] {
+                    for (i, _) in source.match_indices(call) {
+                        // Skip calls like `split("` that merely end in `t(`
+                        if source[..i].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                            continue;
+                        }
+                        let rest = &source[i + call.len()..];
+                        found.push(rest[..rest.find('"').unwrap()].to_string());
+                    }
+                }
+            }
+        }
+    }
+    let mut found = Vec::new();
+    visit(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut found);
+    assert!(found.len() > 100);
+    let known = keys(Locale::En);
+    let missing: Vec<&String> = found.iter().filter(|k| !known.contains(k.as_str())).collect();
+    assert!(missing.is_empty(), "keys missing from the catalogs: {:?}", missing);
+}
+
+#[test]
+fn test_locale_parse() {
+    assert_eq!(Locale::parse("es"), Some(Locale::Es));
+    assert_eq!(Locale::parse("es_AR.UTF-8"), Some(Locale::Es));
+    assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+    assert_eq!(Locale::parse("EN"), Some(Locale::En));
+    assert_eq!(Locale::parse("C"), None);
+    assert_eq!(Locale::parse("fr_FR.UTF-8"), None);
+    assert_eq!(Locale::resolve(Some("en_GB")), Locale::En);
+}
+
+#[test]
+fn test_messages_follow_the_locale() {
+    let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+    set_locale(Locale::Es);
+    assert_eq!(t("col.date"), "Fecha");
+    assert_eq!(day_label(date), "Lun 05/01");
+    assert_eq!(day_title(date), "Lunes 05/01/2026");
+    assert_eq!(GttError::ClientNotFound("acme".into()).to_string(), "El cliente 'acme' no está en la configuración.");
+
+    set_locale(Locale::En);
+    assert_eq!(t("col.date"), "Date");
+    assert_eq!(day_label(date), "Mon 05/01");
+    assert_eq!(tf("report.title", &[("client", &"Acme")]), "Hours report: Acme");
+    assert_eq!(GttError::ClientNotFound("acme".into()).to_string(), "Client 'acme' not found in configuration.");
+}
+
+#[test]
+fn test_default_formats() {
+    set_formats(Formats::default());
+    let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+    assert_eq!(format_date(date), "05/01/2026");
+    assert_eq!(format_day(date), "05/01");
+    assert_eq!(format_month(date), "01/2026");
+    assert_eq!(format_time(NaiveTime::from_hms_opt(14, 5, 0).unwrap()), "14:05");
+    assert_eq!(format_money(dec!(1234567.125)), "1234567.13");
+    assert_eq!(format_signed_money(dec!(21)), "+21.00");
+    assert_eq!(format_signed_money(dec!(-10)), "-10.00");
+    assert_eq!(format_amount(dec!(62.50)), "62.5");
+    assert_eq!(format_number(1.5, 2), "1.50");
+}
+
+#[test]
+fn test_formats_from_settings() {
+    let settings = Settings {
+        date_format: Some("%Y-%m-%d".to_string()),
+        time_format: TimeFormat::H12,
+        decimal_separator: Some(','),
+        thousands_separator: Some('.'),
+        ..Settings::default()
+    };
+    let (formats, invalid) = Formats::from_settings(&settings);
+    assert_eq!(invalid, None);
+    set_formats(formats);
+    set_locale(Locale::En);
+    let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+    assert_eq!(format_date(date), "2026-01-05");
+    assert_eq!(format_day(date), "01-05");
+    assert_eq!(day_label(date), "Mon 01-05");
+    assert_eq!(format_time(NaiveTime::from_hms_opt(14, 5, 0).unwrap()), "2:05 PM");
+    assert_eq!(format_time(NaiveTime::from_hms_opt(0, 30, 0).unwrap()), "12:30 AM");
+    assert_eq!(format_money(dec!(1234567.125)), "1.234.567,13");
+    assert_eq!(format_money(dec!(-999.5)), "-999,50");
+    assert_eq!(format_amount(dec!(62.5)), "62,5");
+
+    // Month and weekday names come from the catalog
+    set_formats(Formats { date: "%A %e %B %Y".to_string(), ..Formats::default() });
+    assert_eq!(format_date(date), "Monday  5 January 2026");
+    assert_eq!(format_day(date), "Monday  5 January");
+    assert_eq!(day_label(date), "Monday  5 January");
+    set_locale(Locale::Es);
+    assert_eq!(format_date(date), "Lunes  5 enero 2026");
+
+    let (formats, invalid) = Formats::from_settings(&Settings { date_format: Some("%d/%Q".to_string()), ..Settings::default() });
+    assert_eq!(invalid.as_deref(), Some("%d/%Q"));
+    assert_eq!(formats, Formats::default());
+
+    // A thousands separator equal to the decimal one is dropped
+    let (formats, _) = Formats::from_settings(&Settings { thousands_separator: Some('.'), ..Settings::default() });
+    assert_eq!(formats.thousands_separator, None);
+    let (formats, _) = Formats::from_settings(&Settings {
+        decimal_separator: Some(','),
+        thousands_separator: Some(','),
+        ..Settings::default()
+    });
+    assert_eq!((formats.decimal_separator, formats.thousands_separator), (',', None));
+}
@@ -0,0 +1,112 @@
+use rust_decimal_macros::dec;
+use gtt::import::llm::parse_usage;
+use gtt::import::org::parse_org_clocks;
+use gtt::import::parse_timestamp;
+use gtt::import::timewarrior::parse_timewarrior;
+
+#[test]
+fn test_parse_timestamp_formats() {
+    let expected = parse_timestamp("2026-01-05T09:30:00+00:00").unwrap();
+    assert_eq!(parse_timestamp("2026-01-05 09:30:00"), Some(expected));
+    assert_eq!(parse_timestamp("1767605400"), Some(expected));
+    assert_eq!(
+        parse_timestamp("2026-01-05"),
+        parse_timestamp("2026-01-05T00:00:00Z")
+    );
+    assert_eq!(parse_timestamp("yesterday"), None);
+}
+
+#[test]
+fn test_parse_usage_csv_with_loose_headers() {
+    let csv = "Date,Model,Cost_USD,Project\n\
+               2026-01-05 09:30:00,claude-sonnet,1.25,startupx\n\
+               2026-01-05 10:00:00,gpt-4o,$0.75,\n";
+    let entries = parse_usage(csv, None).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].cost, dec!(1.25));
+    assert_eq!(entries[0].currency, "USD");
+    assert_eq!(entries[0].provider, "csv");
+    assert_eq!(entries[0].model.as_deref(), Some("claude-sonnet"));
+    assert_eq!(entries[0].tag.as_deref(), Some("startupx"));
+    assert_eq!(entries[1].cost, dec!(0.75));
+    assert_eq!(entries[1].tag, None);
+
+    gtt::i18n::set_locale(gtt::i18n::Locale::En);
+    let err = parse_usage("date,cost\n2026-01-05,abc\n", None).unwrap_err();
+    assert!(err.to_string().contains("record 1"));
+}
+
+#[test]
+fn test_parse_usage_json_shapes() {
+    let records = r#"[{"timestamp": "2026-01-05T09:30:00Z", "cost": 2.5, "currency": "eur", "tag": "agency"}]"#;
+    let entries = parse_usage(records, None).unwrap();
+    assert_eq!(entries[0].provider, "json");
+    assert_eq!(entries[0].cost, dec!(2.5));
+    assert_eq!(entries[0].currency, "EUR");
+
+    let openai = r#"{"object": "page", "data": [
+        {"start_time": 1767571200, "end_time": 1767657600, "results": [
+            {"amount": {"value": 0.06, "currency": "usd"}, "line_item": "gpt-4o", "project_id": "proj_a"},
+            {"amount": {"value": 1.2, "currency": "usd"}, "line_item": null, "project_id": null}
+        ]},
+        {"start_time": 1767657600, "end_time": 1767744000, "results": []}
+    ]}"#;
+    let entries = parse_usage(openai, None).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].provider, "openai");
+    assert_eq!(entries[0].timestamp, parse_timestamp("2026-01-05").unwrap());
+    assert_eq!(entries[0].tag.as_deref(), Some("proj_a"));
+    assert_eq!(entries[1].model, None);
+
+    assert!(parse_usage(records, Some("xml")).is_err());
+    assert!(parse_usage("not an export", None).is_err());
+}
+
+#[test]
+fn test_parse_timewarrior_export() {
+    let json = r#"[
+        {"id":3,"start":"20260105T083000Z","end":"20260105T093000Z","tags":["Startup X","web","call"],"annotation":"Kickoff"},
+        {"id":2,"start":"20260105T100000Z","end":"20260105T101500Z","tags":["planning"]},
+        {"id":1,"start":"20260106T080000Z","end":"20260106T090000Z","tags":["Startup X","gtt"]},
+        {"id":0,"start":"20260107T080000Z","tags":["Startup X"]}
+    ]"#;
+    let intervals = parse_timewarrior(json).unwrap();
+    // gtt's own exports and the running interval are skipped
+    assert_eq!(intervals.len(), 2);
+    assert_eq!(intervals[0].start, parse_timestamp("2026-01-05T08:30:00Z").unwrap());
+    assert_eq!((intervals[0].end - intervals[0].start).num_minutes(), 60);
+    assert_eq!(intervals[0].tags, vec!["Startup X", "web", "call"]);
+    assert_eq!(intervals[0].description.as_deref(), Some("Kickoff"));
+    assert_eq!(intervals[1].description, None);
+
+    assert!(parse_timewarrior(r#"[{"start":"yesterday","end":"20260105T101500Z"}]"#).is_err());
+}
+
+#[test]
+fn test_parse_org_clocks() {
+    let org = "\
+#+TITLE: Work
+* Startup X :client:
+** Sprint planning :web:
+   :LOGBOOK:
+   CLOCK: [2026-01-05 Mon 08:30]--[2026-01-05 Mon 09:45] =>  1:15
+   CLOCK: [2026-01-05 Mon 14:00]
+   :END:
+** Exported :gtt:
+   CLOCK: [2026-01-05 Mon 10:00]--[2026-01-05 Mon 11:00] =>  1:00
+* Agency Y
+   CLOCK: [2026-01-06 10:00]--[2026-01-06 10:30] =>  0:30
+";
+    let intervals = parse_org_clocks(org).unwrap();
+    assert_eq!(intervals.len(), 2);
+    let first = &intervals[0];
+    assert_eq!(first.start.naive_local().to_string(), "2026-01-05 08:30:00");
+    assert_eq!((first.end - first.start).num_minutes(), 75);
+    assert_eq!(first.tags, vec!["Startup X", "client", "Sprint planning", "web"]);
+    assert_eq!(first.description.as_deref(), Some("Sprint planning"));
+    // Headings close when a heading of the same or higher level starts
+    assert_eq!(intervals[1].tags, vec!["Agency Y"]);
+    assert_eq!((intervals[1].end - intervals[1].start).num_minutes(), 30);
+
+    assert!(parse_org_clocks("* A\nCLOCK: [2026-13-05 Mon 08:30]--[2026-01-05 Mon 09:45]").is_err());
+}
@@ -0,0 +1,412 @@
+use chrono::DateTime;
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+use gtt::config::{AccountsConfig, ClientConfig, RepoAttribution, TaxRule, TimeFormat};
+use gtt::git::Commit;
+use gtt::i18n::{set_formats, set_locale, Formats, Locale};
+use gtt::output::csv::serialize_csv;
+use gtt::output::html::serialize_html;
+use gtt::output::ics::serialize_ics;
+use gtt::output::json_fmt::serialize_json;
+use gtt::output::ledger::{serialize_ledger, serialize_timeclock, LedgerFlavor, Postings};
+use gtt::import::org::parse_org_clocks;
+use gtt::import::timewarrior::parse_timewarrior;
+use gtt::output::markdown::serialize_markdown;
+use gtt::output::ndjson::write_ndjson;
+use gtt::output::ods::{openformula, write_ods};
+use gtt::output::org::serialize_org;
+use gtt::output::spreadsheet::{build_workbook, Value};
+use gtt::output::time_entries::{import_target, serialize_time_entries, HARVEST};
+use gtt::output::timewarrior::serialize_timewarrior;
+use gtt::output::xlsx::write_xlsx;
+use gtt::output::Detail;
+use gtt::session::{analyze, apply_rates, group_by_day, ClientReport, DayReport, GroupBy};
+use gtt::store::{Expense, ManualTimeStore};
+
+mod common;
+use common::{default_settings, make_commit_with_volume, make_report};
+
+fn make_commit(hash: &str, date_str: &str, subject: &str) -> Commit {
+    Commit {
+        subject: subject.to_string(),
+        ..make_commit_with_volume(hash, date_str, "web", 10, 2)
+    }
+}
+
+/// One session on 2026-01-05 (09:00-09:30, 60 minutes) and one on 2026-01-06 (30 minutes), at 60/h
+fn sample_report() -> ClientReport {
+    let commits = vec![
+        make_commit("aaaaaaa1111", "2026-01-05T09:00:00+00:00", "Add login form"),
+        make_commit("bbbbbbb2222", "2026-01-05T09:30:00+00:00", "Fix *bold* | pipe"),
+        make_commit("ccccccc3333", "2026-01-06T10:00:00+00:00", "Deploy"),
+    ];
+    let mut days: Vec<DayReport> = group_by_day(analyze(commits, &default_settings()));
+    apply_rates(&mut days, &ClientConfig { hourly_rate: dec!(60), ..Default::default() }, RepoAttribution::Commits);
+    ClientReport {
+        client_name: "Startup X".to_string(),
+        commit_urls: HashMap::from([("web".to_string(), "https://git.example.com/web/commit/{hash}".to_string())]),
+        ..make_report(days)
+    }
+}
+
+#[test]
+fn test_markdown_report() {
+    let report = sample_report();
+    let md = serialize_markdown(&report, false);
+    assert!(md.starts_with("# Reporte de horas: Startup X\n"));
+    assert!(md.contains("| Fecha | Sesiones | Horas | Commits | +/- | Repos |"));
+    assert!(md.contains("| Lun 05/01 | 1 | 1h | 2 | +20 -4 | web |"));
+    assert!(md.contains("| **Total** | **2** | **1h 30m** | **3** | **+30 -6** |  |"));
+    assert!(md.contains("**Monto:** 1.50h × 60/h = 90.00 USD"));
+    assert!(!md.contains("## Sesiones"));
+
+    let md = serialize_markdown(&report, true);
+    assert!(md.contains("- **Sesión 1:** 09:00 → 09:30 (1h, 2 commits, +20 -4)"));
+    assert!(md.contains(
+        "  - 09:30 [`bbbbbbb`](https://git.example.com/web/commit/bbbbbbb2222) Fix \\*bold\\* \\| pipe (+10 -2)"
+    ));
+}
+
+#[test]
+fn test_markdown_report_in_english() {
+    set_locale(Locale::En);
+    let report = sample_report();
+    let md = serialize_markdown(&report, true);
+    assert!(md.starts_with("# Hours report: Startup X\n"));
+    assert!(md.contains("| Date | Sessions | Hours | Commits | +/- | Repos |"));
+    assert!(md.contains("| Mon 05/01 | 1 | 1h | 2 | +20 -4 | web |"));
+    assert!(md.contains("- **Session 1:** 09:00 → 09:30 (1h, 2 commits, +20 -4)"));
+    assert!(!md.contains("Sesión"));
+}
+
+#[test]
+fn test_display_formats_leave_machine_formats_alone() {
+    set_formats(Formats {
+        date: "%m/%d/%Y".to_string(),
+        time: TimeFormat::H12,
+        decimal_separator: ',',
+        thousands_separator: Some('.'),
+    });
+    let report = sample_report();
+    let md = serialize_markdown(&report, true);
+    assert!(md.contains("**Periodo:** 01/05/2026 — 01/06/2026"));
+    assert!(md.contains("| Lun 01/05 |"));
+    assert!(md.contains("1,50h × 60/h = 90,00 USD"));
+    assert!(md.contains("9:00 AM → 9:30 AM"));
+
+    let csv = serialize_csv(&report, None, GroupBy::Day).unwrap();
+    assert!(csv.contains("2026-01-05,") && csv.contains("60.00"));
+    let json: serde_json::Value = serde_json::from_str(&serialize_json(&report, None, GroupBy::Day).unwrap()).unwrap();
+    assert_eq!(json["days"][0]["date"], "2026-01-05");
+    set_formats(Formats::default());
+}
+
+#[test]
+fn test_html_report_is_self_contained() {
+    let mut report = sample_report();
+    report.client_name = "Startup <X>".to_string();
+    let html = serialize_html(&report);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Reporte de horas: Startup &lt;X&gt;</title>"));
+    assert_eq!(html.matches("<svg ").count(), 4);
+    assert!(html.contains("<details>"));
+    assert!(html.contains("<a href=\"https://git.example.com/web/commit/aaaaaaa1111\"><code>aaaaaaa</code></a> Add login form"));
+    // Nothing is loaded from outside the file
+    assert!(!html.contains("<script src") && !html.contains("<link") && !html.contains("<img"));
+    assert!(!html.contains("url("));
+}
+
+#[test]
+fn test_ics_events_per_session() {
+    let report = sample_report();
+    let stamp = DateTime::parse_from_rfc3339("2026-02-01T12:00:00Z").unwrap().to_utc();
+    let ics = serialize_ics(&report, stamp);
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    // Starts 30 base minutes before the first commit, ends at the last one
+    assert!(ics.contains("UID:aaaaaaa1111@gtt\r\nDTSTAMP:20260201T120000Z\r\nDTSTART:20260105T083000Z\r\nDTEND:20260105T093000Z\r\n"));
+    assert!(ics.contains("SUMMARY:Startup X — web\r\n"));
+    assert!(ics.contains("DESCRIPTION:09:00 aaaaaaa Add login form\\n09:30 bbbbbbb Fix *bold* | pipe\r\n"));
+    assert!(ics.lines().all(|l| l.len() <= 75));
+    // Same sessions, same UIDs
+    assert_eq!(ics, serialize_ics(&report, stamp));
+
+    // Manual time has no base minutes and keeps its own interval
+    let mut report = report;
+    let mut manual = ManualTimeStore::default();
+    let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+    manual.add("Startup X", at("2026-01-06T14:00:00+00:00"), at("2026-01-06T15:00:00+00:00"), "Call", None, "org");
+    report.days[1].sessions.push(manual.entries[0].to_session());
+    let ics = serialize_ics(&report, stamp);
+    assert!(ics.contains("DTSTART:20260106T140000Z\r\nDTEND:20260106T150000Z\r\n"));
+}
+
+#[test]
+fn test_time_entry_exports() {
+    let report = sample_report();
+    let toggl = serialize_time_entries(&report, import_target("toggl").unwrap()).unwrap();
+    let mut lines = toggl.lines();
+    assert_eq!(
+        lines.next(),
+        Some("Email,Client,Project,Description,Billable,Start date,Start time,End date,End time,Duration,Tags")
+    );
+    assert_eq!(
+        lines.next(),
+        Some("dev@example.com,Startup X,Startup X,Add login form; Fix *bold* | pipe,Yes,2026-01-05,08:30:00,2026-01-05,09:30:00,01:00:00,web")
+    );
+    assert_eq!(lines.count(), 1);
+
+    let harvest = serialize_time_entries(&report, &HARVEST).unwrap();
+    assert!(harvest.contains("2026-01-06,Startup X,Startup X,Development,Deploy,0.50,Dev,\n"));
+    assert!(import_target("kimai").is_none());
+}
+
+#[test]
+fn test_plain_text_accounting_exports() {
+    let mut report = sample_report();
+    report.tax_rules = vec![TaxRule { name: "VAT".to_string(), percent: dec!(21), withholding: false }];
+    let accounts = AccountsConfig { income: Some("Income:Dev".to_string()), ..Default::default() };
+
+    let timeclock = serialize_timeclock(&report, &accounts);
+    assert!(timeclock.starts_with("i 2026/01/05 08:30:00 Clients:StartupX  web: Add login form; Fix *bold* | pipe\no 2026/01/05 09:30:00\n"));
+
+    // 90 minutes at 60/h = 90.00, +21% VAT = 108.90
+    let ledger = serialize_ledger(&report, &accounts, LedgerFlavor::Ledger, Postings::Period).unwrap();
+    let lines: Vec<&str> = ledger.lines().map(str::trim_end).collect();
+    assert_eq!(lines[0], "2026-01-06 * Startup X | Horas 05/01/2026 — 06/01/2026");
+    assert!(lines[1].starts_with("    Assets:Receivable:StartupX") && lines[1].ends_with(" 108.90 USD"));
+    assert!(lines[2].starts_with("    Income:Dev") && lines[2].ends_with(" -90.00 USD"));
+    assert!(lines[3].starts_with("    Liabilities:Taxes:VAT") && lines[3].ends_with(" -18.90 USD"));
+    assert_eq!(lines.len(), 5);
+
+    // Fractions of a cent add up to one on the invoice: 90.008 → 90.01, +18.90 VAT
+    let mut cents = report.clone();
+    cents.llm_cost = dec!(0.004);
+    cents.expenses = vec![Expense {
+        id: 1,
+        client: "Startup X".to_string(),
+        date: "2026-01-05".parse().unwrap(),
+        amount: dec!(0.004),
+        description: "API".to_string(),
+        repo: None,
+    }];
+    let ledger = serialize_ledger(&cents, &accounts, LedgerFlavor::Ledger, Postings::Period).unwrap();
+    let receivable = ledger.lines().find(|l| l.trim_start().starts_with("Assets:Receivable")).unwrap();
+    let amount: rust_decimal::Decimal = receivable.split_whitespace().nth(1).unwrap().parse().unwrap();
+    assert_eq!(amount, cents.invoice().total());
+    assert_eq!(amount, dec!(108.91));
+
+    // Day postings can't carry the invoice's discounts and taxes
+    assert!(serialize_ledger(&report, &accounts, LedgerFlavor::Beancount, Postings::Day).is_err());
+    report.tax_rules.clear();
+    let beancount = serialize_ledger(&report, &accounts, LedgerFlavor::Beancount, Postings::Day).unwrap();
+    assert!(beancount.starts_with("2026-01-05 open Assets:Receivable:StartupX USD\n2026-01-05 open Income:Dev USD\n\n"));
+    assert!(beancount.contains("2026-01-05 * \"Startup X\" \"Facturable 05/01/2026\"\n  Assets:Receivable:StartupX"));
+    assert!(beancount.contains("2026-01-06 * \"Startup X\""));
+    assert!(!beancount.contains("Taxes"));
+}
+
+#[test]
+fn test_timewarrior_and_org_exports() {
+    let report = sample_report();
+
+    let timew = serialize_timewarrior(&report).unwrap();
+    let intervals: Vec<serde_json::Value> = serde_json::from_str(&timew).unwrap();
+    assert_eq!(intervals.len(), 2);
+    assert_eq!(intervals[0]["start"], "20260105T083000Z");
+    assert_eq!(intervals[0]["end"], "20260105T093000Z");
+    assert_eq!(intervals[0]["tags"], serde_json::json!(["Startup X", "web", "gtt"]));
+    assert_eq!(intervals[1]["annotation"], "Deploy");
+
+    let org = serialize_org(&report);
+    assert!(org.starts_with("* Startup X :gtt:\n** 2026-01-05 Mon\n*** web: Add login form; Fix *bold* | pipe\n"));
+    assert!(org.contains("    CLOCK: [2026-01-05 Mon 08:30]--[2026-01-05 Mon 09:30] =>  1:00\n"));
+    assert!(org.contains("[2026-01-06 Tue 10:00] =>  0:30\n"));
+
+    // gtt's own exports are not imported back as manual time
+    assert!(parse_timewarrior(&timew).unwrap().is_empty());
+    assert!(parse_org_clocks(&org).unwrap().is_empty());
+}
+
+#[test]
+fn test_json_and_csv_detail() {
+    let mut report = sample_report();
+    report.llm_cost_per_kloc = dec!(50);
+
+    let json: serde_json::Value = serde_json::from_str(&serialize_json(&report, None, GroupBy::Day).unwrap()).unwrap();
+    let day = &json["days"][0];
+    // 24 lines at 50/kloc = 1.20 on top of 60.00
+    assert_eq!(day["llm_cost"], 1.2);
+    assert_eq!(day["total_amount"], 61.2);
+    assert!(day.get("session_detail").is_none());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&serialize_json(&report, Some(Detail::Sessions), GroupBy::Day).unwrap()).unwrap();
+    let session = &json["days"][0]["session_detail"][0];
+    assert_eq!(session["start"], "2026-01-05T09:00:00+00:00");
+    assert_eq!(session["duration_minutes"], 60);
+    assert_eq!(session["components"], serde_json::json!({
+        "base_minutes": 30, "gap_minutes": 30, "volume_minutes": 0, "manual_minutes": 0
+    }));
+    assert!(session.get("commits").is_none());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&serialize_json(&report, Some(Detail::Commits), GroupBy::Day).unwrap()).unwrap();
+    let commit = &json["days"][0]["session_detail"][0]["commits"][1];
+    assert_eq!(commit["hash"], "bbbbbbb2222");
+    assert_eq!(commit["time"], "2026-01-05T09:30:00+00:00");
+    assert_eq!(commit["repo"], "web");
+    assert_eq!(commit["url"], "https://git.example.com/web/commit/bbbbbbb2222");
+
+    let csv = serialize_csv(&report, Some(Detail::Sessions), GroupBy::Day).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("date,session,start,end,minutes,base_minutes,gap_minutes,volume_minutes,manual_minutes,commits,repos,lines_added,lines_deleted,description")
+    );
+    assert_eq!(
+        lines.next(),
+        Some("2026-01-05,1,2026-01-05T09:00:00+00:00,2026-01-05T09:30:00+00:00,60,30,30,0,0,2,web,20,4,Add login form; Fix *bold* | pipe")
+    );
+    assert_eq!(lines.count(), 1);
+
+    let csv = serialize_csv(&report, Some(Detail::Commits), GroupBy::Day).unwrap();
+    assert_eq!(csv.lines().count(), 4);
+    assert!(csv.contains("\n2026-01-06,1,30,2026-01-06T10:00:00+00:00,ccccccc3333,web,Deploy,10,2\n"));
+}
+
+#[test]
+fn test_grouped_csv_and_json() {
+    let report = sample_report();
+    let csv = serialize_csv(&report, None, GroupBy::Week).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("week,days,sessions,hours,minutes,billed_minutes,overflow_minutes,covered_minutes,commits,repos,amount,llm_cost,total_amount,currency,lines_added,lines_deleted")
+    );
+    assert_eq!(lines.next(), Some("2026-W02,2,2,1.5000,90,90,0,0,3,web,90.00,0.00,90.00,USD,30,6"));
+    assert_eq!(lines.next(), None);
+
+    let csv = serialize_csv(&report, None, GroupBy::Repo).unwrap();
+    assert!(csv.starts_with("repo,days,"));
+    assert!(csv.contains("\nweb,2,2,1.5000,90,"));
+    assert!(serialize_csv(&report, Some(Detail::Sessions), GroupBy::Repo).is_err());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&serialize_json(&report, None, GroupBy::Month).unwrap()).unwrap();
+    assert_eq!(json["group_by"], "month");
+    assert_eq!(json["groups"][0]["key"], "2026-01");
+    assert_eq!(json["groups"][0]["start"], "2026-01-01");
+    assert_eq!(json["groups"][0]["days"], 2);
+    assert_eq!(json["groups"][0]["total_minutes"], 90);
+    assert_eq!(json["days"].as_array().unwrap().len(), 2);
+
+    let json: serde_json::Value = serde_json::from_str(&serialize_json(&report, None, GroupBy::Day).unwrap()).unwrap();
+    assert!(json.get("groups").is_none());
+}
+
+#[test]
+fn test_ndjson_streams_records_per_client() {
+    let first = sample_report();
+    let mut second = sample_report();
+    second.client_name = "Agency Y".to_string();
+    second.currency = "EUR".to_string();
+
+    let mut out = Vec::new();
+    write_ndjson(&mut out, &first, None).unwrap();
+    write_ndjson(&mut out, &second, None).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let records: Vec<serde_json::Value> = text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0]["record"], "day");
+    assert_eq!(records[0]["client"], "Startup X");
+    assert_eq!(records[0]["date"], "2026-01-05");
+    assert_eq!(records[0]["total_amount"], 60.0);
+    assert_eq!(records[3]["client"], "Agency Y");
+    assert_eq!(records[3]["currency"], "EUR");
+    assert!(text.lines().all(|l| l.matches("\"date\":").count() == 1));
+
+    let mut out = Vec::new();
+    write_ndjson(&mut out, &first, Some(Detail::Sessions)).unwrap();
+    let session: serde_json::Value = serde_json::from_str(String::from_utf8(out).unwrap().lines().next().unwrap()).unwrap();
+    assert_eq!(session["record"], "session");
+    assert_eq!(session["date"], "2026-01-05");
+    assert_eq!(session["components"]["gap_minutes"], 30);
+
+    let mut out = Vec::new();
+    write_ndjson(&mut out, &first, Some(Detail::Commits)).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text.lines().count(), 3);
+    let commit: serde_json::Value = serde_json::from_str(text.lines().nth(1).unwrap()).unwrap();
+    assert_eq!(commit["record"], "commit");
+    assert_eq!(commit["hash"], "bbbbbbb2222");
+    assert_eq!(commit["session_start"], "2026-01-05T09:00:00+00:00");
+}
+
+fn zip_entry(data: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    let mut text = String::new();
+    std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut text).unwrap();
+    text
+}
+
+#[test]
+fn test_spreadsheet_formulas_follow_the_rate_cell() {
+    let book = build_workbook(&[sample_report()]);
+    let names: Vec<&str> = book.sheets.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Startup X", "Startup X - días", "Startup X - sesiones"]);
+
+    let summary = &book.sheets[0];
+    assert_eq!(summary.rows[4][1].value, Value::Number(60.0));
+    let days = &book.sheets[1];
+    assert_eq!(days.rows[1][0].value, Value::Date("2026-01-05".parse().unwrap()));
+    assert_eq!(days.rows[1][5].value, Value::Formula("'Startup X'!$B$5".to_string(), 60.0));
+    assert_eq!(days.rows[1][6].value, Value::Formula("(D2-E2)/60*F2".to_string(), 60.0));
+    let totals = days.rows.last().unwrap();
+    assert_eq!(totals[8].value, Value::Formula("SUM(I2:I3)".to_string(), 90.0));
+    let total = summary.rows.last().unwrap();
+    assert!(matches!(total[1].value, Value::Formula(_, cached) if cached == 90.0));
+    // The subtotal adds up the hours amount, LLM and expenses rows
+    assert_eq!(summary.rows[11][1].value, Value::Formula("ROUND(B9+B10+B11,2)".to_string(), 90.0));
+    // Header, two sessions and the totals row
+    assert_eq!(book.sheets[2].rows.len(), 4);
+
+    assert_eq!(openformula("(D2-E2)/60*F2"), "([.D2]-[.E2])/60*[.F2]");
+    assert_eq!(openformula("ROUND(SUM(G2:G9),2)"), "ROUND(SUM([.G2:.G9]);2)");
+    assert_eq!(openformula("'Startup X - días'!$I$4/1440"), "[$'Startup X - días'.$I$4]/1440");
+
+    let xlsx = write_xlsx(&book).unwrap();
+    assert!(zip_entry(&xlsx, "xl/worksheets/sheet2.xml").contains("<f>(D2-E2)/60*F2</f>"));
+
+    let ods = write_ods(&book).unwrap();
+    let archive = zip::ZipArchive::new(std::io::Cursor::new(&ods[..])).unwrap();
+    assert_eq!(archive.name_for_index(0), Some("mimetype"));
+    let content = zip_entry(&ods, "content.xml");
+    assert!(content.contains(r#"table:formula="of:=([.D2]-[.E2])/60*[.F2]""#));
+    assert!(content.contains(r#"table:name="Startup X - sesiones""#));
+}
+
+#[test]
+fn test_spreadsheet_and_journal_dates_follow_date_format() {
+    set_formats(Formats { date: "%Y-%m-%d".to_string(), time: TimeFormat::H12, ..Formats::default() });
+    let report = sample_report();
+    let book = build_workbook(std::slice::from_ref(&report));
+
+    let styles = zip_entry(&write_xlsx(&book).unwrap(), "xl/styles.xml");
+    assert!(styles.contains(r#"formatCode="yyyy&quot;-&quot;mm&quot;-&quot;dd""#));
+    assert!(styles.contains(r#"formatCode="yyyy&quot;-&quot;mm&quot;-&quot;dd&quot; &quot;h&quot;:&quot;mm&quot; &quot;AM/PM""#));
+
+    let content = zip_entry(&write_ods(&book).unwrap(), "content.xml");
+    assert!(content.contains(concat!(
+        r#"<number:date-style style:name="N_date"><number:year number:style="long"/><number:text>-</number:text>"#,
+        r#"<number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/></number:date-style>"#,
+    )));
+    assert!(content.contains("<number:hours/><number:text>:</number:text>"));
+    assert!(content.contains("<text:p>2026-01-05</text:p>"));
+    assert!(content.contains("<text:p>2026-01-05 9:00 AM</text:p>"));
+
+    let ledger = serialize_ledger(&report, &AccountsConfig::default(), LedgerFlavor::Ledger, Postings::Day).unwrap();
+    assert!(ledger.contains("Facturable 2026-01-05"));
+    assert!(!ledger.contains("05/01/2026"));
+}
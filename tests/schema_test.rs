@@ -0,0 +1,135 @@
+use chrono::{DateTime, NaiveDate};
+use rust_decimal_macros::dec;
+use serde_json::Value;
+use std::collections::HashMap;
+use gtt::config::{ClientConfig, DiscountRule, RepoAttribution, Settings, TaxRule};
+use gtt::output::json_fmt::{serialize_json, REPORT_SCHEMA, SCHEMA_VERSION};
+use gtt::output::Detail;
+use gtt::session::{
+    analyze, apply_rates, convert_report, group_by_day, ClientReport, DayReport, ExchangeRates, GroupBy, RateDate,
+};
+use gtt::store::{Expense, ManualTimeStore};
+
+mod common;
+use common::{default_settings, make_commit_with_volume, make_report};
+
+/// A report using every optional part of the JSON: expenses, discounts, taxes,
+/// a currency conversion, a manual session and commit URLs
+fn full_report() -> ClientReport {
+    let settings = Settings { volume_adjustment: true, ..default_settings() };
+    let mut sessions = analyze(
+        vec![
+            make_commit_with_volume("aaaaaaa1111", "2026-01-05T09:00:00-03:00", "web", 40, 8),
+            make_commit_with_volume("bbbbbbb2222", "2026-01-05T10:15:00-03:00", "api", 40, 8),
+            make_commit_with_volume("ccccccc3333", "2026-01-07T16:00:00-03:00", "web", 40, 8),
+        ],
+        &settings,
+    );
+    let mut manual = ManualTimeStore::default();
+    let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+    manual.add("Client", at("2026-01-06T14:00:00-03:00"), at("2026-01-06T15:00:00-03:00"), "Call", None, "org");
+    sessions.extend(manual.entries.iter().map(|e| e.to_session()));
+    sessions.sort_by_key(|s| s.start);
+
+    let mut days: Vec<DayReport> = group_by_day(sessions);
+    apply_rates(&mut days, &ClientConfig { hourly_rate: dec!(60), ..Default::default() }, RepoAttribution::Commits);
+    let mut report = ClientReport {
+        period_start: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+        period_end: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        llm_cost_per_kloc: dec!(20),
+        llm_cost: dec!(2.88),
+        expenses: vec![Expense {
+            id: 1,
+            client: "Client".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+            amount: dec!(12.99),
+            description: "Domain".to_string(),
+            repo: None,
+        }],
+        discounts: vec![DiscountRule { name: "Fixed".to_string(), percent: None, amount: Some(dec!(5)) }],
+        tax_rules: vec![TaxRule { name: "VAT".to_string(), percent: dec!(21), withholding: false }],
+        commit_urls: HashMap::from([("web".to_string(), "https://git.example.com/web/commit/{hash}".to_string())]),
+        ..make_report(days)
+    };
+    let rates = ExchangeRates::parse("date,from,to,rate\n2026-01-01,USD,EUR,0.90\n").unwrap();
+    report.converted = Some(convert_report(&report, &rates, "EUR", RateDate::Day).unwrap());
+    report
+}
+
+fn schema() -> Value {
+    serde_json::from_str(REPORT_SCHEMA).unwrap()
+}
+
+/// The schema with `additionalProperties: false` on every object, so fields
+/// missing from the published schema fail validation
+fn strict(mut schema: Value) -> Value {
+    fn close(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if map.contains_key("properties") {
+                    map.insert("additionalProperties".to_string(), Value::Bool(false));
+                }
+                map.values_mut().for_each(close);
+            }
+            Value::Array(items) => items.iter_mut().for_each(close),
+            _ => {}
+        }
+    }
+    close(&mut schema);
+    schema
+}
+
+fn assert_valid(schema: &Value, report: &Value) {
+    let validator = jsonschema::validator_for(schema).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(report)
+        .map(|e| format!("{} at {}", e, e.instance_path))
+        .collect();
+    assert!(errors.is_empty(), "report does not match the schema:\n{}", errors.join("\n"));
+}
+
+#[test]
+fn test_reports_match_published_schema() {
+    let schema = schema();
+    assert!(jsonschema::meta::is_valid(&schema));
+    let strict = strict(schema.clone());
+
+    let report = full_report();
+    for detail in [None, Some(Detail::Sessions), Some(Detail::Commits)] {
+        let json: Value = serde_json::from_str(&serialize_json(&report, detail, GroupBy::Day).unwrap()).unwrap();
+        assert_valid(&schema, &json);
+        assert_valid(&strict, &json);
+    }
+    for group_by in [GroupBy::Week, GroupBy::Month, GroupBy::Repo, GroupBy::Weekday] {
+        let json: Value = serde_json::from_str(&serialize_json(&report, None, group_by).unwrap()).unwrap();
+        assert_valid(&strict, &json);
+        assert_eq!(json["group_by"], group_by.name());
+    }
+
+    let mut plain = full_report();
+    plain.converted = None;
+    plain.expenses.clear();
+    let json: Value = serde_json::from_str(&serialize_json(&plain, None, GroupBy::Day).unwrap()).unwrap();
+    assert_valid(&strict, &json);
+    assert_eq!(json["schema_version"], SCHEMA_VERSION);
+}
+
+#[test]
+fn test_schema_rejects_changed_fields() {
+    let validator = jsonschema::validator_for(&schema()).unwrap();
+    let mut json: Value = serde_json::from_str(&serialize_json(&full_report(), None, GroupBy::Day).unwrap()).unwrap();
+    assert!(validator.is_valid(&json));
+
+    // Renamed field: the old name is required
+    let total = json.as_object_mut().unwrap().remove("total_amount").unwrap();
+    json["total"] = total;
+    assert!(!validator.is_valid(&json));
+
+    let mut json: Value = serde_json::from_str(&serialize_json(&full_report(), None, GroupBy::Day).unwrap()).unwrap();
+    json["days"][0]["billed_minutes"] = Value::String("60".to_string());
+    assert!(!validator.is_valid(&json));
+
+    json["days"][0]["billed_minutes"] = Value::from(60);
+    json["schema_version"] = Value::from(SCHEMA_VERSION + 1);
+    assert!(!validator.is_valid(&json));
+}
@@ -1,48 +1,10 @@
 use chrono::DateTime;
-use std::path::PathBuf;
-use gtt::git::Commit;
 use gtt::config::Settings;
-use gtt::session::{analyze, group_by_day};
+use gtt::session::{analyze, group_by_day, trim_manual_overlaps};
+use gtt::store::ManualTimeStore;
 
-fn make_commit(hash: &str, date_str: &str, repo: &str) -> Commit {
-    Commit {
-        hash: hash.to_string(),
-        author_date: DateTime::parse_from_rfc3339(date_str).unwrap(),
-        author_email: "dev@example.com".to_string(),
-        author_name: "Dev".to_string(),
-        subject: format!("commit {}", hash),
-        repo_path: PathBuf::from(format!("/repos/{}", repo)),
-        repo_name: repo.to_string(),
-        lines_added: 0,
-        lines_deleted: 0,
-    }
-}
-
-fn make_commit_with_volume(hash: &str, date_str: &str, repo: &str, added: u32, deleted: u32) -> Commit {
-    Commit {
-        hash: hash.to_string(),
-        author_date: DateTime::parse_from_rfc3339(date_str).unwrap(),
-        author_email: "dev@example.com".to_string(),
-        author_name: "Dev".to_string(),
-        subject: format!("commit {}", hash),
-        repo_path: PathBuf::from(format!("/repos/{}", repo)),
-        repo_name: repo.to_string(),
-        lines_added: added,
-        lines_deleted: deleted,
-    }
-}
-
-fn default_settings() -> Settings {
-    Settings {
-        session_gap_minutes: 120,
-        first_commit_minutes: 30,
-        exclude_weekends: false,
-        bot_authors: vec![],
-        volume_adjustment: false,
-        volume_factor: 5.0,
-        volume_scale: 50.0,
-    }
-}
+mod common;
+use common::{default_settings, make_commit, make_commit_with_volume};
 
 fn volume_settings() -> Settings {
     Settings {
@@ -256,3 +218,42 @@ fn test_volume_lines_aggregated_in_day_report() {
     assert_eq!(days[0].total_lines_added, 150);
     assert_eq!(days[0].total_lines_deleted, 30);
 }
+
+#[test]
+fn test_manual_time_overlapping_commit_session_is_trimmed() {
+    // Git session 09:30-10:30 (30 base minutes before the 10:00 commit)
+    let commits = vec![
+        make_commit("a1", "2026-01-05T10:00:00+00:00", "web"),
+        make_commit("a2", "2026-01-05T10:30:00+00:00", "web"),
+    ];
+    let git = analyze(commits, &default_settings());
+    let mut store = ManualTimeStore::default();
+    let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+    // Tracked 09:00-11:00 while committing, and a call that doesn't overlap
+    store.add("Client", at("2026-01-05T09:00:00+00:00"), at("2026-01-05T11:00:00+00:00"), "Work", None, "timewarrior");
+    store.add("Client", at("2026-01-05T15:00:00+00:00"), at("2026-01-05T15:45:00+00:00"), "Call", None, "org");
+    let manual = store.entries.iter().map(|e| e.to_session()).collect();
+
+    let (kept, trimmed) = trim_manual_overlaps(&git, manual);
+    assert_eq!(trimmed, 60);
+    let spans: Vec<(String, String, u32)> = kept
+        .iter()
+        .map(|s| (s.start.format("%H:%M").to_string(), s.end.format("%H:%M").to_string(), s.duration_minutes))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("09:00".to_string(), "09:30".to_string(), 30),
+            ("10:30".to_string(), "11:00".to_string(), 30),
+            ("15:00".to_string(), "15:45".to_string(), 45),
+        ]
+    );
+    assert!(kept.iter().all(|s| s.parts.manual == s.duration_minutes && s.note.is_some()));
+
+    // An entry inside a git session is dropped entirely
+    let mut store = ManualTimeStore::default();
+    store.add("Client", at("2026-01-05T09:45:00+00:00"), at("2026-01-05T10:15:00+00:00"), "Work", None, "timewarrior");
+    let (kept, trimmed) = trim_manual_overlaps(&git, store.entries.iter().map(|e| e.to_session()).collect());
+    assert!(kept.is_empty());
+    assert_eq!(trimmed, 30);
+}
@@ -0,0 +1,156 @@
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+use gtt::session::receivables::{receivables, AgingBucket};
+use gtt::store::{ExpenseStore, InvoiceRecord, InvoiceStore, LlmUsageStore, ManualTimeStore, Payment};
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_expense_store_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("expenses.toml");
+
+    let mut store = ExpenseStore::load_from(&path).unwrap();
+    assert!(store.expenses.is_empty());
+    store.add("Startup X", date("2026-01-10"), dec!(12.99), "Domain renewal", None);
+    store.add("Startup X", date("2026-01-03"), dec!(40), "Hosting", Some("startupx-web".to_string()));
+    store.save_to(&path).unwrap();
+
+    let loaded = ExpenseStore::load_from(&path).unwrap();
+    assert_eq!(loaded.expenses, store.expenses);
+    assert_eq!(loaded.expenses[0].amount, dec!(12.99));
+    assert_eq!(loaded.expenses[1].repo.as_deref(), Some("startupx-web"));
+}
+
+#[test]
+fn test_expense_ids_and_period_filter() {
+    let mut store = ExpenseStore::default();
+    store.add("Startup X", date("2026-01-10"), dec!(10), "A", None);
+    store.add("Agency Y", date("2026-01-11"), dec!(20), "B", None);
+    store.add("Startup X", date("2026-01-02"), dec!(30), "C", None);
+    store.add("Startup X", date("2026-02-01"), dec!(40), "D", None);
+    assert_eq!(store.expenses.iter().map(|e| e.id).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    let january = store.for_client("Startup X", date("2026-01-01"), date("2026-01-31"));
+    let descriptions: Vec<_> = january.iter().map(|e| e.description.as_str()).collect();
+    assert_eq!(descriptions, vec!["C", "A"]);
+
+    assert!(store.remove(3));
+    assert!(!store.remove(3));
+    // New ids continue after the highest one in use
+    assert_eq!(store.add("Startup X", date("2026-01-05"), dec!(5), "E", None).id, 5);
+}
+
+fn invoice(number: &str, client: &str, issued: &str, amount: rust_decimal::Decimal) -> InvoiceRecord {
+    InvoiceRecord {
+        number: number.to_string(),
+        client: client.to_string(),
+        issued: date(issued),
+        period_start: date("2026-01-01"),
+        period_end: date("2026-01-31"),
+        amount,
+        currency: "USD".to_string(),
+        payments: vec![],
+    }
+}
+
+#[test]
+fn test_invoice_numbers_and_partial_payments() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("invoices.toml");
+
+    let mut store = InvoiceStore::default();
+    assert_eq!(store.next_number(date("2026-02-01")), "2026-001");
+    store.add(invoice("2026-001", "Startup X", "2026-02-01", dec!(1000))).unwrap();
+    assert_eq!(store.next_number(date("2026-03-01")), "2026-002");
+    assert_eq!(store.next_number(date("2027-01-05")), "2027-001");
+    assert!(store.add(invoice("2026-001", "Startup X", "2026-02-01", dec!(1))).is_err());
+
+    store.record_payment("2026-001", dec!(400), date("2026-02-10")).unwrap();
+    assert!(store.record_payment("2026-001", dec!(600.01), date("2026-02-11")).is_err());
+    assert!(store.record_payment("2026-999", dec!(1), date("2026-02-11")).is_err());
+    store.save_to(&path).unwrap();
+
+    let loaded = InvoiceStore::load_from(&path).unwrap();
+    let inv = loaded.get("2026-001").unwrap();
+    assert_eq!(inv.paid(), dec!(400));
+    assert_eq!(inv.outstanding(), dec!(600));
+    assert!(!inv.is_paid());
+}
+
+#[test]
+fn test_receivables_aging_buckets() {
+    let mut paid = invoice("2026-001", "Startup X", "2025-11-01", dec!(100));
+    paid.payments.push(Payment { date: date("2025-11-20"), amount: dec!(100) });
+    let mut partial = invoice("2026-002", "Startup X", "2026-01-15", dec!(500));
+    partial.payments.push(Payment { date: date("2026-02-01"), amount: dec!(200) });
+    let invoices = vec![
+        paid,
+        partial,
+        invoice("2026-003", "Startup X", "2026-03-01", dec!(250)),
+        invoice("2026-004", "Agency Y", "2025-12-01", dec!(80)),
+    ];
+
+    let groups = receivables(&invoices, date("2026-03-20"));
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].client, "Agency Y");
+    assert_eq!(groups[0].invoices[0].bucket, AgingBucket::Days61Plus);
+
+    let startup = &groups[1];
+    assert_eq!(startup.invoices.len(), 2);
+    // 2026-01-15 is 64 days old, 2026-03-01 is 19 days old
+    assert_eq!(startup.invoices[0].age_days, 64);
+    assert_eq!(startup.bucket_totals(), [dec!(250), dec!(0), dec!(300)]);
+    assert_eq!(startup.total(), dec!(550));
+
+    assert_eq!(AgingBucket::for_age(30), AgingBucket::Current);
+    assert_eq!(AgingBucket::for_age(31), AgingBucket::Days31To60);
+    assert_eq!(AgingBucket::for_age(61), AgingBucket::Days61Plus);
+}
+
+#[test]
+fn test_llm_usage_import_skips_duplicates() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("llm_usage.toml");
+    let entries = gtt::import::llm::parse_usage("date,cost\n2026-01-05 10:00:00,1.50\n2026-01-06,0.40\n", None).unwrap();
+
+    let mut store = LlmUsageStore::load_from(&path).unwrap();
+    assert_eq!(store.import(entries.clone()), 2);
+    store.save_to(&path).unwrap();
+
+    let mut reloaded = LlmUsageStore::load_from(&path).unwrap();
+    assert_eq!(reloaded.entries, entries);
+    assert_eq!(reloaded.import(entries), 0);
+}
+
+#[test]
+fn test_manual_time_store() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("manual_time.toml");
+    let at = |s: &str| chrono::DateTime::parse_from_rfc3339(s).unwrap();
+
+    let mut store = ManualTimeStore::load_from(&path).unwrap();
+    assert!(store.add("Startup X", at("2026-01-06T10:00:00Z"), at("2026-01-06T10:45:00Z"), "Call", None, "org"));
+    assert!(store.add("Startup X", at("2026-01-05T08:00:00Z"), at("2026-01-05T09:30:00Z"), "Planning", Some("web".to_string()), "timewarrior"));
+    assert!(store.add("Agency Y", at("2026-01-05T08:00:00Z"), at("2026-01-05T09:30:00Z"), "Planning", None, "timewarrior"));
+    // Re-importing the same interval is a no-op
+    assert!(!store.add("Startup X", at("2026-01-06T10:00:00Z"), at("2026-01-06T10:45:00Z"), "Call again", None, "org"));
+    store.save_to(&path).unwrap();
+
+    let loaded = ManualTimeStore::load_from(&path).unwrap();
+    assert_eq!(loaded.entries, store.entries);
+    let entries = loaded.for_client("Startup X", date("2026-01-01"), date("2026-01-31"));
+    assert_eq!(entries.iter().map(|e| e.id).collect::<Vec<_>>(), vec![2, 1]);
+    assert!(loaded.for_client("Startup X", date("2026-01-06"), date("2026-01-31")).iter().all(|e| e.id == 1));
+
+    let session = entries[0].to_session();
+    assert_eq!(session.duration_minutes, 90);
+    assert!(session.commits.is_empty());
+    assert_eq!(session.repos, vec!["web"]);
+    assert_eq!(session.description(), "Planning");
+
+    store.remove(1);
+    assert_eq!(store.entries.len(), 2);
+}